@@ -7,14 +7,19 @@ use {
 use crate::components::{ComponentId, ComponentSpecification, Event};
 use crate::craft_runtime::CraftRuntimeHandle;
 #[cfg(feature = "dev_tools")]
-use crate::devtools::dev_tools_component::dev_tools_view;
+use crate::devtools::dev_tools_component::{dev_tools_view, DEV_TOOLS_DIVIDER_SIZE, DEV_TOOLS_SPLIT_PANE_ID, DEV_TOOLS_WIDTH};
+#[cfg(feature = "dev_tools")]
+use crate::elements::split_pane::SplitPaneState;
+use crate::elements::toast::{ToastQueue, ToastStack, ToastStackState};
 use crate::elements::{Container, Element};
 use crate::events::event_dispatch::dispatch_event;
 use crate::events::internal::{InternalMessage, InternalUserMessage};
 use crate::events::resource_event::ResourceEvent;
 use crate::events::{CraftMessage, EventDispatchType, Message};
-use crate::geometry::{Rectangle, Size};
+use crate::geometry::Rectangle;
+use crate::i18n::Catalog;
 use crate::layout::layout_context::{measure_content, LayoutContext};
+use crate::options::FontOptions;
 use crate::reactive::element_id::reset_unique_element_id;
 use crate::reactive::element_state_store::ElementStateStore;
 use crate::reactive::reactive_tree::ReactiveTree;
@@ -24,7 +29,8 @@ use crate::resource_manager::resource_type::ResourceType;
 use crate::resource_manager::{ResourceIdentifier, ResourceManager};
 use crate::style::{Display, Unit, Wrap};
 use crate::text::text_context::TextContext;
-use crate::view_introspection::scan_view_for_resources;
+use crate::text::text_selection::selected_text;
+use crate::view_introspection::{find_font_elements, scan_view_for_resources};
 use crate::{GlobalState, RendererBox, WindowContext};
 #[cfg(feature = "accesskit")]
 use
@@ -33,7 +39,7 @@ use
     accesskit_winit::Adapter,
 };
 use cfg_if::cfg_if;
-use craft_logging::{info, span, Level};
+use craft_logging::{info, span, warn, Level};
 use kurbo::{Affine, Point};
 use peniko::Color;
 use std::collections::HashMap;
@@ -75,9 +81,13 @@ pub(crate) struct App {
     pub(crate) window: Option<Arc<Window>>,
     /// The text context is used to manage fonts and text rendering. It is only valid between resume and pause.
     pub(crate) text_context: Option<TextContext>,
+    /// The fonts that the text context is set up with.
+    pub(crate) font_options: FontOptions,
     /// The renderer is used to draw the view. It is only valid between resume and pause.
     pub(crate) renderer: Option<RendererBox>,
     pub(crate) reload_fonts: bool,
+    /// The next redraw only animates elements, so the view isn't run for it.
+    pub(crate) animation_frame_requested: bool,
     /// The resource manager is used to manage resources such as images and fonts.
     ///
     /// The resource manager is responsible for loading, caching, and providing access to resources.
@@ -90,6 +100,11 @@ pub(crate) struct App {
     pub(crate) user_tree: ReactiveTree,
     /// Provides a way for the user to get and set common window properties during view and update.
     pub(crate) window_context: WindowContext,
+    /// The toasts shown over the user's tree.
+    pub(crate) toasts: ToastQueue,
+    /// The locales that each message catalog that is being loaded is added to. The locales of a catalog that fails to
+    /// load are kept, so they get the catalog if it is loaded again.
+    pub(crate) catalog_requests: HashMap<ResourceIdentifier, Vec<String>>,
 
     #[cfg(feature = "dev_tools")]
     pub(crate) is_dev_tools_open: bool,
@@ -188,6 +203,7 @@ impl App {
     /// Handles the window resize event.
     pub(crate) fn on_resize(&mut self, new_size: PhysicalSize<u32>) {
         self.window_context.window_size = new_size;
+        self.animation_frame_requested = false;
         if let Some(renderer) = self.renderer.as_mut() {
             renderer.resize_surface(new_size.width.max(1) as f32, new_size.height.max(1) as f32);
         }
@@ -201,13 +217,12 @@ impl App {
     /// Initialize any data needed to layout/render text.
     fn setup_text_context(&mut self) {
         if self.text_context.is_none() {
-            #[cfg(any(target_arch = "wasm32", not(feature = "system_fonts")))]
-            let mut text_context = TextContext::new();
-            #[cfg(all(not(target_arch = "wasm32"), feature = "system_fonts"))]
-            let text_context = TextContext::new();
+            let system_fonts =
+                cfg!(all(not(target_arch = "wasm32"), feature = "system_fonts")) && self.font_options.system_fonts;
+            let mut text_context =
+                if system_fonts { TextContext::new() } else { TextContext::without_system_fonts() };
 
-            #[cfg(any(target_arch = "wasm32", not(feature = "system_fonts")))]
-            {
+            if !system_fonts {
                 let variable_roboto = include_bytes!("../../../fonts/Roboto-VariableFont_wdth,wght.ttf");
                 let roboto_blog = peniko::Blob::new(Arc::new(variable_roboto));
                 let fonts = text_context.font_context.collection.register_fonts(roboto_blog, None);
@@ -220,6 +235,7 @@ impl App {
                     .append_generic_families(parley::GenericFamily::SystemUi, fonts.iter().map(|f| f.0));
             }
 
+            text_context.configure_fonts(&self.font_options);
             self.text_context = Some(text_context);
         }
     }
@@ -229,10 +245,34 @@ impl App {
         self.setup_text_context();
         let text_context = self.text_context.as_mut().unwrap();
 
+        for toast in self.window_context.take_toasts() {
+            self.toasts.push(toast);
+        }
+        for (locale, resource_identifier) in self.window_context.take_catalog_requests() {
+            self.resource_manager.async_download_resource_and_send_message_on_finish(
+                resource_identifier.clone(),
+                ResourceType::MessageCatalog,
+                &self.resources_collected,
+            );
+            self.catalog_requests.entry(resource_identifier).or_default().push(locale);
+        }
+        // The toast stack is mounted after the user's root while there are toasts, see `ToastQueue::view`.
+        let toast_stack_id = self.user_tree.element_tree.as_ref().and_then(|root| {
+            root.children()
+                .into_iter()
+                .find(|child| child.as_any().is::<ToastStack>())
+                .map(|toast_stack| toast_stack.component_id())
+        });
+        let toast_stack_state = toast_stack_id
+            .and_then(|id| self.user_tree.element_state.storage.get_mut(&id))
+            .and_then(|state| state.data.as_mut().downcast_mut::<ToastStackState>());
+        self.toasts.update(toast_stack_state);
+
         let old_element_ids = self.user_tree.element_ids.clone();
         let old_component_ids = self.user_tree.component_ids.clone();
         update_reactive_tree(
             self.app.clone(),
+            self.toasts.view().into_iter().collect(),
             &mut self.user_tree,
             &mut self.global_state,
             &mut self.reload_fonts,
@@ -281,7 +321,10 @@ impl App {
 
         self.setup_text_context();
 
-        self.update_view();
+        // Frames that only animate elements are laid out and drawn again, but the view doesn't change for them.
+        if !std::mem::take(&mut self.animation_frame_requested) {
+            self.update_view();
+        }
 
         cfg_if! {
             if #[cfg(feature = "dev_tools")] {
@@ -298,8 +341,7 @@ impl App {
         #[cfg(feature = "dev_tools")]
         {
             if self.is_dev_tools_open {
-                let dev_tools_size = Size::new(350.0, root_size.height);
-                root_size.width -= dev_tools_size.width;
+                root_size.width = f32::max(root_size.width - self.dev_tools_width(), 0.0);
             }
         }
 
@@ -322,6 +364,7 @@ impl App {
             if self.is_dev_tools_open {
                 update_reactive_tree(
                     dev_tools_view(self.user_tree.element_tree.clone().unwrap()),
+                    Vec::new(),
                     &mut self.dev_tree,
                     &mut self.global_state,
                     &mut self.reload_fonts,
@@ -330,10 +373,11 @@ impl App {
                     &mut self.window_context,
                 );
 
+                // The dev tree covers the whole window, its first pane is left empty for the user tree.
                 self.layout_tree(
                     true,
-                    LogicalSize::new(surface_size.width - root_size.width, root_size.height),
-                    Point::new(root_size.width as f64, 0.0),
+                    surface_size,
+                    Point::new(0.0, 0.0),
                     self.window_context.effective_scale_factor(),
                     self.window_context.mouse_position,
                 );
@@ -348,11 +392,29 @@ impl App {
             self.renderer.as_mut().unwrap().submit(self.resource_manager.clone());
         }
 
+        if let Some(cursor) = self.user_tree.element_state.requested_cursor.take() {
+            self.window_context.set_cursor_if_unset(cursor);
+        }
+        #[cfg(feature = "dev_tools")]
+        if let Some(cursor) = self.dev_tree.element_state.requested_cursor.take() {
+            self.window_context.set_cursor_if_unset(cursor);
+        }
+
         if let Some(window) = &self.window {
             self.window_context.apply_requests(window);
             self.window_context.reset();
         }
 
+        let mut animating = std::mem::take(&mut self.user_tree.element_state.animation_frame_requested);
+        #[cfg(feature = "dev_tools")]
+        {
+            animating |= std::mem::take(&mut self.dev_tree.element_state.animation_frame_requested);
+        }
+        if animating {
+            self.animation_frame_requested = true;
+            window.request_redraw();
+        }
+
         self.on_process_user_events(false);
         #[cfg(feature = "dev_tools")]
         {
@@ -397,6 +459,7 @@ impl App {
         pointer_event.state.position.y /= zoom;
 
         let cursor_position = pointer_event.state.position;
+        let is_primary = pointer_event.is_primary();
 
         let event = if is_up {
             CraftMessage::PointerButtonUp(pointer_event)
@@ -410,6 +473,15 @@ impl App {
             self.dispatch_event(&message, EventDispatchType::Direct(component), false);
         } else {
             self.dispatch_event(&message, EventDispatchType::Bubbling, true);
+
+            if !is_up && is_primary {
+                if let Some(root) = self.user_tree.element_tree.as_deref() {
+                    self.user_tree.text_selection.start(root, &mut self.user_tree.element_state);
+                }
+            }
+        }
+        if is_up && is_primary {
+            self.user_tree.text_selection.end();
         }
 
         self.request_redraw();
@@ -423,10 +495,15 @@ impl App {
 
         self.window_context.mouse_position = Some(mouse_moved.current.position);
 
+        let pointer = mouse_moved.current.position;
         let message = Message::CraftMessage(CraftMessage::PointerMovedEvent(mouse_moved));
 
         self.dispatch_event(&message, EventDispatchType::Bubbling, true);
 
+        if let Some(root) = self.user_tree.element_tree.as_deref() {
+            self.user_tree.text_selection.extend(root, &mut self.user_tree.element_state, pointer);
+        }
+
         self.request_redraw();
     }
 
@@ -489,6 +566,17 @@ impl App {
 
         self.dispatch_event(&message, EventDispatchType::Bubbling, false);
 
+        // Focused elements, like text inputs, copy their own selection.
+        let action_mod =
+            if cfg!(target_os = "macos") { keyboard_input.modifiers.meta() } else { keyboard_input.modifiers.ctrl() };
+        if action_mod
+            && keyboard_input.state.is_down()
+            && self.user_tree.focus.is_none()
+            && matches!(&keyboard_input.key, ui_events::keyboard::Key::Character(c) if c.eq_ignore_ascii_case("c"))
+        {
+            self.copy_selected_text();
+        }
+
         #[cfg(feature = "dev_tools")]
         {
             let logical_key = keyboard_input.key;
@@ -517,23 +605,58 @@ impl App {
             &mut event,
             &Message::UserMessage(message.message),
         );
+        event.window.claim_toasts(message.source_component_id);
         self.window_context = event.window;
+        // The state changed, so the next frame runs the view even if it was requested by an animation.
+        self.animation_frame_requested = false;
     }
 
     pub(crate) fn on_resource_event(&mut self, resource_event: ResourceEvent) {
         match resource_event {
             ResourceEvent::Loaded(resource_identifier, resource_type, resource) => {
                 if resource_type == ResourceType::Font {
-                    if let Some(_text_context) = self.text_context.as_mut() {
-                        if resource.data().is_some() {
-                            // Todo: Load the font into the text context.
-                            self.resource_manager.resources.insert(resource_identifier.clone(), Arc::new(resource));
-                        }
+                    // The alias and the components to notify come from the font elements of the view, but the font
+                    // is registered even when there is no view yet.
+                    let (family, components) =
+                        match (self.user_tree.element_tree.as_ref(), self.user_tree.component_tree.as_ref()) {
+                            (Some(element_tree), Some(component_tree)) => {
+                                find_font_elements(element_tree.as_ref(), component_tree, &resource_identifier)
+                            }
+                            _ => (None, Vec::new()),
+                        };
+
+                    let mut loaded = None;
+                    if let (Some(text_context), Some(data)) = (self.text_context.as_mut(), resource.data()) {
+                        let families = text_context.register_font(data.to_vec(), family.as_deref());
+                        self.resource_manager.resources.insert(resource_identifier.clone(), Arc::new(resource));
+                        loaded = Some((families, components));
                     }
 
                     self.reload_fonts = true;
+
+                    if let Some((families, components)) = loaded {
+                        let message = Message::CraftMessage(CraftMessage::FontLoaded(families));
+                        for component in components {
+                            self.dispatch_event(&message, EventDispatchType::Direct(component), false);
+                        }
+                    }
+                    self.request_redraw();
                 } else if resource_type == ResourceType::Image || resource_type == ResourceType::TinyVg {
                     self.resource_manager.resources.insert(resource_identifier, Arc::new(resource));
+                } else if resource_type == ResourceType::MessageCatalog {
+                    let Some(locales) = self.catalog_requests.remove(&resource_identifier) else {
+                        return;
+                    };
+                    let source = String::from_utf8_lossy(resource.data().unwrap_or_default());
+                    match Catalog::parse(&source) {
+                        Ok(catalog) => {
+                            for locale in locales {
+                                self.window_context.add_catalog(&locale, catalog.clone());
+                            }
+                            self.request_redraw();
+                        }
+                        Err(error) => warn!("Failed to parse the message catalog {:?}: {}", resource_identifier, error),
+                    }
                 }
             }
             ResourceEvent::UnLoaded(_) => {}
@@ -549,12 +672,44 @@ impl App {
         );
     }
 
-    fn request_redraw(&self) {
+    /// Copies the text selected across the texts of the user's tree to the clipboard.
+    fn copy_selected_text(&self) {
+        let Some(root) = self.user_tree.element_tree.as_deref() else {
+            return;
+        };
+        if let Some(text) = selected_text(root, &self.user_tree.element_state) {
+            self.window_context.clipboard().set_text(&text);
+        }
+    }
+
+    fn request_redraw(&mut self) {
+        self.animation_frame_requested = false;
         if let Some(window) = &self.window {
             window.request_redraw();
         }
     }
 
+    /// Redraws the window when a toast times out, which dismisses it.
+    pub(crate) fn on_toast_timers(&mut self) {
+        if self.toasts.is_timeout_due() {
+            self.request_redraw();
+        }
+    }
+
+    /// The width of the devtools and their divider, which can be changed by dragging the divider.
+    #[cfg(feature = "dev_tools")]
+    fn dev_tools_width(&self) -> f32 {
+        let dev_tools_pane_size = self.dev_tree.element_tree.as_ref().and_then(|root| {
+            let split_pane = root
+                .pre_order_iter()
+                .find(|element| element.get_id().as_deref() == Some(DEV_TOOLS_SPLIT_PANE_ID))?;
+            let split_pane_state = self.dev_tree.element_state.storage.get(&split_pane.component_id())?;
+            split_pane_state.data.as_ref().downcast_ref::<SplitPaneState>()?.pane_size(1)
+        });
+
+        dev_tools_pane_size.unwrap_or(DEV_TOOLS_WIDTH) + DEV_TOOLS_DIVIDER_SIZE
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn layout_tree(
         &mut self,
@@ -639,6 +794,7 @@ impl App {
 #[allow(clippy::too_many_arguments)]
 fn update_reactive_tree(
     component_spec_to_generate_tree: ComponentSpecification,
+    overlays: Vec<ComponentSpecification>,
     reactive_tree: &mut ReactiveTree,
     global_state: &mut GlobalState,
     should_reload_fonts: &mut bool,
@@ -654,6 +810,7 @@ fn update_reactive_tree(
         let _enter = span.enter();
         diff_trees(
             component_spec_to_generate_tree.clone(),
+            overlays,
             window_element,
             old_component_tree,
             &mut reactive_tree.user_state,
@@ -674,6 +831,7 @@ fn update_reactive_tree(
     reactive_tree.component_ids = new_tree.component_ids;
     reactive_tree.element_ids = new_tree.element_ids;
     reactive_tree.pointer_captures = new_tree.pointer_captures;
+    reactive_tree.update_modal_focus();
 }
 
 fn style_root_element(root: &mut Box<dyn Element>, root_size: LogicalSize<f32>) {
@@ -699,7 +857,7 @@ fn style_root_element(root: &mut Box<dyn Element>, root_size: LogicalSize<f32>)
 }
 
 #[allow(clippy::too_many_arguments)]
-fn layout(
+pub(crate) fn layout(
     element_state: &mut ElementStateStore,
     window_size: LogicalSize<f32>,
     text_context: &mut TextContext,
@@ -710,6 +868,7 @@ fn layout(
     pointer: Option<Point>,
 ) -> (TaffyTree<LayoutContext>, NodeId) {
     let mut taffy_tree: TaffyTree<LayoutContext> = TaffyTree::new();
+    element_state.viewport = Rectangle::new(origin.x as f32, origin.y as f32, window_size.width, window_size.height);
     let root_node = root_element.compute_layout(&mut taffy_tree, element_state, scale_factor).unwrap();
 
     let available_space: taffy::Size<AvailableSpace> = taffy::Size {
@@ -0,0 +1,281 @@
+//! Access to the clipboard for components.
+//!
+//! The [`Clipboard`] is available through [`WindowContext::clipboard`](crate::WindowContext::clipboard). Writes
+//! happen right away, while reads are done in the background with [`Event::read_clipboard`], which sends the
+//! [`ClipboardRead`] back to the component as a user message.
+
+use image::RgbaImage;
+use std::fmt;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// A kind of content on the clipboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ClipboardFormat {
+    Text,
+    Html,
+    Image,
+}
+
+/// Content read from the clipboard.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClipboardContent {
+    Text(String),
+    Html(String),
+    Image(RgbaImage),
+}
+
+/// The result of [`Event::read_clipboard`](crate::components::Event::read_clipboard).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClipboardRead {
+    /// The format that was read.
+    pub format: ClipboardFormat,
+    /// The content, or `None` if the clipboard has no content of the format.
+    pub content: Option<ClipboardContent>,
+}
+
+/// Where the content of a [`Clipboard`] is stored.
+pub trait ClipboardBackend: Send {
+    fn text(&mut self) -> Option<String>;
+    fn set_text(&mut self, text: String);
+    fn html(&mut self) -> Option<String>;
+    /// Set the HTML content, with `alt_text` as the text content for applications that don't read HTML.
+    fn set_html(&mut self, html: String, alt_text: String);
+    fn image(&mut self) -> Option<RgbaImage>;
+    fn set_image(&mut self, image: RgbaImage);
+}
+
+/// A clipboard that only lives in memory, which is used when there is no system clipboard, like in headless tests.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryClipboard {
+    text: Option<String>,
+    html: Option<String>,
+    image: Option<RgbaImage>,
+}
+
+impl ClipboardBackend for MemoryClipboard {
+    fn text(&mut self) -> Option<String> {
+        self.text.clone()
+    }
+
+    fn set_text(&mut self, text: String) {
+        *self = Self {
+            text: Some(text),
+            ..Self::default()
+        };
+    }
+
+    fn html(&mut self) -> Option<String> {
+        self.html.clone()
+    }
+
+    fn set_html(&mut self, html: String, alt_text: String) {
+        *self = Self {
+            text: Some(alt_text),
+            html: Some(html),
+            image: None,
+        };
+    }
+
+    fn image(&mut self) -> Option<RgbaImage> {
+        self.image.clone()
+    }
+
+    fn set_image(&mut self, image: RgbaImage) {
+        *self = Self {
+            image: Some(image),
+            ..Self::default()
+        };
+    }
+}
+
+/// The clipboard of the operating system.
+#[cfg(all(any(target_os = "windows", target_os = "macos", target_os = "linux"), feature = "clipboard"))]
+struct SystemClipboard;
+
+#[cfg(all(any(target_os = "windows", target_os = "macos", target_os = "linux"), feature = "clipboard"))]
+impl SystemClipboard {
+    fn context() -> Option<clipboard_rs::ClipboardContext> {
+        clipboard_rs::ClipboardContext::new().ok()
+    }
+}
+
+#[cfg(all(any(target_os = "windows", target_os = "macos", target_os = "linux"), feature = "clipboard"))]
+impl ClipboardBackend for SystemClipboard {
+    fn text(&mut self) -> Option<String> {
+        use clipboard_rs::Clipboard;
+        Self::context()?.get_text().ok()
+    }
+
+    fn set_text(&mut self, text: String) {
+        use clipboard_rs::Clipboard;
+        if let Some(context) = Self::context() {
+            context.set_text(text).ok();
+        }
+    }
+
+    fn html(&mut self) -> Option<String> {
+        use clipboard_rs::Clipboard;
+        Self::context()?.get_html().ok()
+    }
+
+    fn set_html(&mut self, html: String, alt_text: String) {
+        use clipboard_rs::Clipboard;
+        if let Some(context) = Self::context() {
+            let contents = vec![
+                clipboard_rs::ClipboardContent::Html(html),
+                clipboard_rs::ClipboardContent::Text(alt_text),
+            ];
+            context.set(contents).ok();
+        }
+    }
+
+    fn image(&mut self) -> Option<RgbaImage> {
+        use clipboard_rs::common::RustImage;
+        use clipboard_rs::Clipboard;
+        Self::context()?.get_image().ok()?.to_rgba8().ok()
+    }
+
+    fn set_image(&mut self, image: RgbaImage) {
+        use clipboard_rs::common::RustImage;
+        use clipboard_rs::Clipboard;
+        if let Some(context) = Self::context() {
+            let image = clipboard_rs::RustImageData::from_dynamic_image(::image::DynamicImage::ImageRgba8(image));
+            context.set_image(image).ok();
+        }
+    }
+}
+
+/// A shared handle to the clipboard.
+///
+/// The default clipboard is the system clipboard, or a [`MemoryClipboard`] if the system clipboard is not available.
+#[derive(Clone)]
+pub struct Clipboard {
+    backend: Arc<Mutex<dyn ClipboardBackend>>,
+}
+
+impl fmt::Debug for Clipboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Clipboard").finish_non_exhaustive()
+    }
+}
+
+impl Default for Clipboard {
+    fn default() -> Self {
+        static CLIPBOARD: OnceLock<Clipboard> = OnceLock::new();
+        CLIPBOARD.get_or_init(|| Self::system().unwrap_or_else(Self::memory)).clone()
+    }
+}
+
+impl Clipboard {
+    pub fn new(backend: impl ClipboardBackend + 'static) -> Self {
+        Self {
+            backend: Arc::new(Mutex::new(backend)),
+        }
+    }
+
+    /// The clipboard of the operating system, if it is available.
+    pub fn system() -> Option<Self> {
+        #[cfg(all(any(target_os = "windows", target_os = "macos", target_os = "linux"), feature = "clipboard"))]
+        {
+            SystemClipboard::context().map(|_| Self::new(SystemClipboard))
+        }
+        #[cfg(not(all(any(target_os = "windows", target_os = "macos", target_os = "linux"), feature = "clipboard")))]
+        {
+            None
+        }
+    }
+
+    /// A new, empty clipboard that only lives in memory.
+    pub fn memory() -> Self {
+        Self::new(MemoryClipboard::default())
+    }
+
+    fn with_backend<T>(&self, f: impl FnOnce(&mut dyn ClipboardBackend) -> T) -> T {
+        let mut backend = self.backend.lock().unwrap_or_else(|error| error.into_inner());
+        f(&mut *backend)
+    }
+
+    pub fn text(&self) -> Option<String> {
+        self.with_backend(|backend| backend.text())
+    }
+
+    pub fn set_text(&self, text: &str) {
+        self.with_backend(|backend| backend.set_text(text.to_string()));
+    }
+
+    pub fn html(&self) -> Option<String> {
+        self.with_backend(|backend| backend.html())
+    }
+
+    /// Set the HTML content, with `alt_text` as the text content for applications that don't read HTML.
+    pub fn set_html(&self, html: &str, alt_text: &str) {
+        self.with_backend(|backend| backend.set_html(html.to_string(), alt_text.to_string()));
+    }
+
+    pub fn image(&self) -> Option<RgbaImage> {
+        self.with_backend(|backend| backend.image())
+    }
+
+    pub fn set_image(&self, image: RgbaImage) {
+        self.with_backend(|backend| backend.set_image(image));
+    }
+
+    /// Reads the content of `format` from the clipboard.
+    pub fn read(&self, format: ClipboardFormat) -> ClipboardRead {
+        let content = match format {
+            ClipboardFormat::Text => self.text().map(ClipboardContent::Text),
+            ClipboardFormat::Html => self.html().map(ClipboardContent::Html),
+            ClipboardFormat::Image => self.image().map(ClipboardContent::Image),
+        };
+        ClipboardRead { format, content }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Clipboard, ClipboardBackend, ClipboardContent, ClipboardFormat, MemoryClipboard};
+    use image::RgbaImage;
+
+    #[test]
+    fn html_is_set_with_its_alt_text() {
+        let mut clipboard = MemoryClipboard::default();
+        clipboard.set_html("<b>bold</b>".to_string(), "bold".to_string());
+
+        assert_eq!(clipboard.html().as_deref(), Some("<b>bold</b>"));
+        assert_eq!(clipboard.text().as_deref(), Some("bold"));
+    }
+
+    #[test]
+    fn setting_text_clears_the_other_formats() {
+        let mut clipboard = MemoryClipboard::default();
+        clipboard.set_html("<b>bold</b>".to_string(), "bold".to_string());
+        clipboard.set_text("plain".to_string());
+        assert_eq!(clipboard.html(), None);
+
+        clipboard.set_image(RgbaImage::new(1, 1));
+        clipboard.set_text("plain".to_string());
+        assert_eq!(clipboard.image(), None);
+        assert_eq!(clipboard.text().as_deref(), Some("plain"));
+    }
+
+    #[test]
+    fn read_returns_the_content_of_each_format() {
+        let clipboard = Clipboard::memory();
+        clipboard.set_html("<b>bold</b>", "bold");
+
+        let text = clipboard.read(ClipboardFormat::Text);
+        assert_eq!(text.format, ClipboardFormat::Text);
+        assert_eq!(text.content, Some(ClipboardContent::Text("bold".to_string())));
+        let html = clipboard.read(ClipboardFormat::Html);
+        assert_eq!(html.format, ClipboardFormat::Html);
+        assert_eq!(html.content, Some(ClipboardContent::Html("<b>bold</b>".to_string())));
+        assert_eq!(clipboard.read(ClipboardFormat::Image).content, None);
+
+        let image = RgbaImage::new(2, 1);
+        clipboard.set_image(image.clone());
+        let read = clipboard.read(ClipboardFormat::Image);
+        assert_eq!(read.format, ClipboardFormat::Image);
+        assert_eq!(read.content, Some(ClipboardContent::Image(image)));
+        assert_eq!(clipboard.read(ClipboardFormat::Text).content, None);
+    }
+}
@@ -219,6 +219,33 @@ where
                 CraftMessage::SliderValueChanged(slider_value) => {
                     self.on_slider_value_changed(global_state, props, event, *slider_value);
                 }
+                CraftMessage::DialogCloseRequested => {
+                    self.on_dialog_close_requested(global_state, props, event);
+                }
+                CraftMessage::MenuItemActivated(item) => {
+                    self.on_menu_item_activated(global_state, props, event, item);
+                }
+                CraftMessage::PaneResized(sizes) => {
+                    self.on_pane_resized(global_state, props, event, sizes);
+                }
+                CraftMessage::NumberChanged(value) => {
+                    self.on_number_changed(global_state, props, event, *value);
+                }
+                CraftMessage::DateChanged(date) => {
+                    self.on_date_changed(global_state, props, event, *date);
+                }
+                CraftMessage::TimeChanged(time) => {
+                    self.on_time_changed(global_state, props, event, *time);
+                }
+                CraftMessage::ColorChanged(color) => {
+                    self.on_color_changed(global_state, props, event, *color);
+                }
+                CraftMessage::LinkClicked(link) => {
+                    self.on_link_clicked(global_state, props, event, link);
+                }
+                CraftMessage::FontLoaded(families) => {
+                    self.on_font_loaded(global_state, props, event, families);
+                }
                 CraftMessage::ElementMessage(_) => {}
             },
             crate::events::Message::UserMessage(user_message) => {
@@ -409,6 +436,127 @@ where
         }
     }
 
+    fn on_dialog_close_requested(
+        &mut self,
+        global_state: &mut Self::GlobalState,
+        _props: &Self::Props,
+        event: &mut Event,
+    ) {
+        if let Some(element) = event.current_target {
+            if let Some(on_dialog_close_requested) = &element.element_data().on_dialog_close_requested {
+                on_dialog_close_requested(self, global_state, event);
+            }
+        }
+    }
+
+    fn on_menu_item_activated(
+        &mut self,
+        global_state: &mut Self::GlobalState,
+        _props: &Self::Props,
+        event: &mut Event,
+        item: &str,
+    ) {
+        if let Some(element) = event.current_target {
+            if let Some(on_menu_item_activated) = &element.element_data().on_menu_item_activated {
+                on_menu_item_activated(self, global_state, event, item);
+            }
+        }
+    }
+
+    fn on_pane_resized(
+        &mut self,
+        global_state: &mut Self::GlobalState,
+        _props: &Self::Props,
+        event: &mut Event,
+        sizes: &[f32],
+    ) {
+        if let Some(element) = event.current_target {
+            if let Some(on_pane_resized) = &element.element_data().on_pane_resized {
+                on_pane_resized(self, global_state, event, sizes);
+            }
+        }
+    }
+
+    fn on_number_changed(
+        &mut self,
+        global_state: &mut Self::GlobalState,
+        _props: &Self::Props,
+        event: &mut Event,
+        value: f64,
+    ) {
+        if let Some(element) = event.current_target {
+            if let Some(on_number_changed) = &element.element_data().on_number_changed {
+                on_number_changed(self, global_state, event, value);
+            }
+        }
+    }
+
+    fn on_date_changed(
+        &mut self,
+        global_state: &mut Self::GlobalState,
+        _props: &Self::Props,
+        event: &mut Event,
+        date: chrono::NaiveDate,
+    ) {
+        if let Some(element) = event.current_target {
+            if let Some(on_date_changed) = &element.element_data().on_date_changed {
+                on_date_changed(self, global_state, event, date);
+            }
+        }
+    }
+
+    fn on_time_changed(
+        &mut self,
+        global_state: &mut Self::GlobalState,
+        _props: &Self::Props,
+        event: &mut Event,
+        time: chrono::NaiveTime,
+    ) {
+        if let Some(element) = event.current_target {
+            if let Some(on_time_changed) = &element.element_data().on_time_changed {
+                on_time_changed(self, global_state, event, time);
+            }
+        }
+    }
+
+    fn on_color_changed(
+        &mut self,
+        global_state: &mut Self::GlobalState,
+        _props: &Self::Props,
+        event: &mut Event,
+        color: crate::Color,
+    ) {
+        if let Some(element) = event.current_target {
+            if let Some(on_color_changed) = &element.element_data().on_color_changed {
+                on_color_changed(self, global_state, event, color);
+            }
+        }
+    }
+
+    fn on_link_clicked(
+        &mut self,
+        global_state: &mut Self::GlobalState,
+        _props: &Self::Props,
+        event: &mut Event,
+        link: &str,
+    ) {
+        if let Some(element) = event.current_target {
+            if let Some(on_link_clicked) = &element.element_data().on_link_clicked {
+                on_link_clicked(self, global_state, event, link);
+            }
+        }
+    }
+
+    /// Called when a font loaded by a `Font` element of this component can be used.
+    fn on_font_loaded(
+        &mut self,
+        _global_state: &mut Self::GlobalState,
+        _props: &Self::Props,
+        _event: &mut Event,
+        _families: &[String],
+    ) {
+    }
+
     fn default_state() -> Box<StateStoreItem> {
         Box::<Self>::default()
     }
@@ -1,4 +1,5 @@
-use crate::elements::Element;
+use crate::clipboard::ClipboardFormat;
+use crate::elements::{Element, Toast};
 use crate::events::{CraftMessage, EventDispatchType, Message};
 use crate::geometry::Rectangle;
 use crate::window_context::WindowContext;
@@ -27,6 +28,8 @@ pub struct Event<'a> {
     /// Redirect future pointer events to this component. None by default.
     pub(crate) pointer_capture: PointerCapture,
     pub(crate) effects: Vec<(EventDispatchType, Message)>,
+    /// The clipboard reads requested by the update, which are queued alongside `future`.
+    pub(crate) clipboard_reads: Vec<PinnedFutureAny>,
     pub(crate) ime: ImeAction,
     pub focus: FocusAction,
 
@@ -109,6 +112,7 @@ impl<'a> Default for Event<'a> {
             result_message: None,
             pointer_capture: Default::default(),
             effects: Vec::new(),
+            clipboard_reads: Vec::new(),
             ime: ImeAction::None,
             focus: FocusAction::None,
             target: None,
@@ -156,4 +160,20 @@ impl<'a> Event<'a> {
     pub fn add_effect(&mut self, event_dispatch_type: EventDispatchType, message: Message) {
         self.effects.push((event_dispatch_type, message));
     }
+
+    /// Shows a toast notification in the corner of the window.
+    ///
+    /// The toast's action message is sent to the component whose update pushed it.
+    pub fn push_toast(&mut self, toast: Toast) {
+        self.window.push_toast(toast);
+    }
+
+    /// Reads `format` from the clipboard in the background.
+    ///
+    /// The [`ClipboardRead`](crate::clipboard::ClipboardRead) is sent to the component as a user message once it's
+    /// read. The read doesn't replace the future of the event.
+    pub fn read_clipboard(&mut self, format: ClipboardFormat) {
+        let clipboard = self.window.clipboard().clone();
+        self.clipboard_reads.push(Box::pin(async move { Event::async_result(clipboard.read(format)) }));
+    }
 }
@@ -154,7 +154,11 @@ impl ApplicationHandler for CraftWinitState {
             match self.event_reducer.reduce(&event) {
                 UiEvent::Keyboard(keyboard_event) => {
                     use ui_events::keyboard::{Key, NamedKey};
-                    if keyboard_event.state.is_down() && matches!(keyboard_event.key, Key::Named(NamedKey::Escape)) {
+                    // Escape is reserved for closing dialogs and menus while one is open.
+                    if keyboard_event.state.is_down()
+                        && matches!(keyboard_event.key, Key::Named(NamedKey::Escape))
+                        && !self.craft_app.user_tree.captures_escape()
+                    {
                         event_loop.exit();
                     } else {
                         self.craft_app.on_keyboard_input(keyboard_event);
@@ -253,6 +257,8 @@ impl ApplicationHandler for CraftWinitState {
             }
         }
 
+        self.craft_app.on_toast_timers();
+
         if self.close_requested {
             info!("Exiting winit event loop");
 
@@ -5,12 +5,18 @@ use crate::devtools::dev_tools_element::DevTools;
 use crate::devtools::tree_window::tree_window;
 use crate::devtools::layout_window::{LayoutWindow, LayoutWindowProps};
 use crate::elements::element::Element;
-use crate::elements::ElementStyles;
+use crate::elements::{Container, ElementStyles, Pane, SplitDirection, SplitPane};
 use crate::events::{CraftMessage, Message};
 use crate::style::Display::Flex;
 use crate::style::{FlexDirection, Unit};
 use crate::window_context::WindowContext;
 
+/// The id of the split pane that places the devtools next to the user tree.
+pub(crate) const DEV_TOOLS_SPLIT_PANE_ID: &str = "dev_tools_split_pane";
+/// The initial width of the devtools.
+pub(crate) const DEV_TOOLS_WIDTH: f32 = 350.0;
+pub(crate) const DEV_TOOLS_DIVIDER_SIZE: f32 = 4.0;
+
 #[derive(Default)]
 pub(crate) struct DevToolsComponent {
     pub selected_element: Option<ComponentId>,
@@ -50,18 +56,26 @@ impl Component for DevToolsComponent {
             selected_element: selected_element.map(|e| e.clone_box()),
         }));
 
-        DevTools::new()
+        let dev_tools = DevTools::new()
             .display(Flex)
             .push_debug_inspector_tree(root)
             .push_selected_inspector_element(self.selected_element)
             .push_hovered_inspector_element(self.inspector_hovered_element)
             .flex_direction(FlexDirection::Column)
             .background(CONTAINER_BACKGROUND_COLOR)
-            .width(Unit::Percentage(100.0))
             .height(Unit::Percentage(100.0))
             .max_height(Unit::Percentage(100.0))
             .push(element_tree)
-            .push(styles_window)
+            .push(styles_window);
+
+        // The user tree is laid out and drawn in place of the empty first pane.
+        SplitPane::new(SplitDirection::Horizontal)
+            .id(DEV_TOOLS_SPLIT_PANE_ID)
+            .divider_size(DEV_TOOLS_DIVIDER_SIZE)
+            .width(Unit::Percentage(100.0))
+            .height(Unit::Percentage(100.0))
+            .pane(Container::new(), Pane::new().min_size(100.0))
+            .pane(dev_tools, Pane::new().size(DEV_TOOLS_WIDTH).min_size(200.0))
             .component()
     }
 
@@ -253,6 +253,46 @@ fn tab_styles(selected_element: &Box<dyn Element>, search: &str) -> Container {
         push_field!("Font Style", format!("{:?}", style.font_style()));
     }
 
+    if style.dirty_flags.contains(StyleFlags::FONT_OBLIQUE_ANGLE) {
+        if let Some(angle) = style.font_oblique_angle() {
+            push_field!("Font Oblique Angle", format!("{angle}deg"));
+        }
+    }
+
+    if style.dirty_flags.contains(StyleFlags::FONT_FEATURES) {
+        let features: Vec<String> = style.font_features().iter().map(|feature| feature.to_string()).collect();
+        push_field!("Font Features", features.join(", "));
+    }
+
+    if style.dirty_flags.contains(StyleFlags::FONT_VARIATIONS) {
+        let variations: Vec<String> = style.font_variations().iter().map(|variation| variation.to_string()).collect();
+        push_field!("Font Variations", variations.join(", "));
+    }
+
+    if style.dirty_flags.contains(StyleFlags::LINE_HEIGHT) {
+        push_field!("Line Height", style.line_height().to_string());
+    }
+
+    if style.dirty_flags.contains(StyleFlags::LETTER_SPACING) {
+        push_field!("Letter Spacing", style.letter_spacing().to_string());
+    }
+
+    if style.dirty_flags.contains(StyleFlags::WORD_SPACING) {
+        push_field!("Word Spacing", style.word_spacing().to_string());
+    }
+
+    if style.dirty_flags.contains(StyleFlags::TEXT_ALIGN) {
+        push_field!("Text Align", format!("{:?}", style.text_align()));
+    }
+
+    if style.dirty_flags.contains(StyleFlags::DIRECTION) {
+        push_field!("Direction", format!("{:?}", style.direction()));
+    }
+
+    if style.dirty_flags.contains(StyleFlags::WHITE_SPACE) {
+        push_field!("White Space", format!("{:?}", style.white_space()));
+    }
+
     if style.dirty_flags.contains(StyleFlags::OVERFLOW) {
         push_field!("Overflow", format!("{:?}", style.overflow()));
     }
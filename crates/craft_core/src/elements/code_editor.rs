@@ -0,0 +1,592 @@
+use crate::components::component::{ComponentId, ComponentSpecification};
+use crate::components::Event;
+use crate::components::Props;
+use crate::elements::element::{resolve_clip_for_scrollable, Element};
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+#[cfg(feature = "accesskit")]
+use crate::elements::text_input::compute_text_input_accessibility_tree;
+use crate::elements::text_input::{
+    default_text_input_style, draw_text_input_content, finalize_text_input_layout, on_text_input_event, TextInputState,
+};
+use crate::events::CraftMessage;
+use crate::generate_component_methods_no_children;
+use crate::geometry::{Point, Rectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::{Style, Unit};
+use crate::text::label_cache::LabelCache;
+use crate::text::parley_editor::Generation;
+use crate::text::text_context::TextContext;
+use crate::text::tokenizer::LineStyleCache;
+use crate::text::{RangedStyles, Tokenizer};
+use kurbo::Affine;
+use peniko::Color;
+use std::any::Any;
+use std::ops::Range;
+use std::sync::Arc;
+use taffy::{AvailableSpace, NodeId, Size, TaffyTree};
+use ui_events::keyboard::{Key, NamedKey};
+use winit::window::Window;
+
+const GUTTER_COLOR: Color = Color::from_rgb8(245, 245, 247);
+const LINE_NUMBER_COLOR: Color = Color::from_rgb8(150, 150, 160);
+const CURRENT_LINE_COLOR: Color = Color::from_rgba8(0, 0, 0, 12);
+
+/// The space on both sides of the line numbers in logical pixels.
+const GUTTER_PADDING: f32 = 8.0;
+
+/// A text editor for source code.
+///
+/// Shows line numbers in a gutter and highlights the line with the caret while focused. Tab indents and Shift+Tab
+/// outdents the selected lines, and Enter keeps the indentation of the current line. The code is styled by a
+/// [`Tokenizer`], which only tokenizes the lines that changed.
+///
+/// Emits [`CraftMessage::TextInputChanged`] when the text changes.
+#[derive(Clone)]
+pub struct CodeEditor {
+    element_data: ElementData,
+    /// Whether the editor takes the text of the view when the view changes it.
+    use_text_value_on_update: bool,
+    text: Option<String>,
+    tokenizer: Option<Arc<dyn Tokenizer>>,
+    /// The number of spaces that an indentation level has.
+    tab_size: usize,
+    line_numbers: bool,
+    gutter_color: Color,
+    line_number_color: Color,
+    current_line_color: Color,
+    /// The width of the gutter in logical pixels, computed during layout.
+    gutter_width: f32,
+}
+
+/// The state of a [`CodeEditor`], which wraps the state of the text input that it is built on.
+pub(crate) struct CodeEditorState {
+    text_input: TextInputState,
+    /// The text of the last view, so that the typed text is only replaced when the view changes it.
+    view_text: Option<String>,
+    line_styles: LineStyleCache,
+    /// The generation of the editor when the text was last tokenized.
+    tokenized_generation: Option<Generation>,
+    labels: LabelCache,
+    /// The last line number and its width in logical pixels, which is measured while drawing, as the layout has no
+    /// text context.
+    last_line_number_width: Option<(String, f32)>,
+}
+
+impl Element for CodeEditor {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "CodeEditor"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        element_state: &mut ElementStateStore,
+        _pointer: Option<Point>,
+        window: Option<Arc<Window>>,
+        scale_factor: f64,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+
+        self.draw_borders(renderer, element_state, scale_factor);
+        self.draw_gutter(renderer, text_context, element_state, window, scale_factor);
+        draw_text_input_content(self, renderer, element_state, code_editor_text_input_state, scale_factor);
+        self.draw_scrollbar(renderer, scale_factor);
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        _scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+
+        let state = self.get_state_mut(element_state);
+        if let Some(tokenizer) = &self.tokenizer {
+            tokenize(state, tokenizer.as_ref());
+        }
+
+        self.gutter_width = if self.line_numbers {
+            let last_line_number = (state.text_input.layout_text().matches('\n').count() + 1).to_string();
+            let width = match &state.last_line_number_width {
+                Some((line_number, width)) if *line_number == last_line_number => *width,
+                // Until the line number is measured, digits are about 0.6em wide in most fonts.
+                _ => last_line_number.len() as f32 * self.element_data.style.font_size() * 0.6,
+            };
+            width + GUTTER_PADDING * 2.0
+        } else {
+            0.0
+        };
+
+        // The gutter is drawn in the left padding, so that the text starts after it.
+        let mut style: taffy::Style = self.element_data.style.to_taffy_style();
+        let padding_left = match self.element_data.style.padding().left {
+            Unit::Px(padding_left) => padding_left,
+            _ => 0.0,
+        };
+        style.padding.left = taffy::LengthPercentage::length(padding_left + self.gutter_width);
+
+        let component_id = self.element_data.component_id;
+        self.element_data.layout_item.build_tree_with_context(
+            taffy_tree,
+            style,
+            LayoutContext::Other(component_id, measure_code_editor),
+        )
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: Affine,
+        element_state: &mut ElementStateStore,
+        _pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        finalize_text_input_layout(
+            self,
+            taffy_tree,
+            root_node,
+            position,
+            z_index,
+            transform,
+            element_state,
+            code_editor_text_input_state,
+            text_context,
+            clip_bounds,
+        );
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        let focused = self.get_base_state(element_state).base.focused;
+
+        if let CraftMessage::KeyboardInputEvent(keyboard_input) = message {
+            let state = &mut self.get_state_mut(element_state).text_input;
+            if focused && keyboard_input.state.is_down() && !state.editor.is_composing() {
+                let shift = keyboard_input.modifiers.shift();
+                let handled = match &keyboard_input.key {
+                    Key::Named(NamedKey::Tab) if shift => {
+                        self.outdent(state, text_context);
+                        true
+                    }
+                    Key::Named(NamedKey::Tab) => {
+                        self.indent(state, text_context);
+                        true
+                    }
+                    Key::Named(NamedKey::Enter) => {
+                        self.insert_line_break(state, text_context);
+                        true
+                    }
+                    _ => false,
+                };
+
+                if handled {
+                    state.cursor_reset();
+                    event.prevent_defaults();
+                    event.prevent_propagate();
+                    event.result_message(CraftMessage::TextInputChanged(state.editor.text().to_string()));
+                    return;
+                }
+            }
+        }
+
+        on_text_input_event(
+            self,
+            message,
+            element_state,
+            code_editor_text_input_state,
+            text_context,
+            should_style,
+            event,
+        );
+    }
+
+    fn resolve_clip(&mut self, clip_bounds: Option<Rectangle>) {
+        resolve_clip_for_scrollable(self, clip_bounds);
+    }
+
+    fn initialize_state(&mut self, scaling_factor: f64) -> ElementStateStoreItem {
+        let text = std::mem::take(&mut self.text);
+        let state = CodeEditorState {
+            text_input: TextInputState::new(self.style(), text.clone(), RangedStyles::new(vec![]), scaling_factor),
+            view_text: text,
+            line_styles: LineStyleCache::default(),
+            tokenized_generation: None,
+            labels: LabelCache::default(),
+            last_line_number_width: None,
+        };
+
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(state),
+        }
+    }
+
+    #[cfg(feature = "accesskit")]
+    fn compute_accessibility_tree(
+        &mut self,
+        tree: &mut accesskit::TreeUpdate,
+        parent_index: Option<usize>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) {
+        let current_node = accesskit::Node::new(accesskit::Role::MultilineTextInput);
+        compute_text_input_accessibility_tree(
+            self,
+            current_node,
+            tree,
+            parent_index,
+            element_state,
+            code_editor_text_input_state,
+            scale_factor,
+        );
+    }
+
+    fn update_state(&mut self, element_state: &mut ElementStateStore, reload_fonts: bool, scaling_factor: f64) {
+        let use_text_value_on_update = self.use_text_value_on_update;
+        let text = self.text.take();
+        let state = self.get_state_mut(element_state);
+        let text_input = &mut state.text_input;
+        text_input.update_scale(scaling_factor);
+        if reload_fonts {
+            text_input.reload_fonts();
+        }
+        text_input.update_style(self.style());
+
+        if let (true, Some(text)) = (use_text_value_on_update, text) {
+            if state.view_text.as_ref() != Some(&text) {
+                if state.text_input.text() != text {
+                    state.text_input.set_text(&text);
+                }
+                state.view_text = Some(text);
+            }
+        }
+    }
+
+    fn default_style(&self) -> Style {
+        default_text_input_style()
+    }
+}
+
+impl CodeEditor {
+    pub fn new(text: &str) -> Self {
+        Self {
+            element_data: ElementData::default(),
+            use_text_value_on_update: true,
+            text: Some(text.to_string()),
+            tokenizer: None,
+            tab_size: 4,
+            line_numbers: true,
+            gutter_color: GUTTER_COLOR,
+            line_number_color: LINE_NUMBER_COLOR,
+            current_line_color: CURRENT_LINE_COLOR,
+            gutter_width: 0.0,
+        }
+    }
+
+    /// Set the tokenizer that styles the code.
+    pub fn tokenizer<T: Tokenizer + 'static>(mut self, tokenizer: T) -> Self {
+        self.tokenizer = Some(Arc::new(tokenizer));
+        self
+    }
+
+    /// Whether the editor takes the text of the view when the view changes it, so that the view can replace the
+    /// code. The text that was typed is kept while the view passes the same text. Defaults to true.
+    pub fn use_text_value_on_update(mut self, use_text_value_on_update: bool) -> Self {
+        self.use_text_value_on_update = use_text_value_on_update;
+        self
+    }
+
+    /// Set the number of spaces that Tab indents by. Defaults to 4.
+    pub fn tab_size(mut self, tab_size: usize) -> Self {
+        self.tab_size = tab_size.max(1);
+        self
+    }
+
+    /// Set whether the line numbers are shown. Defaults to true.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Set the background color of the line number gutter.
+    pub fn gutter_color(mut self, gutter_color: Color) -> Self {
+        self.gutter_color = gutter_color;
+        self
+    }
+
+    /// Set the color of the line numbers.
+    pub fn line_number_color(mut self, line_number_color: Color) -> Self {
+        self.line_number_color = line_number_color;
+        self
+    }
+
+    /// Set the color that the line with the caret is highlighted with while the editor is focused.
+    pub fn current_line_color(mut self, current_line_color: Color) -> Self {
+        self.current_line_color = current_line_color;
+        self
+    }
+
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a CodeEditorState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    fn get_state_mut<'a>(&self, element_state: &'a mut ElementStateStore) -> &'a mut CodeEditorState {
+        element_state.storage.get_mut(&self.element_data.component_id).unwrap().data.as_mut().downcast_mut().unwrap()
+    }
+
+    /// Draws the gutter with the line numbers, and the highlight of the line with the caret behind the text.
+    fn draw_gutter(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        element_state: &mut ElementStateStore,
+        window: Option<Arc<Window>>,
+        scale_factor: f64,
+    ) {
+        let focused = self.get_base_state(element_state).base.focused;
+        let computed_box_transformed = self.computed_box_transformed();
+        let padding_rectangle = computed_box_transformed.padding_rectangle();
+        let content_rectangle = computed_box_transformed.content_rectangle();
+        let gutter =
+            Rectangle::new(padding_rectangle.x, padding_rectangle.y, self.gutter_width, padding_rectangle.height);
+        let style = self.element_data.style;
+        let scale = scale_factor as f32;
+
+        let state = self.get_state_mut(element_state);
+        let scroll_y = state.text_input.scroll_state.scroll_y;
+        let Some(layout) = state.text_input.editor.try_layout() else {
+            return;
+        };
+        // The editor lays out the text in physical pixels.
+        let to_logical_y = |y: f32| content_rectangle.y + (y - scroll_y) / scale;
+
+        renderer.push_layer(padding_rectangle.scale(scale_factor));
+
+        if focused {
+            if let Some(cursor) = state.text_input.editor.cursor_geometry(1.0) {
+                let y0 = to_logical_y(cursor.y0 as f32);
+                let y1 = to_logical_y(cursor.y1 as f32);
+                let current_line = Rectangle::new(padding_rectangle.x, y0, padding_rectangle.width, y1 - y0);
+                renderer.draw_rect(current_line.scale(scale_factor), self.current_line_color);
+            }
+        }
+
+        if self.line_numbers {
+            renderer.draw_rect(gutter.scale(scale_factor), self.gutter_color);
+
+            let editor = &state.text_input.editor;
+            let text = editor.raw_text();
+            let labels = &mut state.labels;
+
+            // The gutter is as wide as the last line number, so it is laid out again when that changes.
+            let last_line_number = (text.matches('\n').count() + 1).to_string();
+            let width = labels.measure(text_context, &style, &last_line_number, scale_factor).width;
+            if state.last_line_number_width != Some((last_line_number.clone(), width)) {
+                state.last_line_number_width = Some((last_line_number, width));
+                if let Some(window) = window {
+                    window.request_redraw();
+                }
+            }
+
+            let mut line_number = 0;
+            for line in layout.lines() {
+                // Lines that are wrapped don't get a number of their own.
+                let start = editor.buffer_index(line.text_range().start);
+                if start != 0 && text.as_bytes()[start - 1] != b'\n' {
+                    continue;
+                }
+                line_number += 1;
+
+                let metrics = line.metrics();
+                let top = to_logical_y(metrics.min_coord);
+                let bottom = to_logical_y(metrics.max_coord);
+                if bottom < padding_rectangle.y {
+                    continue;
+                }
+                if top > padding_rectangle.y + padding_rectangle.height {
+                    break;
+                }
+
+                let bounds = Rectangle::new(gutter.x, top, gutter.width, bottom - top);
+                labels.draw(
+                    renderer,
+                    text_context,
+                    &style,
+                    &line_number.to_string(),
+                    self.line_number_color,
+                    bounds,
+                    true,
+                    scale_factor,
+                );
+            }
+        }
+
+        renderer.pop_layer();
+    }
+
+    /// Indents the selected lines, or inserts spaces up to the next tab stop if nothing is selected.
+    fn indent(&self, state: &mut TextInputState, text_context: &mut TextContext) {
+        let text = state.editor.raw_text();
+        let selection = state.editor.selection_byte_range();
+
+        if selection.is_empty() {
+            let line_start = line_start(text, selection.start);
+            let column = text[line_start..selection.start].chars().count();
+            let spaces = " ".repeat(self.tab_size - column % self.tab_size);
+            state.edit(text_context, |drv| drv.insert_or_replace_selection(&spaces));
+            return;
+        }
+
+        let lines = selected_lines(text, selection);
+        let indentation = " ".repeat(self.tab_size);
+        let indented: Vec<String> = text[lines.clone()]
+            .split('\n')
+            .map(|line| if line.is_empty() { String::new() } else { format!("{indentation}{line}") })
+            .collect();
+        let indented = indented.join("\n");
+        let selection = lines.start..lines.start + indented.len();
+        state.edit(text_context, |drv| drv.replace_byte_range(lines, &indented, selection));
+    }
+
+    /// Removes one level of indentation from the selected lines.
+    fn outdent(&self, state: &mut TextInputState, text_context: &mut TextContext) {
+        let text = state.editor.raw_text();
+        let selection = state.editor.selection_byte_range();
+        let lines = selected_lines(text, selection.clone());
+
+        let mut removed_before_caret = 0;
+        let mut line_start = lines.start;
+        let mut outdented = Vec::new();
+        for line in text[lines.clone()].split('\n') {
+            let removed = if line.starts_with('\t') {
+                1
+            } else {
+                line.bytes().take(self.tab_size).take_while(|byte| *byte == b' ').count()
+            };
+            if line_start <= selection.start {
+                removed_before_caret = removed.min(selection.start - line_start);
+            }
+            outdented.push(&line[removed..]);
+            line_start += line.len() + 1;
+        }
+        let outdented = outdented.join("\n");
+        if outdented.len() == lines.len() {
+            return;
+        }
+
+        let selection = if selection.is_empty() {
+            let caret = selection.start - removed_before_caret;
+            caret..caret
+        } else {
+            lines.start..lines.start + outdented.len()
+        };
+        state.edit(text_context, |drv| drv.replace_byte_range(lines, &outdented, selection));
+    }
+
+    /// Inserts a line break that keeps the indentation of the current line, and indents once more after an
+    /// opening bracket.
+    fn insert_line_break(&self, state: &mut TextInputState, text_context: &mut TextContext) {
+        let text = state.editor.raw_text();
+        let caret = state.editor.selection_byte_range().start;
+        let line_start = line_start(text, caret);
+        let before_caret = &text[line_start..caret];
+
+        let indentation_length = before_caret.len() - before_caret.trim_start_matches([' ', '\t']).len();
+        let mut line_break = format!("\n{}", &before_caret[..indentation_length]);
+        if before_caret.trim_end().ends_with(['{', '(', '[']) {
+            line_break.push_str(&" ".repeat(self.tab_size));
+        }
+
+        state.edit(text_context, |drv| drv.insert_or_replace_selection(&line_break));
+    }
+
+    generate_component_methods_no_children!();
+}
+
+impl ElementStyles for CodeEditor {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
+
+/// The [`TextInputStateFn`](crate::elements::text_input::TextInputStateFn) of the code editor.
+fn code_editor_text_input_state(data: &mut Box<dyn Any + Send>) -> &mut TextInputState {
+    &mut data.downcast_mut::<CodeEditorState>().unwrap().text_input
+}
+
+/// Measures the text of the code editor, like a text input is measured.
+fn measure_code_editor(
+    component_id: ComponentId,
+    element_state: &mut ElementStateStore,
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    text_context: &mut TextContext,
+) -> Size<f32> {
+    let state: &mut CodeEditorState =
+        element_state.storage.get_mut(&component_id).unwrap().data.downcast_mut().unwrap();
+    state.text_input.measure(known_dimensions, available_space, text_context)
+}
+
+/// Styles the text of the editor with the tokenizer, when it changed since the last time.
+fn tokenize(state: &mut CodeEditorState, tokenizer: &dyn Tokenizer) {
+    let text_input = &mut state.text_input;
+    let generation = text_input.editor.generation();
+    if !text_input.has_new_text() && state.tokenized_generation == Some(generation) {
+        return;
+    }
+    state.tokenized_generation = Some(generation);
+
+    let ranged_styles = state.line_styles.tokenize(tokenizer, text_input.layout_text());
+    text_input.set_ranged_styles(ranged_styles);
+}
+
+/// The byte index of the start of the line that contains `index`.
+fn line_start(text: &str, index: usize) -> usize {
+    text[..index].rfind('\n').map_or(0, |line_break| line_break + 1)
+}
+
+/// The byte range of the lines that the selection touches, without the line break at the end.
+fn selected_lines(text: &str, selection: Range<usize>) -> Range<usize> {
+    // A selection that ends at the start of a line doesn't touch that line.
+    let end = if selection.end > selection.start && text[..selection.end].ends_with('\n') {
+        selection.end - 1
+    } else {
+        selection.end
+    };
+    let line_end = text[end..].find('\n').map_or(text.len(), |line_break| end + line_break);
+    line_start(text, selection.start)..line_end
+}
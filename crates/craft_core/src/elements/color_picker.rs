@@ -0,0 +1,728 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::Event;
+use crate::components::Props;
+use crate::elements::base_element_state::DUMMY_DEVICE_ID;
+use crate::elements::element::Element;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::elements::number_input::NumberInput;
+use crate::elements::picker::{fill_rounded_rect, PICKER_BORDER_COLOR, PICKER_SELECTED_COLOR};
+use crate::elements::slider::{Slider, SliderState};
+use crate::elements::text_input::{TextInput, TextInputState};
+use crate::events::CraftMessage;
+use crate::generate_component_methods_no_children;
+use crate::geometry::{Point, PointConverter, Rectangle, TrblRectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::renderer::Brush;
+use crate::style::{Display, FlexDirection, Style, Unit};
+use crate::text::text_context::TextContext;
+use crate::Color;
+use kurbo::{Affine, Circle, Shape};
+use peniko::color::Rgba8;
+use peniko::Gradient;
+use std::any::Any;
+use std::sync::Arc;
+use taffy::{NodeId, TaffyTree};
+use ui_events::keyboard::{Key, NamedKey};
+use winit::window::Window;
+
+/// The indices of the children that [`ColorPicker::new`] creates.
+const HUE_SLIDER: usize = 0;
+const ALPHA_SLIDER: usize = 1;
+const HEX_INPUT: usize = 2;
+const RED_INPUT: usize = 3;
+const GREEN_INPUT: usize = 4;
+const BLUE_INPUT: usize = 5;
+
+const AREA_HEIGHT: f32 = 150.0;
+const SWATCH_SIZE: f32 = 20.0;
+const SWATCH_GAP: f32 = 4.0;
+/// The size of the squares that are drawn behind transparent colors.
+const CHECKER_SIZE: f32 = 5.0;
+const CHECKER_COLOR: Color = Color::from_rgb8(204, 204, 204);
+
+/// An element for picking a color.
+///
+/// The saturation and value of the color are picked in an area that is drawn with the current hue,
+/// while the hue and the opacity are picked with sliders. The color can also be typed as a hex code or as
+/// red, green and blue channels, or picked from a list of swatches.
+///
+/// Emits [`CraftMessage::ColorChanged`] when the color changes.
+#[derive(Clone)]
+pub struct ColorPicker {
+    pub element_data: ElementData,
+    /// The color from the view. The picker only follows it when it changes, so that the hue is kept
+    /// while picking colors that don't have one, like black or gray.
+    value: Color,
+    swatches: Vec<Color>,
+    /// The taffy nodes of the parts that are not elements, so they are not stored in the user tree.
+    area_node: Option<NodeId>,
+    fields_node: Option<NodeId>,
+    swatches_node: Option<NodeId>,
+    swatch_nodes: Vec<NodeId>,
+    /// The transformed bounds of the saturation and value area.
+    area: Rectangle,
+    /// The transformed bounds of the swatches, in the same order as the swatches.
+    swatch_rectangles: Vec<Rectangle>,
+}
+
+pub struct ColorPickerState {
+    /// The hue in degrees and the saturation, value and alpha between 0 and 1.
+    hsva: [f32; 4],
+    /// The color from the view during the last update.
+    view_value: Rgba8,
+    dragging_area: bool,
+}
+
+impl ColorPickerState {
+    fn color(&self) -> Color {
+        hsva_to_color(self.hsva)
+    }
+}
+
+impl Element for ColorPicker {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "ColorPicker"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<Window>>,
+        scale_factor: f64,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+
+        self.draw_borders(renderer, element_state, scale_factor);
+
+        let state = self.get_state(element_state);
+        let [hue, saturation, value, _alpha] = state.hsva;
+        let color = state.color();
+        let opaque_hue = hsva_to_color([hue, 1.0, 1.0, 1.0]);
+
+        // The area fades from white to the hue horizontally, and to black vertically.
+        let area = self.area;
+        fill_gradient(renderer, area, [Color::WHITE, opaque_hue], false, scale_factor);
+        fill_gradient(renderer, area, [Color::TRANSPARENT, Color::BLACK], true, scale_factor);
+
+        let handle =
+            Point::new((area.x + saturation * area.width) as f64, (area.y + (1.0 - value) * area.height) as f64);
+        fill_circle(renderer, handle, 7.0, Color::WHITE, scale_factor);
+        fill_circle(renderer, handle, 5.0, color.with_alpha(1.0), scale_factor);
+
+        // The sliders are transparent, so that their tracks can be drawn with gradients.
+        if let Some(hue_track) = self.child_rectangle(HUE_SLIDER) {
+            let hues = [0.0, 60.0, 120.0, 180.0, 240.0, 300.0, 360.0].map(|hue| hsva_to_color([hue, 1.0, 1.0, 1.0]));
+            fill_gradient(renderer, hue_track, hues, false, scale_factor);
+        }
+        if let Some(alpha_track) = self.child_rectangle(ALPHA_SLIDER) {
+            draw_checkerboard(renderer, alpha_track, scale_factor);
+            fill_gradient(renderer, alpha_track, [color.with_alpha(0.0), color.with_alpha(1.0)], false, scale_factor);
+        }
+
+        let selected = color.to_rgba8();
+        for (swatch, rectangle) in self.swatches.iter().zip(self.swatch_rectangles.iter()) {
+            let border_color = if swatch.to_rgba8() == selected { PICKER_SELECTED_COLOR } else { PICKER_BORDER_COLOR };
+            fill_rounded_rect(renderer, *rectangle, 4.0, border_color, scale_factor);
+
+            let inner =
+                Rectangle::new(rectangle.x + 2.0, rectangle.y + 2.0, rectangle.width - 4.0, rectangle.height - 4.0);
+            draw_checkerboard(renderer, inner, scale_factor);
+            fill_rounded_rect(renderer, inner, 2.0, *swatch, scale_factor);
+        }
+
+        self.draw_children(renderer, text_context, element_state, pointer, window, scale_factor);
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+        self.update_children(element_state);
+
+        let area_style = taffy::Style {
+            size: taffy::Size {
+                width: taffy::Dimension::percent(1.0),
+                height: taffy::Dimension::length(AREA_HEIGHT),
+            },
+            flex_shrink: 0.0,
+            ..Default::default()
+        };
+        let area_node = taffy_tree.new_leaf(area_style).unwrap();
+        self.area_node = Some(area_node);
+        self.element_data.layout_item.push_child(&Some(area_node));
+
+        let mut field_nodes = Vec::new();
+        for (index, child) in self.element_data.children.iter_mut().enumerate() {
+            let child_node = child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            if index < HEX_INPUT {
+                self.element_data.layout_item.push_child(&child_node);
+            } else {
+                field_nodes.extend(child_node);
+            }
+        }
+
+        // The text fields share a row.
+        let fields_style = taffy::Style {
+            display: taffy::Display::Flex,
+            flex_direction: taffy::FlexDirection::Row,
+            justify_content: Some(taffy::JustifyContent::SpaceBetween),
+            gap: taffy::Size {
+                width: taffy::LengthPercentage::length(4.0),
+                height: taffy::LengthPercentage::length(0.0),
+            },
+            ..Default::default()
+        };
+        let fields_node = taffy_tree.new_with_children(fields_style, &field_nodes).unwrap();
+        self.fields_node = Some(fields_node);
+        self.element_data.layout_item.push_child(&Some(fields_node));
+
+        self.swatch_nodes.clear();
+        self.swatches_node = None;
+        if !self.swatches.is_empty() {
+            let swatch_style = taffy::Style {
+                size: taffy::Size {
+                    width: taffy::Dimension::length(SWATCH_SIZE),
+                    height: taffy::Dimension::length(SWATCH_SIZE),
+                },
+                flex_shrink: 0.0,
+                ..Default::default()
+            };
+            for _ in &self.swatches {
+                self.swatch_nodes.push(taffy_tree.new_leaf(swatch_style.clone()).unwrap());
+            }
+
+            let swatches_style = taffy::Style {
+                display: taffy::Display::Flex,
+                flex_direction: taffy::FlexDirection::Row,
+                flex_wrap: taffy::FlexWrap::Wrap,
+                gap: taffy::Size {
+                    width: taffy::LengthPercentage::length(SWATCH_GAP),
+                    height: taffy::LengthPercentage::length(SWATCH_GAP),
+                },
+                ..Default::default()
+            };
+            let swatches_node = taffy_tree.new_with_children(swatches_style, &self.swatch_nodes).unwrap();
+            self.swatches_node = Some(swatches_node);
+            self.element_data.layout_item.push_child(&Some(swatches_node));
+        }
+
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: Affine,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        let origin = self.element_data.layout_item.computed_box.position;
+        let node_rectangle = |node: NodeId, origin: Point| {
+            let layout = taffy_tree.layout(node).unwrap();
+            let position = transform * (origin + Point::from_taffy_point(layout.location).to_vec2());
+            Rectangle::new(position.x as f32, position.y as f32, layout.size.width, layout.size.height)
+        };
+
+        if let Some(area_node) = self.area_node {
+            self.area = node_rectangle(area_node, origin);
+        }
+
+        self.swatch_rectangles.clear();
+        if let Some(swatches_node) = self.swatches_node {
+            let swatches_origin =
+                origin + Point::from_taffy_point(taffy_tree.layout(swatches_node).unwrap().location).to_vec2();
+            for swatch_node in &self.swatch_nodes {
+                self.swatch_rectangles.push(node_rectangle(*swatch_node, swatches_origin));
+            }
+        }
+
+        // The text fields are laid out in the fields row, so they are positioned relative to it.
+        let fields_origin = match self.fields_node {
+            Some(fields_node) => {
+                origin + Point::from_taffy_point(taffy_tree.layout(fields_node).unwrap().location).to_vec2()
+            }
+            None => origin,
+        };
+
+        for (index, child) in self.element_data.children.iter_mut().enumerate() {
+            let taffy_child_node_id = child.internal.element_data().layout_item.taffy_node_id;
+            if taffy_child_node_id.is_none() {
+                continue;
+            }
+
+            child.internal.finalize_layout(
+                taffy_tree,
+                taffy_child_node_id.unwrap(),
+                if index < HEX_INPUT { origin } else { fields_origin },
+                z_index,
+                transform,
+                element_state,
+                pointer,
+                text_context,
+                self.element_data.layout_item.clip_bounds,
+            );
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+        self.maybe_set_focus(message, event);
+
+        // The child that the message came from, if it came from one of the children.
+        let child = event.target.and_then(|target| {
+            self.element_data.children.iter().position(|child| child.internal.component_id() == target.component_id())
+        });
+
+        let base_state = self.get_base_state_mut(element_state);
+        let focused = base_state.base.focused;
+        let state = base_state.data.as_mut().downcast_mut::<ColorPickerState>().unwrap();
+        let rgba = state.color().to_rgba8();
+
+        match message {
+            CraftMessage::PointerButtonDown(pointer_button) => {
+                let position = Point::new(pointer_button.state.position.x, pointer_button.state.position.y);
+                if !pointer_button.is_primary() || !self.area.contains(&position) {
+                    return;
+                }
+
+                state.dragging_area = true;
+                // FIXME: Turn pointer capture on with the correct device id.
+                base_state.base.pointer_capture.insert(DUMMY_DEVICE_ID, true);
+                self.pick_in_area(state, position, event);
+            }
+            CraftMessage::PointerMovedEvent(pointer_update) => {
+                if state.dragging_area {
+                    let position = Point::new(pointer_update.current.position.x, pointer_update.current.position.y);
+                    self.pick_in_area(state, position, event);
+                }
+            }
+            CraftMessage::PointerButtonUp(pointer_button) => {
+                if state.dragging_area {
+                    state.dragging_area = false;
+                    // FIXME: Turn pointer capture on with the correct device id.
+                    base_state.base.pointer_capture.remove(&DUMMY_DEVICE_ID);
+                    return;
+                }
+
+                if !message.clicked() {
+                    return;
+                }
+                let position = Point::new(pointer_button.state.position.x, pointer_button.state.position.y);
+                if let Some(index) = self.swatch_rectangles.iter().position(|swatch| swatch.contains(&position)) {
+                    set_color(state, self.swatches[index], event);
+                }
+            }
+            CraftMessage::KeyboardInputEvent(keyboard_input) => {
+                if !focused || !keyboard_input.state.is_down() {
+                    return;
+                }
+
+                let step = if keyboard_input.modifiers.shift() { 0.1 } else { 0.01 };
+                let (saturation, value) = match &keyboard_input.key {
+                    Key::Named(NamedKey::ArrowLeft) => (-step, 0.0),
+                    Key::Named(NamedKey::ArrowRight) => (step, 0.0),
+                    Key::Named(NamedKey::ArrowUp) => (0.0, step),
+                    Key::Named(NamedKey::ArrowDown) => (0.0, -step),
+                    _ => return,
+                };
+                state.hsva[1] = (state.hsva[1] + saturation).clamp(0.0, 1.0);
+                state.hsva[2] = (state.hsva[2] + value).clamp(0.0, 1.0);
+                event.prevent_propagate();
+                event.result_message(CraftMessage::ColorChanged(state.color()));
+            }
+            CraftMessage::SliderValueChanged(slider_value) => {
+                match child {
+                    Some(HUE_SLIDER) => state.hsva[0] = *slider_value as f32,
+                    Some(ALPHA_SLIDER) => state.hsva[3] = *slider_value as f32 / 100.0,
+                    _ => return,
+                }
+                event.prevent_propagate();
+                event.result_message(CraftMessage::ColorChanged(state.color()));
+            }
+            CraftMessage::TextInputChanged(text) if child == Some(HEX_INPUT) => {
+                event.prevent_propagate();
+                if let Some(color) = parse_hex(text) {
+                    set_color(state, color, event);
+                }
+            }
+            CraftMessage::NumberChanged(channel) => {
+                let channel = channel.clamp(0.0, 255.0) as u8;
+                let color = match child {
+                    Some(RED_INPUT) => Color::from_rgba8(channel, rgba.g, rgba.b, rgba.a),
+                    Some(GREEN_INPUT) => Color::from_rgba8(rgba.r, channel, rgba.b, rgba.a),
+                    Some(BLUE_INPUT) => Color::from_rgba8(rgba.r, rgba.g, channel, rgba.a),
+                    _ => return,
+                };
+                event.prevent_propagate();
+                set_color(state, color, event);
+            }
+            _ => {}
+        }
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(ColorPickerState {
+                hsva: color_to_hsva(self.value),
+                view_value: self.value.to_rgba8(),
+                dragging_area: false,
+            }),
+        }
+    }
+
+    fn update_state(&mut self, element_state: &mut ElementStateStore, _reload_fonts: bool, _scaling_factor: f64) {
+        let value = self.value.to_rgba8();
+        let state = self.get_state_mut(element_state);
+        if state.view_value == value {
+            return;
+        }
+
+        state.view_value = value;
+        if state.color().to_rgba8() != value {
+            state.hsva = color_to_hsva(self.value);
+        }
+    }
+
+    #[cfg(feature = "accesskit")]
+    fn compute_accessibility_tree(
+        &mut self,
+        tree: &mut accesskit::TreeUpdate,
+        parent_index: Option<usize>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) {
+        let current_node_id = accesskit::NodeId(self.element_data().component_id);
+        let color = self.get_state(element_state).color();
+
+        let mut current_node = accesskit::Node::new(accesskit::Role::ColorWell);
+        current_node.set_value(format_hex(color));
+        current_node.add_action(accesskit::Action::Focus);
+
+        let padding_box =
+            self.element_data().layout_item.computed_box_transformed.padding_rectangle().scale(scale_factor);
+
+        current_node.set_bounds(accesskit::Rect {
+            x0: padding_box.left() as f64,
+            y0: padding_box.top() as f64,
+            x1: padding_box.right() as f64,
+            y1: padding_box.bottom() as f64,
+        });
+
+        let current_index = tree.nodes.len();
+
+        if let Some(parent_index) = parent_index {
+            let parent_node = tree.nodes.get_mut(parent_index).unwrap();
+            parent_node.1.push_child(current_node_id);
+        }
+
+        tree.nodes.push((current_node_id, current_node));
+
+        for child in self.element_data.children.iter_mut() {
+            child.internal.compute_accessibility_tree(tree, Some(current_index), element_state, scale_factor);
+        }
+    }
+
+    fn default_style(&self) -> Style {
+        let mut style = Style::default();
+        *style.display_mut() = Display::Flex;
+        *style.flex_direction_mut() = FlexDirection::Column;
+        *style.gap_mut() = [Unit::Px(10.0), Unit::Px(10.0)];
+        *style.width_mut() = Unit::Px(240.0);
+        *style.padding_mut() = TrblRectangle::new_all(Unit::Px(8.0));
+        *style.background_mut() = Color::WHITE;
+        *style.border_color_mut() = TrblRectangle::new_all(PICKER_BORDER_COLOR);
+        *style.border_width_mut() = TrblRectangle::new_all(Unit::Px(1.0));
+        *style.border_radius_mut() = [(6.0, 6.0); 4];
+
+        style
+    }
+}
+
+impl ColorPicker {
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a ColorPickerState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    fn get_state_mut<'a>(&self, element_state: &'a mut ElementStateStore) -> &'a mut ColorPickerState {
+        self.get_base_state_mut(element_state).data.as_mut().downcast_mut().unwrap()
+    }
+
+    /// Creates a color picker with `value` picked.
+    pub fn new(value: Color) -> ColorPicker {
+        let rgba = value.to_rgba8();
+        let [hue, _saturation, _value, alpha] = color_to_hsva(value);
+
+        let mut thumb_style = Style::default();
+        *thumb_style.background_mut() = Color::WHITE;
+        *thumb_style.border_color_mut() = TrblRectangle::new_all(PICKER_BORDER_COLOR);
+        *thumb_style.border_width_mut() = TrblRectangle::new_all(Unit::Px(1.0));
+        *thumb_style.border_radius_mut() = [(8.0, 8.0); 4];
+        let slider = |max: f64, value: f64| {
+            Slider::new(16.0)
+                .max(max)
+                .value(value)
+                .value_track_color(None)
+                .thumb_style(thumb_style)
+                .background(Color::TRANSPARENT)
+                .width(Unit::Percentage(100.0))
+        };
+        let channel = |value: u8| NumberInput::new(value as f64).min(0.0).max(255.0).width(Unit::Px(44.0));
+
+        let mut color_picker = ColorPicker {
+            element_data: Default::default(),
+            value,
+            swatches: Vec::new(),
+            area_node: None,
+            fields_node: None,
+            swatches_node: None,
+            swatch_nodes: Vec::new(),
+            area: Rectangle::default(),
+            swatch_rectangles: Vec::new(),
+        };
+
+        let children: [ComponentSpecification; 6] = [
+            slider(360.0, hue as f64).into(),
+            slider(100.0, (alpha * 100.0).round() as f64).into(),
+            TextInput::new(&format_hex(value)).width(Unit::Px(76.0)).into(),
+            channel(rgba.r).into(),
+            channel(rgba.g).into(),
+            channel(rgba.b).into(),
+        ];
+        color_picker.element_data.child_specs.extend(children);
+
+        color_picker
+    }
+
+    /// Set the colors that can be picked with a single click.
+    pub fn swatches(mut self, swatches: Vec<Color>) -> Self {
+        self.swatches = swatches;
+        self
+    }
+
+    /// The transformed bounds of a child.
+    fn child_rectangle(&self, index: usize) -> Option<Rectangle> {
+        self.element_data.children.get(index).map(|child| child.internal.computed_box_transformed().border_rectangle())
+    }
+
+    /// Shows the picked color in the sliders and the text fields that are not being used.
+    fn update_children(&self, element_state: &mut ElementStateStore) {
+        let state = self.get_state(element_state);
+        let [hue, _saturation, _value, alpha] = state.hsva;
+        let color = state.color();
+        let rgba = color.to_rgba8();
+
+        for (index, child) in self.element_data.children.iter().enumerate() {
+            let Some(item) = element_state.storage.get_mut(&child.internal.component_id()) else {
+                continue;
+            };
+            if item.base.focused {
+                continue;
+            }
+
+            if let Some(slider_state) = item.data.downcast_mut::<SliderState>() {
+                if !slider_state.dragging {
+                    slider_state.value = if index == HUE_SLIDER { hue as f64 } else { (alpha * 100.0).round() as f64 };
+                }
+            } else if let Some(text_input_state) = item.data.downcast_mut::<TextInputState>() {
+                let text = match index {
+                    HEX_INPUT => format_hex(color),
+                    RED_INPUT => rgba.r.to_string(),
+                    GREEN_INPUT => rgba.g.to_string(),
+                    BLUE_INPUT => rgba.b.to_string(),
+                    _ => continue,
+                };
+                if text_input_state.text() != text {
+                    text_input_state.set_text(&text);
+                }
+            }
+        }
+    }
+
+    /// Picks the saturation and value at `position` in the area.
+    fn pick_in_area(&self, state: &mut ColorPickerState, position: Point, event: &mut Event) {
+        let area = self.area;
+        state.hsva[1] = ((position.x as f32 - area.x) / area.width).clamp(0.0, 1.0);
+        state.hsva[2] = (1.0 - (position.y as f32 - area.y) / area.height).clamp(0.0, 1.0);
+        event.result_message(CraftMessage::ColorChanged(state.color()));
+    }
+
+    generate_component_methods_no_children!();
+}
+
+impl ElementStyles for ColorPicker {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
+
+/// Picks `color`, keeping the hue when the color doesn't have one.
+fn set_color(state: &mut ColorPickerState, color: Color, event: &mut Event) {
+    let hue = state.hsva[0];
+    state.hsva = color_to_hsva(color);
+    if state.hsva[1] == 0.0 || state.hsva[2] == 0.0 {
+        state.hsva[0] = hue;
+    }
+    event.result_message(CraftMessage::ColorChanged(state.color()));
+}
+
+fn hsva_to_color([hue, saturation, value, alpha]: [f32; 4]) -> Color {
+    let chroma = value * saturation;
+    let sector = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (red, green, blue) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = value - chroma;
+
+    Color::new([red + m, green + m, blue + m, alpha])
+}
+
+fn color_to_hsva(color: Color) -> [f32; 4] {
+    let [red, green, blue, alpha] = color.components;
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let delta = max - min;
+
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == red {
+        60.0 * ((green - blue) / delta).rem_euclid(6.0)
+    } else if max == green {
+        60.0 * ((blue - red) / delta + 2.0)
+    } else {
+        60.0 * ((red - green) / delta + 4.0)
+    };
+    let saturation = if max > 0.0 { delta / max } else { 0.0 };
+
+    [hue, saturation, max, alpha]
+}
+
+/// Formats a color as `#rrggbb`, or as `#rrggbbaa` when it is not opaque.
+fn format_hex(color: Color) -> String {
+    let Rgba8 { r, g, b, a } = color.to_rgba8();
+    if a == 255 {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    }
+}
+
+/// Parses `#rgb`, `#rrggbb` or `#rrggbbaa`, with or without the `#`.
+fn parse_hex(text: &str) -> Option<Color> {
+    let hex = text.trim().trim_start_matches('#');
+    if !hex.is_ascii() {
+        return None;
+    }
+
+    let channel = |index: usize| u8::from_str_radix(&hex[index * 2..index * 2 + 2], 16).ok();
+    match hex.len() {
+        3 => {
+            let mut channels = hex.chars().map(|digit| digit.to_digit(16).map(|digit| digit as u8 * 17));
+            Some(Color::from_rgb8(channels.next()??, channels.next()??, channels.next()??))
+        }
+        6 => Some(Color::from_rgb8(channel(0)?, channel(1)?, channel(2)?)),
+        8 => Some(Color::from_rgba8(channel(0)?, channel(1)?, channel(2)?, channel(3)?)),
+        _ => None,
+    }
+}
+
+/// Fills a rectangle given in logical window coordinates with colors that are evenly spread from left to right,
+/// or from top to bottom.
+fn fill_gradient<const N: usize>(
+    renderer: &mut RenderList,
+    rectangle: Rectangle,
+    colors: [Color; N],
+    vertical: bool,
+    scale_factor: f64,
+) {
+    let rectangle = rectangle.scale(scale_factor);
+    let start = kurbo::Point::new(rectangle.left() as f64, rectangle.top() as f64);
+    let end = if vertical {
+        kurbo::Point::new(rectangle.left() as f64, rectangle.bottom() as f64)
+    } else {
+        kurbo::Point::new(rectangle.right() as f64, rectangle.top() as f64)
+    };
+
+    let path = kurbo::Rect::new(start.x, start.y, rectangle.right() as f64, rectangle.bottom() as f64).to_path(0.1);
+    renderer.fill_bez_path(path, Brush::Gradient(Gradient::new_linear(start, end).with_stops(colors)));
+}
+
+fn fill_circle(renderer: &mut RenderList, center: Point, radius: f64, color: Color, scale_factor: f64) {
+    let mut path = Circle::new(center, radius).to_path(0.1);
+    path.apply_affine(Affine::scale(scale_factor));
+    renderer.fill_bez_path(path, Brush::Color(color));
+}
+
+/// Draws the squares that show through transparent colors.
+fn draw_checkerboard(renderer: &mut RenderList, rectangle: Rectangle, scale_factor: f64) {
+    renderer.draw_rect(rectangle.scale(scale_factor), Color::WHITE);
+
+    renderer.push_layer(rectangle.scale(scale_factor));
+    let columns = (rectangle.width / CHECKER_SIZE).ceil() as usize;
+    let rows = (rectangle.height / CHECKER_SIZE).ceil() as usize;
+    for row in 0..rows {
+        for column in (row % 2..columns).step_by(2) {
+            let square = Rectangle::new(
+                rectangle.x + column as f32 * CHECKER_SIZE,
+                rectangle.y + row as f32 * CHECKER_SIZE,
+                CHECKER_SIZE,
+                CHECKER_SIZE,
+            );
+            renderer.draw_rect(square.scale(scale_factor), CHECKER_COLOR);
+        }
+    }
+    renderer.pop_layer();
+}
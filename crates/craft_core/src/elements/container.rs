@@ -13,6 +13,7 @@ use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreI
 use crate::renderer::renderer::RenderList;
 use crate::style::Style;
 use crate::text::text_context::TextContext;
+use crate::text::text_selection::select_all_text;
 use std::any::Any;
 use std::sync::Arc;
 use kurbo::Affine;
@@ -25,6 +26,12 @@ pub struct Container {
     pub element_data: ElementData,
 }
 
+/// An external message that allows others to command the Container.
+pub enum ContainerMessage {
+    /// Selects the text of all the `Text` elements in the container.
+    SelectAllText,
+}
+
 #[derive(Clone, Copy, Default)]
 pub struct ContainerState {
     pub(crate) scroll_state: ScrollState,
@@ -169,6 +176,12 @@ impl Element for Container {
         let container_state = base_state.data.as_mut().downcast_mut::<ContainerState>().unwrap();
 
         container_state.scroll_state.on_event(message, &self.element_data, &mut base_state.base, event);
+
+        if let CraftMessage::ElementMessage(msg) = message {
+            if let Some(ContainerMessage::SelectAllText) = msg.downcast_ref::<ContainerMessage>() {
+                select_all_text(self, element_state);
+            }
+        }
     }
 
     fn resolve_clip(&mut self, clip_bounds: Option<Rectangle>) {
@@ -0,0 +1,771 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::Props;
+use crate::components::{Event, FocusAction};
+use crate::elements::element::Element;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+#[cfg(feature = "accesskit")]
+use crate::elements::picker::to_accesskit_rect;
+use crate::elements::picker::{
+    default_picker_field_style, draw_popover_panel, fill_rounded_rect, inset, is_picker_field_focused, picker_field,
+    set_picker_field_text, PICKER_DISABLED_TEXT_COLOR, PICKER_HOVER_COLOR, PICKER_MUTED_TEXT_COLOR, PICKER_RADIUS,
+    PICKER_SELECTED_COLOR, PICKER_SELECTED_TEXT_COLOR,
+};
+use crate::events::CraftMessage;
+use crate::generate_component_methods_no_children;
+use crate::geometry::{Point, Rectangle, Size};
+use crate::layout::layout_context::LayoutContext;
+use crate::layout::popover::Placement;
+#[cfg(feature = "accesskit")]
+use crate::reactive::element_id::create_unique_element_id;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::Style;
+use crate::text::label_cache::LabelCache;
+use crate::text::text_context::TextContext;
+use chrono::{DateTime, Datelike, Months, NaiveDate, TimeDelta, Utc, Weekday};
+use kurbo::Affine;
+use std::any::Any;
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time;
+use taffy::{NodeId, TaffyTree};
+use ui_events::keyboard::{Key, NamedKey};
+#[cfg(target_arch = "wasm32")]
+use web_time as time;
+use winit::window::Window;
+
+/// The size of a day in the calendar in logical pixels.
+const CELL_SIZE: f32 = 32.0;
+const CALENDAR_PADDING: f32 = 8.0;
+/// The height of the row with the month and the month navigation buttons.
+const HEADER_HEIGHT: f32 = 32.0;
+const WEEKDAY_ROW_HEIGHT: f32 = 24.0;
+/// Enough rows for any month, no matter which weekday it starts on.
+const GRID_ROWS: usize = 6;
+const CALENDAR_WIDTH: f32 = 7.0 * CELL_SIZE + 2.0 * CALENDAR_PADDING;
+const CALENDAR_HEIGHT: f32 =
+    CALENDAR_PADDING + HEADER_HEIGHT + WEEKDAY_ROW_HEIGHT + GRID_ROWS as f32 * CELL_SIZE + CALENDAR_PADDING;
+
+/// A text field for a date that opens a calendar to pick one.
+///
+/// The date can be typed or pasted in the `format` of the picker. The calendar is drawn in the overlay layer under
+/// the field. While the field is focused, the arrow keys move between days, Page Up and Page Down move between
+/// months, Enter picks the highlighted day and Escape closes the calendar. Days outside of the `min` and `max` dates
+/// and disabled days can't be picked or typed.
+///
+/// Emits [`CraftMessage::DateChanged`] when a day is picked, or when the typed text is a day that can be picked.
+#[derive(Clone)]
+pub struct DatePicker {
+    pub element_data: ElementData,
+    /// The date that is picked when the element is created.
+    value: Option<NaiveDate>,
+    min: Option<NaiveDate>,
+    max: Option<NaiveDate>,
+    is_day_disabled: Option<Arc<dyn Fn(NaiveDate) -> bool + Send + Sync>>,
+    /// The day that the weeks in the calendar start on.
+    first_weekday: Weekday,
+    /// How the picked date is shown in and read from the field, see [`chrono::format::strftime`].
+    format: String,
+    /// The text shown in the field while no date is picked.
+    placeholder: String,
+    /// Where the calendar is placed relative to the field.
+    calendar_placement: Placement,
+    /// Where the calendar was placed in window coordinates, `None` while it is closed.
+    calendar: Option<Rectangle>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CalendarTarget {
+    PreviousMonth,
+    NextMonth,
+    Day(NaiveDate),
+}
+
+pub struct DatePickerState {
+    is_open: bool,
+    selected: Option<NaiveDate>,
+    /// The date from the view during the last update.
+    view_value: Option<NaiveDate>,
+    /// The day that the keyboard moves from, it is highlighted while the calendar is open.
+    focused_day: NaiveDate,
+    /// The first day of the month shown in the calendar.
+    visible_month: NaiveDate,
+    hovered: Option<CalendarTarget>,
+    /// Whether the field shows the picked date even while it is focused, because it was picked in the calendar or
+    /// changed by the view.
+    update_field: bool,
+    labels: LabelCache,
+}
+
+impl DatePickerState {
+    pub(crate) fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+
+impl Element for DatePicker {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    /// An open calendar claims every point in the window, so that a click outside of it closes the calendar.
+    fn in_bounds(&self, point: Point) -> bool {
+        if self.calendar.is_some() {
+            return true;
+        }
+
+        let element_data = self.element_data();
+        let rect = element_data.layout_item.computed_box_transformed.border_rectangle();
+
+        if let Some(clip) = element_data.layout_item.clip_bounds {
+            match rect.intersection(&clip) {
+                Some(bounds) => bounds.contains(&point),
+                None => false,
+            }
+        } else {
+            rect.contains(&point)
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "DatePicker"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<Window>>,
+        scale_factor: f64,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+
+        self.draw_borders(renderer, element_state, scale_factor);
+        self.draw_children(renderer, text_context, element_state, pointer, window, scale_factor);
+
+        let Some(calendar) = self.calendar else {
+            return;
+        };
+
+        let style = *self.element_data.current_style();
+        let state = self.get_state_mut(element_state);
+
+        renderer.start_overlay();
+        draw_popover_panel(renderer, calendar, scale_factor);
+
+        // The month and the buttons that move to the previous and next month.
+        let previous_month = previous_month_rectangle(calendar);
+        let next_month = next_month_rectangle(calendar);
+        for (target, rectangle, text, months) in
+            [(CalendarTarget::PreviousMonth, previous_month, "‹", -1), (CalendarTarget::NextMonth, next_month, "›", 1)]
+        {
+            let is_enabled = self.is_month_in_range(add_months(state.visible_month, months));
+            if is_enabled && state.hovered == Some(target) {
+                fill_rounded_rect(renderer, inset(rectangle), PICKER_RADIUS, PICKER_HOVER_COLOR, scale_factor);
+            }
+            let color = if is_enabled { style.color() } else { PICKER_DISABLED_TEXT_COLOR };
+            state.labels.draw(renderer, text_context, &style, text, color, rectangle, true, scale_factor);
+        }
+        let title = Rectangle::new(
+            previous_month.right(),
+            previous_month.y,
+            next_month.left() - previous_month.right(),
+            HEADER_HEIGHT,
+        );
+        let title_text = state.visible_month.format("%B %Y").to_string();
+        state.labels.draw(renderer, text_context, &style, &title_text, style.color(), title, true, scale_factor);
+
+        // The names of the days, starting with the first day of the week.
+        let mut weekday = self.first_weekday;
+        for column in 0..7 {
+            let rectangle = Rectangle::new(
+                calendar.x + CALENDAR_PADDING + column as f32 * CELL_SIZE,
+                calendar.y + CALENDAR_PADDING + HEADER_HEIGHT,
+                CELL_SIZE,
+                WEEKDAY_ROW_HEIGHT,
+            );
+            let name = weekday.to_string();
+            let short_name = &name[..2];
+            state.labels.draw(
+                renderer,
+                text_context,
+                &style,
+                short_name,
+                PICKER_MUTED_TEXT_COLOR,
+                rectangle,
+                true,
+                scale_factor,
+            );
+            weekday = weekday.succ();
+        }
+
+        // The days of the visible month.
+        let today = today();
+        let first_day = self.first_grid_day(state.visible_month);
+        for index in 0..GRID_ROWS * 7 {
+            let day = add_days(first_day, index as i64);
+            if day.month() != state.visible_month.month() {
+                continue;
+            }
+
+            let rectangle = day_rectangle(calendar, index);
+            let is_selectable = self.is_selectable(day);
+            let is_highlighted = day == state.focused_day || state.hovered == Some(CalendarTarget::Day(day));
+
+            let color = if state.selected == Some(day) {
+                fill_rounded_rect(renderer, inset(rectangle), PICKER_RADIUS, PICKER_SELECTED_COLOR, scale_factor);
+                PICKER_SELECTED_TEXT_COLOR
+            } else {
+                if is_highlighted && is_selectable {
+                    fill_rounded_rect(renderer, inset(rectangle), PICKER_RADIUS, PICKER_HOVER_COLOR, scale_factor);
+                }
+                if !is_selectable {
+                    PICKER_DISABLED_TEXT_COLOR
+                } else if day == today {
+                    PICKER_SELECTED_COLOR
+                } else {
+                    style.color()
+                }
+            };
+            let text = day.day().to_string();
+            state.labels.draw(renderer, text_context, &style, &text, color, rectangle, true, scale_factor);
+        }
+        renderer.end_overlay();
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+        self.update_field(element_state);
+
+        for child in self.element_data.children.iter_mut() {
+            let child_node = child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&child_node);
+        }
+
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: Affine,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        let origin = self.element_data.layout_item.computed_box.position;
+        for child in self.element_data.children.iter_mut() {
+            let Some(taffy_child_node_id) = child.internal.element_data().layout_item.taffy_node_id else {
+                continue;
+            };
+
+            child.internal.finalize_layout(
+                taffy_tree,
+                taffy_child_node_id,
+                origin,
+                z_index,
+                transform,
+                element_state,
+                pointer,
+                text_context,
+                self.element_data.layout_item.clip_bounds,
+            );
+        }
+
+        self.calendar = if self.get_state(element_state).is_open {
+            let anchor = self.computed_box_transformed().border_rectangle();
+            let size = Size::new(CALENDAR_WIDTH, CALENDAR_HEIGHT);
+            Some(self.calendar_placement.resolve(anchor, size, element_state.viewport))
+        } else {
+            None
+        };
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+
+        // The field is focused instead of the picker, so that it can be typed in.
+        let focused = is_picker_field_focused(&self.element_data, element_state);
+        let field = self.computed_box_transformed().border_rectangle();
+        let state = self.get_state_mut(element_state);
+
+        match message {
+            CraftMessage::PointerButtonDown(pointer_button) => {
+                let Some(calendar) = self.calendar else {
+                    return;
+                };
+                event.prevent_propagate();
+
+                let position = Point::new(pointer_button.state.position.x, pointer_button.state.position.y);
+                if !calendar.contains(&position) && !field.contains(&position) {
+                    state.is_open = false;
+                    event.focus_action(FocusAction::Unset);
+                }
+            }
+            CraftMessage::PointerButtonUp(pointer_button) => {
+                if !message.clicked() {
+                    return;
+                }
+
+                // Clicking in the open field only moves the caret.
+                let position = Point::new(pointer_button.state.position.x, pointer_button.state.position.y);
+                if field.contains(&position) {
+                    if !state.is_open {
+                        self.open(state);
+                    }
+                    return;
+                }
+
+                let Some(calendar) = self.calendar else {
+                    return;
+                };
+                event.prevent_propagate();
+                match calendar_target(calendar, self.first_grid_day(state.visible_month), state.visible_month, position)
+                {
+                    Some(CalendarTarget::PreviousMonth) => self.show_month(state, -1),
+                    Some(CalendarTarget::NextMonth) => self.show_month(state, 1),
+                    Some(CalendarTarget::Day(day)) => self.select(state, day, event),
+                    None => {}
+                }
+            }
+            CraftMessage::PointerMovedEvent(pointer_update) => {
+                let Some(calendar) = self.calendar else {
+                    return;
+                };
+
+                let position = Point::new(pointer_update.current.position.x, pointer_update.current.position.y);
+                state.hovered =
+                    calendar_target(calendar, self.first_grid_day(state.visible_month), state.visible_month, position);
+            }
+            CraftMessage::KeyboardInputEvent(keyboard_input) => {
+                if !focused || !keyboard_input.state.is_down() {
+                    return;
+                }
+
+                if !state.is_open {
+                    if matches!(&keyboard_input.key, Key::Named(NamedKey::Enter | NamedKey::ArrowDown)) {
+                        self.open(state);
+                        event.prevent_propagate();
+                    }
+                    return;
+                }
+                event.prevent_propagate();
+
+                // Page Up and Page Down move by a year when shift is held.
+                let months = if keyboard_input.modifiers.shift() { 12 } else { 1 };
+                let focused_day = state.focused_day;
+                let days_from_week_start =
+                    (focused_day.weekday().num_days_from_monday() + 7 - self.first_weekday.num_days_from_monday()) % 7;
+
+                match &keyboard_input.key {
+                    Key::Named(NamedKey::Escape) => state.is_open = false,
+                    Key::Named(NamedKey::ArrowLeft) => self.focus_day(state, add_days(focused_day, -1)),
+                    Key::Named(NamedKey::ArrowRight) => self.focus_day(state, add_days(focused_day, 1)),
+                    Key::Named(NamedKey::ArrowUp) => self.focus_day(state, add_days(focused_day, -7)),
+                    Key::Named(NamedKey::ArrowDown) => self.focus_day(state, add_days(focused_day, 7)),
+                    Key::Named(NamedKey::Home) => {
+                        self.focus_day(state, add_days(focused_day, -(days_from_week_start as i64)))
+                    }
+                    Key::Named(NamedKey::End) => {
+                        self.focus_day(state, add_days(focused_day, 6 - days_from_week_start as i64))
+                    }
+                    Key::Named(NamedKey::PageUp) => self.focus_day(state, add_months(focused_day, -months)),
+                    Key::Named(NamedKey::PageDown) => self.focus_day(state, add_months(focused_day, months)),
+                    Key::Named(NamedKey::Enter) => self.select(state, focused_day, event),
+                    _ => {}
+                }
+            }
+            CraftMessage::TextInputChanged(text) => {
+                event.prevent_propagate();
+                let Ok(day) = NaiveDate::parse_from_str(text.trim(), &self.format) else {
+                    return;
+                };
+                if state.selected == Some(day) || !self.is_selectable(day) {
+                    return;
+                }
+
+                // The calendar follows the typed date, but stays open.
+                state.selected = Some(day);
+                self.focus_day(state, day);
+                event.result_message(CraftMessage::DateChanged(day));
+            }
+            _ => {}
+        }
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        let focused_day = self.clamp(self.value.unwrap_or_else(today));
+
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(DatePickerState {
+                is_open: false,
+                selected: self.value,
+                view_value: self.value,
+                focused_day,
+                visible_month: first_of_month(focused_day),
+                hovered: None,
+                update_field: false,
+                labels: LabelCache::default(),
+            }),
+        }
+    }
+
+    fn update_state(&mut self, element_state: &mut ElementStateStore, _reload_fonts: bool, _scaling_factor: f64) {
+        let state = self.get_base_state_mut(element_state).data.as_mut().downcast_mut::<DatePickerState>().unwrap();
+        if state.view_value == self.value {
+            return;
+        }
+
+        state.view_value = self.value;
+        state.selected = self.value;
+        state.update_field = true;
+        if let Some(value) = self.value {
+            state.focused_day = self.clamp(value);
+            state.visible_month = first_of_month(state.focused_day);
+        }
+    }
+
+    #[cfg(feature = "accesskit")]
+    fn compute_accessibility_tree(
+        &mut self,
+        tree: &mut accesskit::TreeUpdate,
+        parent_index: Option<usize>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) {
+        let current_node_id = accesskit::NodeId(self.element_data().component_id);
+        let state = self.get_state(element_state);
+
+        let mut current_node = accesskit::Node::new(accesskit::Role::DateInput);
+        if let Some(selected) = state.selected {
+            current_node.set_value(selected.format(&self.format).to_string());
+        }
+        current_node.set_has_popup(accesskit::HasPopup::Dialog);
+        current_node.set_expanded(self.calendar.is_some());
+        current_node.add_action(accesskit::Action::Click);
+
+        let padding_box =
+            self.element_data().layout_item.computed_box_transformed.padding_rectangle().scale(scale_factor);
+
+        current_node.set_bounds(accesskit::Rect {
+            x0: padding_box.left() as f64,
+            y0: padding_box.top() as f64,
+            x1: padding_box.right() as f64,
+            y1: padding_box.bottom() as f64,
+        });
+
+        // The calendar is exposed as a grid of the days in the visible month.
+        if let Some(calendar) = self.calendar {
+            let grid_id = accesskit::NodeId(create_unique_element_id());
+            let mut grid = accesskit::Node::new(accesskit::Role::Grid);
+            grid.set_label(state.visible_month.format("%B %Y").to_string());
+            grid.set_bounds(to_accesskit_rect(calendar.scale(scale_factor)));
+
+            let first_day = self.first_grid_day(state.visible_month);
+            for index in 0..GRID_ROWS * 7 {
+                let day = add_days(first_day, index as i64);
+                if day.month() != state.visible_month.month() {
+                    continue;
+                }
+
+                let cell_id = accesskit::NodeId(create_unique_element_id());
+                let mut cell = accesskit::Node::new(accesskit::Role::GridCell);
+                cell.set_label(day.format("%A, %B %-d, %Y").to_string());
+                cell.set_bounds(to_accesskit_rect(day_rectangle(calendar, index).scale(scale_factor)));
+                cell.set_selected(state.selected == Some(day));
+                if !self.is_selectable(day) {
+                    cell.set_disabled();
+                }
+                grid.push_child(cell_id);
+                tree.nodes.push((cell_id, cell));
+            }
+
+            current_node.push_child(grid_id);
+            tree.nodes.push((grid_id, grid));
+        }
+
+        let current_index = tree.nodes.len();
+
+        if let Some(parent_index) = parent_index {
+            let parent_node = tree.nodes.get_mut(parent_index).unwrap();
+            parent_node.1.push_child(current_node_id);
+        }
+
+        tree.nodes.push((current_node_id, current_node));
+
+        for child in self.element_data.children.iter_mut() {
+            child.internal.compute_accessibility_tree(tree, Some(current_index), element_state, scale_factor);
+        }
+    }
+
+    fn default_style(&self) -> Style {
+        default_picker_field_style()
+    }
+}
+
+impl DatePicker {
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a DatePickerState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    fn get_state_mut<'a>(&self, element_state: &'a mut ElementStateStore) -> &'a mut DatePickerState {
+        self.get_base_state_mut(element_state).data.as_mut().downcast_mut().unwrap()
+    }
+
+    /// Creates a date picker with `value` picked.
+    pub fn new(value: Option<NaiveDate>) -> DatePicker {
+        let mut date_picker = DatePicker {
+            element_data: Default::default(),
+            value,
+            min: None,
+            max: None,
+            is_day_disabled: None,
+            first_weekday: Weekday::Mon,
+            format: "%Y-%m-%d".to_string(),
+            placeholder: "YYYY-MM-DD".to_string(),
+            calendar_placement: Placement::default().offset(4.0),
+            calendar: None,
+        };
+        date_picker.set_field_spec();
+
+        date_picker
+    }
+
+    /// Set the earliest date that can be picked.
+    pub fn min(mut self, min: NaiveDate) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set the latest date that can be picked.
+    pub fn max(mut self, max: NaiveDate) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Disables the days for which `is_day_disabled` returns true, for example weekends or holidays.
+    pub fn disabled_days<F>(mut self, is_day_disabled: F) -> Self
+    where
+        F: Fn(NaiveDate) -> bool + Send + Sync + 'static,
+    {
+        self.is_day_disabled = Some(Arc::new(is_day_disabled));
+        self
+    }
+
+    /// Set the day that the weeks in the calendar start on. Defaults to Monday.
+    pub fn first_weekday(mut self, weekday: Weekday) -> Self {
+        self.first_weekday = weekday;
+        self
+    }
+
+    /// Set how the picked date is shown in the field, see [`chrono::format::strftime`]. Defaults to `%Y-%m-%d`.
+    pub fn format(mut self, format: &str) -> Self {
+        self.format = format.to_string();
+        self.set_field_spec();
+        self
+    }
+
+    /// Set the text shown in the field while no date is picked.
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = placeholder.to_string();
+        self.set_field_spec();
+        self
+    }
+
+    /// Sets where the calendar is placed relative to the field.
+    /// By default, the calendar is placed below the field and flips above it when there is not enough room.
+    pub fn calendar_placement(mut self, placement: Placement) -> Self {
+        self.calendar_placement = placement;
+        self
+    }
+
+    /// The picked date in the format of the picker.
+    fn field_text(&self, selected: Option<NaiveDate>) -> String {
+        selected.map(|selected| selected.format(&self.format).to_string()).unwrap_or_default()
+    }
+
+    fn set_field_spec(&mut self) {
+        self.element_data.child_specs = vec![picker_field(&self.field_text(self.value), &self.placeholder)];
+    }
+
+    /// Shows the picked date in the field, unless it is being typed.
+    fn update_field(&self, element_state: &mut ElementStateStore) {
+        let state = self.get_state_mut(element_state);
+        let text = self.field_text(state.selected);
+        let force = std::mem::take(&mut state.update_field);
+        set_picker_field_text(&self.element_data, element_state, &text, force);
+    }
+
+    fn clamp(&self, day: NaiveDate) -> NaiveDate {
+        let day = self.min.map_or(day, |min| day.max(min));
+        self.max.map_or(day, |max| day.min(max))
+    }
+
+    fn is_selectable(&self, day: NaiveDate) -> bool {
+        self.clamp(day) == day && !self.is_day_disabled.as_ref().is_some_and(|is_day_disabled| is_day_disabled(day))
+    }
+
+    /// Whether any day of the month that starts at `month` is in the range.
+    fn is_month_in_range(&self, month: NaiveDate) -> bool {
+        let next_month = add_months(month, 1);
+        self.min.is_none_or(|min| next_month > min) && self.max.is_none_or(|max| month <= max)
+    }
+
+    /// The day in the top left corner of the calendar for the month that starts at `month`.
+    fn first_grid_day(&self, month: NaiveDate) -> NaiveDate {
+        let offset = (month.weekday().num_days_from_monday() + 7 - self.first_weekday.num_days_from_monday()) % 7;
+        add_days(month, -(offset as i64))
+    }
+
+    fn open(&self, state: &mut DatePickerState) {
+        state.is_open = true;
+        state.hovered = None;
+        let day = state.selected.unwrap_or_else(today);
+        self.focus_day(state, day);
+    }
+
+    /// Highlights `day` and shows its month.
+    fn focus_day(&self, state: &mut DatePickerState, day: NaiveDate) {
+        state.focused_day = self.clamp(day);
+        state.visible_month = first_of_month(state.focused_day);
+    }
+
+    /// Moves the calendar by a number of months, if the month has days that can be picked.
+    fn show_month(&self, state: &mut DatePickerState, months: i32) {
+        let month = add_months(state.visible_month, months);
+        if !self.is_month_in_range(month) {
+            return;
+        }
+
+        state.visible_month = month;
+        state.focused_day = self.clamp(add_months(state.focused_day, months));
+    }
+
+    fn select(&self, state: &mut DatePickerState, day: NaiveDate, event: &mut Event) {
+        if !self.is_selectable(day) {
+            return;
+        }
+
+        state.selected = Some(day);
+        state.focused_day = day;
+        state.is_open = false;
+        state.update_field = true;
+        event.result_message(CraftMessage::DateChanged(day));
+    }
+
+    generate_component_methods_no_children!();
+}
+
+impl ElementStyles for DatePicker {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
+
+/// The current date in UTC. The calendar starts here when no date is picked.
+fn today() -> NaiveDate {
+    let since_epoch = time::SystemTime::now().duration_since(time::UNIX_EPOCH).unwrap_or_default();
+    DateTime::<Utc>::from_timestamp(since_epoch.as_secs() as i64, 0).map(|now| now.date_naive()).unwrap_or_default()
+}
+
+fn first_of_month(day: NaiveDate) -> NaiveDate {
+    day.with_day(1).unwrap()
+}
+
+fn add_days(day: NaiveDate, days: i64) -> NaiveDate {
+    day.checked_add_signed(TimeDelta::days(days)).unwrap_or(day)
+}
+
+/// Adds a number of months, the day is clamped to the last day of the resulting month.
+fn add_months(day: NaiveDate, months: i32) -> NaiveDate {
+    let result = if months >= 0 {
+        day.checked_add_months(Months::new(months as u32))
+    } else {
+        day.checked_sub_months(Months::new(months.unsigned_abs()))
+    };
+    result.unwrap_or(day)
+}
+
+fn previous_month_rectangle(calendar: Rectangle) -> Rectangle {
+    Rectangle::new(calendar.x + CALENDAR_PADDING, calendar.y + CALENDAR_PADDING, CELL_SIZE, HEADER_HEIGHT)
+}
+
+fn next_month_rectangle(calendar: Rectangle) -> Rectangle {
+    Rectangle::new(
+        calendar.right() - CALENDAR_PADDING - CELL_SIZE,
+        calendar.y + CALENDAR_PADDING,
+        CELL_SIZE,
+        HEADER_HEIGHT,
+    )
+}
+
+/// The rectangle of the day at `index` in the grid, counting from the top left.
+fn day_rectangle(calendar: Rectangle, index: usize) -> Rectangle {
+    let row = (index / 7) as f32;
+    let column = (index % 7) as f32;
+    Rectangle::new(
+        calendar.x + CALENDAR_PADDING + column * CELL_SIZE,
+        calendar.y + CALENDAR_PADDING + HEADER_HEIGHT + WEEKDAY_ROW_HEIGHT + row * CELL_SIZE,
+        CELL_SIZE,
+        CELL_SIZE,
+    )
+}
+
+/// Finds the button or the day of the visible month at `point`.
+fn calendar_target(
+    calendar: Rectangle,
+    first_grid_day: NaiveDate,
+    visible_month: NaiveDate,
+    point: Point,
+) -> Option<CalendarTarget> {
+    if previous_month_rectangle(calendar).contains(&point) {
+        return Some(CalendarTarget::PreviousMonth);
+    }
+    if next_month_rectangle(calendar).contains(&point) {
+        return Some(CalendarTarget::NextMonth);
+    }
+
+    (0..GRID_ROWS * 7)
+        .find(|index| day_rectangle(calendar, *index).contains(&point))
+        .map(|index| add_days(first_grid_day, index as i64))
+        .filter(|day| day.month() == visible_month.month())
+        .map(CalendarTarget::Day)
+}
@@ -0,0 +1,347 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::{Event, FocusAction};
+use crate::components::Props;
+use crate::elements::element::Element;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::events::CraftMessage;
+use crate::generate_component_methods;
+use crate::geometry::{Point, Rectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::{AlignItems, Display, FlexDirection, JustifyContent, Style, Unit};
+use crate::text::text_context::TextContext;
+#[cfg(feature = "accesskit")]
+use accesskit::Role;
+use kurbo::Affine;
+use peniko::Color;
+use std::any::Any;
+use std::sync::Arc;
+use taffy::{NodeId, Position, TaffyTree};
+use ui_events::keyboard::{Key, NamedKey};
+use winit::window::Window;
+
+/// A modal element that dims the rest of the window and captures all input until it is closed.
+///
+/// Place the dialog at the root of your view, so that it can cover the window.
+/// While a dialog is mounted, pointer events do not reach the elements underneath it and Tab focus cycles
+/// through the focusable elements inside the dialog. The previously focused element is focused again when
+/// the dialog is removed from the view.
+#[derive(Clone)]
+pub struct Dialog {
+    pub element_data: ElementData,
+    /// The key that requests the dialog to close.
+    close_key: Option<Key>,
+    /// Whether clicking the backdrop requests the dialog to close.
+    close_on_backdrop_click: bool,
+    /// The color drawn over the elements underneath the dialog.
+    backdrop_color: Color,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct DialogState {}
+
+impl Element for Dialog {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    /// A dialog is modal, so it claims every point in the window.
+    fn in_bounds(&self, _point: Point) -> bool {
+        true
+    }
+
+    fn name(&self) -> &'static str {
+        "Dialog"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<Window>>,
+        scale_factor: f64,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+        renderer.start_overlay();
+
+        // Dim the whole window, not just the area covered by the dialog's box.
+        if let Some(window) = window.as_ref() {
+            let window_size = window.inner_size();
+            renderer.draw_rect(
+                Rectangle::new(0.0, 0.0, window_size.width as f32, window_size.height as f32),
+                self.backdrop_color,
+            );
+        }
+
+        // We draw the borders before we start any layers, so that we don't clip the borders.
+        self.draw_borders(renderer, element_state, scale_factor);
+        self.maybe_start_layer(renderer, scale_factor);
+        {
+            self.draw_children(renderer, text_context, element_state, pointer, window, scale_factor);
+        }
+        self.maybe_end_layer(renderer);
+        self.draw_scrollbar(renderer, scale_factor);
+
+        renderer.end_overlay();
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+
+        for child in self.element_data.children.iter_mut() {
+            let child_node = child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&child_node);
+        }
+
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: Affine,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        for child in self.element_data.children.iter_mut() {
+            let taffy_child_node_id = child.internal.element_data().layout_item.taffy_node_id;
+            if taffy_child_node_id.is_none() {
+                continue;
+            }
+
+            child.internal.finalize_layout(
+                taffy_tree,
+                taffy_child_node_id.unwrap(),
+                self.element_data.layout_item.computed_box.position,
+                z_index,
+                transform,
+                element_state,
+                pointer,
+                text_context,
+                None,
+            );
+        }
+    }
+
+    fn resolve_clip(&mut self, _clip_bounds: Option<Rectangle>) {
+        self.element_data.layout_item.clip_bounds = None;
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+
+        match message {
+            CraftMessage::PointerButtonDown(_) => {
+                // Keep the keyboard focus inside the dialog when the backdrop is pressed.
+                self.maybe_set_focus(message, event);
+            }
+            CraftMessage::PointerButtonUp(pointer_button) => {
+                if !message.clicked() || !self.close_on_backdrop_click {
+                    return;
+                }
+
+                let is_backdrop_click = event
+                    .target
+                    .is_some_and(|target| target.component_id() == self.component_id())
+                    && !self.children().iter().any(|child| child.in_bounds(pointer_button.state.position));
+                if is_backdrop_click {
+                    event.result_message(CraftMessage::DialogCloseRequested);
+                }
+            }
+            CraftMessage::KeyboardInputEvent(keyboard_input) => {
+                if !keyboard_input.state.is_down() {
+                    return;
+                }
+
+                if self.close_key.as_ref().is_some_and(|close_key| *close_key == keyboard_input.key) {
+                    event.prevent_propagate();
+                    event.result_message(CraftMessage::DialogCloseRequested);
+                    return;
+                }
+
+                if keyboard_input.key == Key::Named(NamedKey::Tab) {
+                    event.prevent_propagate();
+                    event.focus_action(FocusAction::Set(
+                        self.next_focus(element_state, keyboard_input.modifiers.shift()),
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(DialogState::default()),
+        }
+    }
+
+    #[cfg(feature = "accesskit")]
+    fn compute_accessibility_tree(
+        &mut self,
+        tree: &mut accesskit::TreeUpdate,
+        parent_index: Option<usize>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) {
+        let current_node_id = accesskit::NodeId(self.element_data().component_id);
+
+        let mut current_node = accesskit::Node::new(Role::Dialog);
+        current_node.set_modal();
+
+        let padding_box =
+            self.element_data().layout_item.computed_box_transformed.padding_rectangle().scale(scale_factor);
+
+        current_node.set_bounds(accesskit::Rect {
+            x0: padding_box.left() as f64,
+            y0: padding_box.top() as f64,
+            x1: padding_box.right() as f64,
+            y1: padding_box.bottom() as f64,
+        });
+
+        let current_index = tree.nodes.len();
+
+        if let Some(parent_index) = parent_index {
+            let parent_node = tree.nodes.get_mut(parent_index).unwrap();
+            parent_node.1.push_child(current_node_id);
+        }
+
+        tree.nodes.push((current_node_id, current_node));
+
+        for child in self.element_data_mut().children.iter_mut() {
+            child.internal.compute_accessibility_tree(tree, Some(current_index), element_state, scale_factor);
+        }
+    }
+
+    /// The default style for the dialog's backdrop. The children are centered inside the backdrop.
+    fn default_style(&self) -> Style {
+        let mut default_style = Style::default();
+
+        *default_style.display_mut() = Display::Flex;
+        *default_style.flex_direction_mut() = FlexDirection::Column;
+        *default_style.align_items_mut() = Some(AlignItems::Center);
+        *default_style.justify_content_mut() = Some(JustifyContent::Center);
+        *default_style.position_mut() = Position::Absolute;
+        default_style.inset_mut().top = Unit::Px(0.0);
+        default_style.inset_mut().left = Unit::Px(0.0);
+        *default_style.width_mut() = Unit::Percentage(100.0);
+        *default_style.height_mut() = Unit::Percentage(100.0);
+
+        default_style
+    }
+}
+
+impl Dialog {
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a DialogState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    /// Finds the element that should receive focus when Tab is pressed inside the dialog.
+    ///
+    /// Focus wraps around at either end. If the dialog has no focusable elements, the dialog itself keeps the focus.
+    fn next_focus(&self, element_state: &ElementStateStore, reverse: bool) -> u64 {
+        let focusable: Vec<u64> = (self as &dyn Element)
+            .pre_order_iter()
+            .filter(|element| element.is_focusable())
+            .map(|element| element.component_id())
+            .collect();
+
+        if focusable.is_empty() {
+            return self.component_id();
+        }
+
+        let focused_index = focusable.iter().position(|id| {
+            element_state.storage.get(id).is_some_and(|item| item.base.focused)
+        });
+
+        let next_index = match (focused_index, reverse) {
+            (None, false) => 0,
+            (None, true) => focusable.len() - 1,
+            (Some(index), false) => (index + 1) % focusable.len(),
+            (Some(index), true) => (index + focusable.len() - 1) % focusable.len(),
+        };
+
+        focusable[next_index]
+    }
+
+    pub fn new() -> Dialog {
+        Dialog {
+            element_data: Default::default(),
+            close_key: Some(Key::Named(NamedKey::Escape)),
+            close_on_backdrop_click: false,
+            backdrop_color: Color::from_rgba8(0, 0, 0, 128),
+        }
+    }
+
+    /// Sets the key that emits a close request. Pass `None` to only close the dialog programmatically.
+    pub fn close_key(mut self, close_key: Option<Key>) -> Self {
+        self.close_key = close_key;
+        self
+    }
+
+    /// Emits a close request when the backdrop around the dialog's children is clicked.
+    pub fn close_on_backdrop_click(mut self, close_on_backdrop_click: bool) -> Self {
+        self.close_on_backdrop_click = close_on_backdrop_click;
+        self
+    }
+
+    /// Sets the color drawn over the elements underneath the dialog.
+    pub fn backdrop_color(mut self, backdrop_color: Color) -> Self {
+        self.backdrop_color = backdrop_color;
+        self
+    }
+
+    generate_component_methods!();
+}
+
+impl Default for Dialog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ElementStyles for Dialog {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
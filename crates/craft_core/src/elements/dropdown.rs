@@ -9,6 +9,7 @@ use crate::events::CraftMessage;
 use crate::generate_component_methods;
 use crate::geometry::{Point, Rectangle, TrblRectangle};
 use crate::layout::layout_context::LayoutContext;
+use crate::layout::popover::Placement;
 use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
 use crate::renderer::renderer::RenderList;
 use crate::style::{AlignItems, Display, FlexDirection, Style, Unit};
@@ -17,7 +18,7 @@ use peniko::Color;
 use std::any::Any;
 use std::sync::Arc;
 use kurbo::Affine;
-use taffy::{NodeId, Position, TaffyTree, TraversePartialTree};
+use taffy::{NodeId, TaffyTree, TraversePartialTree};
 use winit::window::Window;
 
 /// The index of the dropdown list in the layout tree.
@@ -32,7 +33,9 @@ pub struct Dropdown {
     pseudo_dropdown_selection: Option<ElementBoxed>,
     /// An element not in the user tree. Created, so that we can utilize our existing functionality (like scrollbars).
     pseudo_dropdown_list_element: Container,
-    
+    /// Where the dropdown list is placed relative to the dropdown selection.
+    dropdown_list_placement: Placement,
+
     default_item: usize,
 }
 
@@ -156,8 +159,10 @@ impl Element for Dropdown {
                 .iter_mut()
                 .filter_map(|child| child.internal.compute_layout(taffy_tree, element_state, scale_factor))
                 .collect();
+            let mut default_dropdown_list_style = Self::default_dropdown_list_style();
+            self.dropdown_list_placement.apply_to_style(&mut default_dropdown_list_style);
             self.pseudo_dropdown_list_element.element_data.style = Style::merge(
-                &Self::default_dropdown_list_style(),
+                &default_dropdown_list_style,
                 &self.pseudo_dropdown_list_element.element_data.style,
             );
 
@@ -218,16 +223,16 @@ impl Element for Dropdown {
             let dropdown_list =
                 taffy_tree.get_child_id(self.element_data.layout_item.taffy_node_id.unwrap(), DROPDOWN_LIST_INDEX);
             self.pseudo_dropdown_list_element.element_data.layout_item.taffy_node_id = Some(dropdown_list);
-            self.pseudo_dropdown_list_element.finalize_layout(
+            let anchor = self.computed_box_transformed().border_rectangle();
+            self.dropdown_list_placement.finalize_popover(
+                &mut self.pseudo_dropdown_list_element,
                 taffy_tree,
                 dropdown_list,
-                self.computed_box().position,
+                anchor,
                 z_index,
-                transform,
                 element_state,
                 pointer,
                 text_context,
-                None,
             );
 
             for child in self.element_data.children.iter_mut() {
@@ -242,7 +247,8 @@ impl Element for Dropdown {
                     // The location of where the dropdown list starts for the list items.
                     self.pseudo_dropdown_list_element.element_data.layout_item.computed_box.position,
                     z_index,
-                    transform,
+                    // The dropdown list is placed in window space, so the list items are as well.
+                    Affine::IDENTITY,
                     element_state,
                     pointer,
                     text_context,
@@ -352,6 +358,13 @@ impl Dropdown {
         self
     }
 
+    /// Sets where the dropdown list is placed relative to the dropdown selection.
+    /// By default, the list is placed below the selection and flips above it when there is not enough room.
+    pub fn dropdown_list_placement(mut self, placement: Placement) -> Self {
+        self.dropdown_list_placement = placement;
+        self
+    }
+
     /// Returns the default style for a dropdown list.
     fn default_dropdown_list_style() -> Style {
         let mut default_style = Style::default();
@@ -373,9 +386,6 @@ impl Dropdown {
 
         *default_style.display_mut() = Display::Flex;
         *default_style.flex_direction_mut() = FlexDirection::Column;
-        *default_style.position_mut() = Position::Absolute;
-        // Position the dropdown list at the bottom of the dropdown.
-        default_style.inset_mut().top = Unit::Percentage(100.0);
 
         default_style
     }
@@ -390,6 +400,7 @@ impl Dropdown {
             element_data: Default::default(),
             pseudo_dropdown_selection: Default::default(),
             pseudo_dropdown_list_element: Default::default(),
+            dropdown_list_placement: Placement::default().match_anchor_width(true),
             default_item: 0,
         }
     }
@@ -161,6 +161,11 @@ pub trait Element: Any + StandardElementClone + Send + Sync {
         self.element_data_mut().layout_item.resolve_clip(clip_bounds);
     }
     
+    /// Whether the element can receive keyboard focus, for example when Tab cycles through a dialog.
+    fn is_focusable(&self) -> bool {
+        false
+    }
+
     fn maybe_unset_focus(&self, message: &CraftMessage, event: &mut Event) {
         if let CraftMessage::PointerButtonDown(_) = &message {
             if let Some(target) = event.target {
@@ -512,6 +517,15 @@ macro_rules! generate_component_methods_no_children {
             self
         }
 
+        #[allow(dead_code)]
+        /// Wraps the element in a tooltip that shows `content` when the element is hovered.
+        pub fn tooltip<T>(self, content: T) -> ComponentSpecification
+        where
+            T: Into<ComponentSpecification>,
+        {
+            $crate::elements::Tooltip::new(content).push(self).component()
+        }
+
         #[allow(dead_code)]
         /// Sets the on_pointer_button_down handler for the element.
         pub fn on_pointer_button_down<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
@@ -805,6 +819,171 @@ macro_rules! generate_component_methods_no_children {
             self.element_data_mut().on_slider_value_changed = Some(callback);
             self
         }
+
+        #[allow(dead_code)]
+        /// Sets the on_dialog_close_requested handler for the element.
+        pub fn on_dialog_close_requested<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event) + Send + Sync + 'static,
+        {
+            use $crate::components::Event;
+            use $crate::elements::element_data::EventHandler;
+
+            let callback: EventHandler =
+                Arc::new(move |state_any: &mut dyn Any, global_any: &mut dyn Any, event: &mut Event| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event);
+                });
+            self.element_data_mut().on_dialog_close_requested = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_menu_item_activated handler for the element.
+        pub fn on_menu_item_activated<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &str) + Send + Sync + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerWithRef;
+
+            let callback: EventHandlerWithRef<str> = Arc::new(move |state_any, global_any, event, item| {
+                let state = state_any.downcast_mut::<State>().unwrap();
+                let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                handler(state, global, event, item);
+            });
+            self.element_data_mut().on_menu_item_activated = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_pane_resized handler for the element.
+        pub fn on_pane_resized<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &[f32]) + Send + Sync + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerWithRef;
+
+            let callback: EventHandlerWithRef<[f32]> = Arc::new(move |state_any, global_any, event, sizes| {
+                let state = state_any.downcast_mut::<State>().unwrap();
+                let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                handler(state, global, event, sizes);
+            });
+            self.element_data_mut().on_pane_resized = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_number_changed handler for the element.
+        pub fn on_number_changed<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, f64) + Send + Sync + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerCopy;
+
+            let callback: EventHandlerCopy<f64> = Arc::new(move |state_any, global_any, event, value| {
+                let state = state_any.downcast_mut::<State>().unwrap();
+                let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                handler(state, global, event, value);
+            });
+            self.element_data_mut().on_number_changed = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_date_changed handler for the element.
+        pub fn on_date_changed<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, $crate::events::chrono::NaiveDate)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerCopy;
+
+            let callback: EventHandlerCopy<$crate::events::chrono::NaiveDate> =
+                Arc::new(move |state_any, global_any, event, date| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event, date);
+                });
+            self.element_data_mut().on_date_changed = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_time_changed handler for the element.
+        pub fn on_time_changed<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, $crate::events::chrono::NaiveTime)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerCopy;
+
+            let callback: EventHandlerCopy<$crate::events::chrono::NaiveTime> =
+                Arc::new(move |state_any, global_any, event, time| {
+                    let state = state_any.downcast_mut::<State>().unwrap();
+                    let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                    handler(state, global, event, time);
+                });
+            self.element_data_mut().on_time_changed = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_color_changed handler for the element.
+        pub fn on_color_changed<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, $crate::Color)
+                + Send
+                + Sync
+                + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerCopy;
+
+            let callback: EventHandlerCopy<$crate::Color> = Arc::new(move |state_any, global_any, event, color| {
+                let state = state_any.downcast_mut::<State>().unwrap();
+                let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                handler(state, global, event, color);
+            });
+            self.element_data_mut().on_color_changed = Some(callback);
+            self
+        }
+
+        #[allow(dead_code)]
+        /// Sets the on_link_clicked handler for the element.
+        pub fn on_link_clicked<State, GlobalState, Handler>(mut self, handler: Handler) -> Self
+        where
+            State: Any + Send + Sync + 'static,
+            GlobalState: Any + Send + Sync + Default + 'static,
+            Handler: Fn(&mut State, &mut GlobalState, &mut $crate::components::Event, &str) + Send + Sync + 'static,
+        {
+            use $crate::elements::element_data::EventHandlerWithRef;
+
+            let callback: EventHandlerWithRef<str> = Arc::new(move |state_any, global_any, event, link| {
+                let state = state_any.downcast_mut::<State>().unwrap();
+                let global = global_any.downcast_mut::<GlobalState>().unwrap();
+                handler(state, global, event, link);
+            });
+            self.element_data_mut().on_link_clicked = Some(callback);
+            self
+        }
     };
 }
 
@@ -67,6 +67,14 @@ pub struct ElementData {
     pub(crate) on_dropdown_item_selected: Option<EventHandlerCopy<usize>>,
     pub(crate) on_switch_toggled: Option<EventHandlerCopy<bool>>,
     pub(crate) on_slider_value_changed: Option<EventHandlerCopy<f64>>,
+    pub(crate) on_dialog_close_requested: Option<EventHandler>,
+    pub(crate) on_menu_item_activated: Option<EventHandlerWithRef<str>>,
+    pub(crate) on_pane_resized: Option<EventHandlerWithRef<[f32]>>,
+    pub(crate) on_number_changed: Option<EventHandlerCopy<f64>>,
+    pub(crate) on_date_changed: Option<EventHandlerCopy<chrono::NaiveDate>>,
+    pub(crate) on_time_changed: Option<EventHandlerCopy<chrono::NaiveTime>>,
+    pub(crate) on_color_changed: Option<EventHandlerCopy<crate::Color>>,
+    pub(crate) on_link_clicked: Option<EventHandlerWithRef<str>>,
 }
 
 impl ElementData {
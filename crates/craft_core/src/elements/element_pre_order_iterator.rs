@@ -67,6 +67,7 @@ mod tests {
 
         let initial_tree = diff_trees(
             initial_view,
+            Vec::new(),
             root_element.clone(),
             None,
             &mut user_state,
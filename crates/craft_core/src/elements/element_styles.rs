@@ -1,6 +1,9 @@
 use crate::geometry::TrblRectangle;
 use crate::renderer::color::Color;
-use crate::style::{AlignItems, Display, FlexDirection, FontStyle, JustifyContent, Overflow, Style, Underline, Unit, Weight, Wrap};
+use crate::style::{
+    AlignItems, Direction, Display, FlexDirection, FontFeature, FontStyle, FontVariation, JustifyContent, Overflow, Style, TextAlign, TextDecoration,
+    TextDecorationStyle, Underline, Unit, Weight, WhiteSpace, Wrap,
+};
 use taffy::Position;
 
 pub trait ElementStyles
@@ -170,17 +173,102 @@ where
             Underline {
                 thickness: Some(thickness),
                 color,
-                offset
+                offset,
+                style: TextDecorationStyle::Solid,
             }
         );
         self
     }
 
+    fn strikethrough(mut self, thickness: f32, color: Color, offset: Option<f32>) -> Self {
+        *self.styles_mut().strikethrough_mut() = Some(TextDecoration {
+            thickness: Some(thickness),
+            color,
+            offset,
+            style: TextDecorationStyle::Solid,
+        });
+        self
+    }
+
+    fn overline(mut self, thickness: f32, color: Color, offset: Option<f32>) -> Self {
+        *self.styles_mut().overline_mut() = Some(TextDecoration {
+            thickness: Some(thickness),
+            color,
+            offset,
+            style: TextDecorationStyle::Solid,
+        });
+        self
+    }
+
+    /// Set how the underline, strikethrough and overline are drawn, which need to be set first.
+    fn text_decoration_style(mut self, style: TextDecorationStyle) -> Self {
+        let styles = self.styles_mut();
+        if let Some(underline) = styles.underline() {
+            *styles.underline_mut() = Some(Underline { style, ..underline });
+        }
+        if let Some(strikethrough) = styles.strikethrough() {
+            *styles.strikethrough_mut() = Some(TextDecoration { style, ..strikethrough });
+        }
+        if let Some(overline) = styles.overline() {
+            *styles.overline_mut() = Some(TextDecoration { style, ..overline });
+        }
+        self
+    }
+
     fn font_style(mut self, font_style: FontStyle) -> Self {
         *self.styles_mut().font_style_mut() = font_style;
         self
     }
 
+    /// Set the angle in degrees that the `Oblique` font style slants the text by.
+    fn font_oblique_angle(mut self, angle: f32) -> Self {
+        *self.styles_mut().font_oblique_angle_mut() = Some(angle);
+        self
+    }
+
+    /// Set an OpenType feature, replacing the value of the same feature.
+    fn font_feature(mut self, feature: FontFeature) -> Self {
+        self.styles_mut().font_features_mut().set(feature, |a, b| a.tag == b.tag);
+        self
+    }
+
+    /// Set the value of a variable font axis, replacing the value of the same axis.
+    fn font_variation(mut self, variation: FontVariation) -> Self {
+        self.styles_mut().font_variations_mut().set(variation, |a, b| a.tag == b.tag);
+        self
+    }
+
+    /// Set the height of a line relative to the font size.
+    fn line_height(mut self, line_height: f32) -> Self {
+        *self.styles_mut().line_height_mut() = line_height;
+        self
+    }
+
+    fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        *self.styles_mut().letter_spacing_mut() = letter_spacing;
+        self
+    }
+
+    fn word_spacing(mut self, word_spacing: f32) -> Self {
+        *self.styles_mut().word_spacing_mut() = word_spacing;
+        self
+    }
+
+    fn text_align(mut self, text_align: TextAlign) -> Self {
+        *self.styles_mut().text_align_mut() = text_align;
+        self
+    }
+
+    fn direction(mut self, direction: Direction) -> Self {
+        *self.styles_mut().direction_mut() = direction;
+        self
+    }
+
+    fn white_space(mut self, white_space: WhiteSpace) -> Self {
+        *self.styles_mut().white_space_mut() = white_space;
+        self
+    }
+
     fn overflow(mut self, overflow: Overflow) -> Self {
         *self.styles_mut().overflow_mut() = [overflow, overflow];
         self
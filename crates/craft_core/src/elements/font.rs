@@ -15,9 +15,14 @@ use kurbo::Affine;
 use taffy::{NodeId, TaffyTree};
 use winit::window::Window;
 
+/// Loads a font and registers it with the text context once it is ready.
+///
+/// The components that contain the element receive [`CraftMessage::FontLoaded`](crate::events::CraftMessage::FontLoaded)
+/// when the font can be used.
 #[derive(Clone)]
 pub struct Font {
     pub(crate) resource_identifier: ResourceIdentifier,
+    pub(crate) family: Option<String>,
     pub element_data: ElementData,
 }
 
@@ -25,10 +30,17 @@ impl Font {
     pub fn new(resource_identifier: ResourceIdentifier) -> Self {
         Self {
             resource_identifier,
+            family: None,
             element_data: Default::default(),
         }
     }
 
+    /// Register the font under `family` instead of the family name in the font file.
+    pub fn family(mut self, family: &str) -> Self {
+        self.family = Some(family.to_string());
+        self
+    }
+
     pub fn name() -> &'static str {
         "Font"
     }
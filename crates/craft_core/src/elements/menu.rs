@@ -0,0 +1,672 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::{ComponentId, Event, FocusAction};
+use crate::components::Props;
+use crate::elements::element::{Element, ElementBoxed};
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::elements::sub_menu::SUB_MENU_ITEMS_START;
+use crate::elements::{Container, MenuItem, SubMenu};
+use crate::events::CraftMessage;
+use crate::generate_component_methods_no_children;
+use crate::geometry::{Point, Rectangle, TrblRectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::layout::popover::{Placement, PlacementAlignment, PlacementSide};
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::{Display, FlexDirection, Style, Unit};
+use crate::text::text_context::TextContext;
+#[cfg(feature = "accesskit")]
+use accesskit::Role;
+use kurbo::Affine;
+use peniko::Color;
+use std::any::Any;
+use std::sync::Arc;
+use taffy::{NodeId, TaffyTree};
+use ui_events::keyboard::{Key, NamedKey};
+use ui_events::pointer::PointerButton;
+use winit::window::Window;
+
+/// The index of the element that opens the menu in the children.
+const MENU_TRIGGER_INDEX: usize = 0;
+
+/// The index of the first item of the menu in the children.
+const MENU_ITEMS_START: usize = 1;
+
+/// A context menu that opens at the pointer when its trigger is clicked with the secondary button.
+///
+/// The items are [`MenuItem`]s and [`SubMenu`]s. While the menu is open it has the keyboard focus:
+/// the arrow keys move the highlight and open or close submenus, Enter or Space activates the highlighted
+/// item and Escape closes the menu. Clicking outside of the menu closes it as well.
+#[derive(Clone, Default)]
+pub struct Menu {
+    pub element_data: ElementData,
+    /// The lists of the root menu and its open submenus, from the root down. These are not in the element tree.
+    pseudo_menu_lists: Vec<Container>,
+    /// The style of the lists, merged over the default list style.
+    menu_list_style: Style,
+}
+
+#[derive(Clone, Default)]
+pub struct MenuState {
+    /// The highlighted item of each open list, from the root list down. The menu is closed when this is empty.
+    open_lists: Vec<Option<usize>>,
+    /// Where the menu was opened, in window coordinates.
+    position: Point,
+    /// The element that was focused before the menu opened, so that it can be focused again when the menu closes.
+    previous_focus: Option<ComponentId>,
+}
+
+impl MenuState {
+    pub(crate) fn is_open(&self) -> bool {
+        !self.open_lists.is_empty()
+    }
+}
+
+impl Element for Menu {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    /// An open menu claims every point in the window, so that a click outside of it closes the menu.
+    fn in_bounds(&self, point: Point) -> bool {
+        if !self.pseudo_menu_lists.is_empty() {
+            return true;
+        }
+
+        let element_data = self.element_data();
+        let rect = element_data.layout_item.computed_box_transformed.border_rectangle();
+
+        if let Some(clip) = element_data.layout_item.clip_bounds {
+            match rect.intersection(&clip) {
+                Some(bounds) => bounds.contains(&point),
+                None => false,
+            }
+        } else {
+            rect.contains(&point)
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "Menu"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<Window>>,
+        scale_factor: f64,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+        let open_lists = self.get_state(element_state).open_lists.clone();
+
+        // We draw the borders before we start any layers, so that we don't clip the borders.
+        self.draw_borders(renderer, element_state, scale_factor);
+        self.maybe_start_layer(renderer, scale_factor);
+        {
+            if let Some(trigger) = self.element_data.children.get_mut(MENU_TRIGGER_INDEX) {
+                if trigger.internal.taffy_node_id().is_some() {
+                    trigger.internal.draw(renderer, text_context, element_state, pointer, window.clone(), scale_factor);
+                }
+            }
+        }
+        self.maybe_end_layer(renderer);
+
+        // Each list is drawn in its own overlay, so that a submenu is drawn over its parent list.
+        for level in 0..self.pseudo_menu_lists.len() {
+            renderer.start_overlay();
+            self.pseudo_menu_lists[level].draw(renderer, text_context, element_state, pointer, window.clone(), scale_factor);
+            if let Some(items) = self.list_items_mut(&open_lists, level) {
+                for item in items.iter_mut() {
+                    // Skip non-visual elements.
+                    if item.internal.taffy_node_id().is_none() {
+                        continue;
+                    }
+                    item.internal.draw(renderer, text_context, element_state, pointer, window.clone(), scale_factor);
+                }
+            }
+            renderer.end_overlay();
+        }
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+        let open_lists = self.get_state(element_state).open_lists.clone();
+
+        if let Some(trigger) = self.element_data.children.get_mut(MENU_TRIGGER_INDEX) {
+            let trigger_node = trigger.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&trigger_node);
+        }
+
+        self.pseudo_menu_lists.clear();
+        for level in 0..open_lists.len() {
+            let item_nodes: Vec<NodeId> = if let Some(items) = self.list_items_mut(&open_lists, level) {
+                items
+                    .iter_mut()
+                    .enumerate()
+                    .filter_map(|(index, item)| {
+                        // The highlighted item is drawn as hovered, whether the pointer or the keyboard moved to it.
+                        if let Some(item_state) = element_state.storage.get_mut(&item.internal.component_id()) {
+                            item_state.base.hovered = open_lists[level] == Some(index);
+                        }
+                        item.internal.compute_layout(taffy_tree, element_state, scale_factor)
+                    })
+                    .collect()
+            } else {
+                break;
+            };
+
+            let mut menu_list = Container::new();
+            // The list is not in the element tree, so it borrows the menu's state.
+            menu_list.element_data.component_id = self.element_data.component_id;
+            let mut default_menu_list_style = Self::default_menu_list_style();
+            Self::list_placement(level).apply_to_style(&mut default_menu_list_style);
+            menu_list.element_data.style = Style::merge(&default_menu_list_style, &self.menu_list_style);
+
+            let menu_list_node =
+                taffy_tree.new_with_children(menu_list.element_data.style.to_taffy_style(), &item_nodes).unwrap();
+            menu_list.element_data.layout_item.taffy_node_id = Some(menu_list_node);
+            self.element_data.layout_item.push_child(&Some(menu_list_node));
+            self.pseudo_menu_lists.push(menu_list);
+        }
+
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: Affine,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let state = self.get_state(element_state);
+        let open_lists = state.open_lists.clone();
+        let menu_position = state.position;
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        let element_position = self.element_data.layout_item.computed_box.position;
+        let child_clip_bounds = self.element_data.layout_item.clip_bounds;
+        if let Some(trigger) = self.element_data.children.get_mut(MENU_TRIGGER_INDEX) {
+            if let Some(trigger_node) = trigger.internal.taffy_node_id() {
+                trigger.internal.finalize_layout(
+                    taffy_tree,
+                    trigger_node,
+                    element_position,
+                    z_index,
+                    transform,
+                    element_state,
+                    pointer,
+                    text_context,
+                    child_clip_bounds,
+                );
+            }
+        }
+
+        // The root list opens at the pointer, every submenu list opens next to its submenu.
+        let mut anchor = Rectangle::new(menu_position.x as f32, menu_position.y as f32, 0.0, 0.0);
+        for level in 0..self.pseudo_menu_lists.len() {
+            let menu_list = &mut self.pseudo_menu_lists[level];
+            let menu_list_node = menu_list.element_data.layout_item.taffy_node_id.unwrap();
+            Self::list_placement(level).finalize_popover(
+                menu_list,
+                taffy_tree,
+                menu_list_node,
+                anchor,
+                z_index,
+                element_state,
+                pointer,
+                text_context,
+            );
+            let menu_list_position = menu_list.element_data.layout_item.computed_box.position;
+
+            let Some(items) = self.list_items_mut(&open_lists, level) else {
+                break;
+            };
+            for item in items.iter_mut() {
+                let Some(item_node) = item.internal.taffy_node_id() else {
+                    continue;
+                };
+                item.internal.finalize_layout(
+                    taffy_tree,
+                    item_node,
+                    menu_list_position,
+                    z_index,
+                    // The lists are placed in window space, so the items are as well.
+                    Affine::IDENTITY,
+                    element_state,
+                    pointer,
+                    text_context,
+                    None,
+                );
+            }
+
+            if let Some(item) = open_lists[level].and_then(|index| items.get(index)) {
+                anchor = item.internal.computed_box_transformed().border_rectangle();
+            }
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+
+        match message {
+            CraftMessage::PointerButtonDown(pointer_button) => {
+                let state = self.get_state_mut(element_state);
+                if !state.is_open() {
+                    return;
+                }
+                event.prevent_propagate();
+
+                let position = pointer_button.state.position;
+                if self.hit_test(&state.open_lists, Point::new(position.x, position.y)).is_some() {
+                    // Keep the keyboard focus on the menu when an item is pressed.
+                    event.focus_action(FocusAction::Set(self.component_id()));
+                } else {
+                    Self::close(state, event);
+                }
+            }
+            CraftMessage::PointerButtonUp(pointer_button) => {
+                let position = pointer_button.state.position;
+                let position = Point::new(position.x, position.y);
+
+                if !self.get_state(element_state).is_open() {
+                    if pointer_button.button == Some(PointerButton::Secondary) {
+                        let previous_focus =
+                            element_state.storage.iter().find(|(_, item)| item.base.focused).map(|(id, _)| *id);
+                        let state = self.get_state_mut(element_state);
+                        state.open_lists = vec![None];
+                        state.position = position;
+                        state.previous_focus = previous_focus;
+                        event.focus_action(FocusAction::Set(self.component_id()));
+                        event.prevent_propagate();
+                    }
+                    return;
+                }
+
+                let state = self.get_state_mut(element_state);
+                event.prevent_propagate();
+                if let Some((level, Some(index))) = self.hit_test(&state.open_lists, position) {
+                    self.activate(state, level, index, false, event);
+                }
+            }
+            CraftMessage::PointerMovedEvent(pointer_update) => {
+                let state = self.get_state_mut(element_state);
+                if !state.is_open() {
+                    return;
+                }
+
+                let position = pointer_update.current.position;
+                if let Some((level, Some(index))) = self.hit_test(&state.open_lists, Point::new(position.x, position.y))
+                {
+                    self.highlight(state, level, index);
+                }
+            }
+            CraftMessage::KeyboardInputEvent(keyboard_input) => {
+                let state = self.get_state_mut(element_state);
+                if !state.is_open() || !keyboard_input.state.is_down() {
+                    return;
+                }
+                event.prevent_propagate();
+
+                // The keyboard moves through the innermost open list.
+                let level = state.open_lists.len() - 1;
+                let highlighted = state.open_lists[level];
+                let items = self.list_items(&state.open_lists, level).unwrap_or_default();
+
+                match &keyboard_input.key {
+                    Key::Named(NamedKey::Escape) => Self::close(state, event),
+                    Key::Named(NamedKey::ArrowDown) => {
+                        state.open_lists[level] = next_selectable_item(items, highlighted, false);
+                    }
+                    Key::Named(NamedKey::ArrowUp) => {
+                        state.open_lists[level] = next_selectable_item(items, highlighted, true);
+                    }
+                    Key::Named(NamedKey::Home) => {
+                        state.open_lists[level] = next_selectable_item(items, None, false);
+                    }
+                    Key::Named(NamedKey::End) => {
+                        state.open_lists[level] = next_selectable_item(items, None, true);
+                    }
+                    Key::Named(NamedKey::ArrowRight) => {
+                        if let Some(index) = highlighted {
+                            if items.get(index).is_some_and(|item| item.internal.as_any().is::<SubMenu>()) {
+                                self.activate(state, level, index, true, event);
+                            }
+                        }
+                    }
+                    Key::Named(NamedKey::ArrowLeft) => {
+                        if level > 0 {
+                            state.open_lists.pop();
+                        }
+                    }
+                    Key::Named(NamedKey::Enter) => {
+                        if let Some(index) = highlighted {
+                            self.activate(state, level, index, true, event);
+                        }
+                    }
+                    Key::Character(character) if character == " " => {
+                        if let Some(index) = highlighted {
+                            self.activate(state, level, index, true, event);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(MenuState::default()),
+        }
+    }
+
+    #[cfg(feature = "accesskit")]
+    fn compute_accessibility_tree(
+        &mut self,
+        tree: &mut accesskit::TreeUpdate,
+        parent_index: Option<usize>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) {
+        let current_node_id = accesskit::NodeId(self.element_data().component_id);
+
+        let is_open = !self.pseudo_menu_lists.is_empty();
+        let mut current_node = accesskit::Node::new(if is_open { Role::Menu } else { Role::GenericContainer });
+        current_node.set_has_popup(accesskit::HasPopup::Menu);
+        current_node.set_expanded(is_open);
+
+        let padding_box =
+            self.element_data().layout_item.computed_box_transformed.padding_rectangle().scale(scale_factor);
+
+        current_node.set_bounds(accesskit::Rect {
+            x0: padding_box.left() as f64,
+            y0: padding_box.top() as f64,
+            x1: padding_box.right() as f64,
+            y1: padding_box.bottom() as f64,
+        });
+
+        let current_index = tree.nodes.len();
+
+        if let Some(parent_index) = parent_index {
+            let parent_node = tree.nodes.get_mut(parent_index).unwrap();
+            parent_node.1.push_child(current_node_id);
+        }
+
+        tree.nodes.push((current_node_id, current_node));
+
+        // The items are only exposed while the menu is open. Open submenus expose their own items.
+        let children_count = if is_open { self.element_data.children.len() } else { MENU_ITEMS_START };
+        for child in self.element_data_mut().children.iter_mut().take(children_count) {
+            child.internal.compute_accessibility_tree(tree, Some(current_index), element_state, scale_factor);
+        }
+    }
+}
+
+impl Menu {
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a MenuState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    fn get_state_mut<'a>(&self, element_state: &'a mut ElementStateStore) -> &'a mut MenuState {
+        self.get_base_state_mut(element_state).data.as_mut().downcast_mut().unwrap()
+    }
+
+    /// Creates a menu that opens when `trigger` is clicked with the secondary button.
+    pub fn new(trigger: impl Into<ComponentSpecification>) -> Menu {
+        let mut menu = Menu::default();
+        menu.element_data.child_specs.push(trigger.into());
+        menu
+    }
+
+    /// Sets the style of the menu's list and the lists of its submenus.
+    pub fn menu_list_style(mut self, style: &Style) -> Self {
+        self.menu_list_style = *style;
+        self
+    }
+
+    /// Returns the default style for a menu list.
+    fn default_menu_list_style() -> Style {
+        let mut default_style = Style::default();
+
+        *default_style.display_mut() = Display::Flex;
+        *default_style.flex_direction_mut() = FlexDirection::Column;
+        *default_style.padding_mut() = TrblRectangle::new_all(Unit::Px(4.0));
+        *default_style.min_width_mut() = Unit::Px(160.0);
+        *default_style.background_mut() = Color::from_rgb8(250, 250, 250);
+
+        let border_color = Color::from_rgb8(180, 180, 180);
+        let border_radius = (6.0, 6.0);
+        *default_style.border_radius_mut() = [border_radius, border_radius, border_radius, border_radius];
+        *default_style.border_color_mut() = TrblRectangle::new_all(border_color);
+        *default_style.border_width_mut() = TrblRectangle::new_all(Unit::Px(1.0));
+
+        default_style
+    }
+
+    /// The root list opens below and to the right of the pointer, submenu lists open to the right of their submenu.
+    fn list_placement(level: usize) -> Placement {
+        if level == 0 {
+            Placement::new(PlacementSide::Bottom, PlacementAlignment::Start)
+        } else {
+            Placement::new(PlacementSide::Right, PlacementAlignment::Start)
+        }
+    }
+
+    /// Returns the items of the open list at `level`.
+    fn list_items<'a>(&'a self, open_lists: &[Option<usize>], level: usize) -> Option<&'a [ElementBoxed]> {
+        let mut items = self.element_data.children.get(MENU_ITEMS_START..)?;
+        for highlighted in open_lists.iter().take(level) {
+            let sub_menu = items.get((*highlighted)?)?;
+            if !sub_menu.internal.as_any().is::<SubMenu>() {
+                return None;
+            }
+            items = sub_menu.internal.element_data().children.get(SUB_MENU_ITEMS_START..)?;
+        }
+        Some(items)
+    }
+
+    /// Returns the items of the open list at `level`.
+    fn list_items_mut<'a>(
+        &'a mut self,
+        open_lists: &[Option<usize>],
+        level: usize,
+    ) -> Option<&'a mut [ElementBoxed]> {
+        let mut items = self.element_data.children.get_mut(MENU_ITEMS_START..)?;
+        for highlighted in open_lists.iter().take(level) {
+            let sub_menu = items.get_mut((*highlighted)?)?;
+            if !sub_menu.internal.as_any().is::<SubMenu>() {
+                return None;
+            }
+            items = sub_menu.internal.element_data_mut().children.get_mut(SUB_MENU_ITEMS_START..)?;
+        }
+        Some(items)
+    }
+
+    /// Finds the topmost open list under `point`, and the item under `point` in that list.
+    fn hit_test(&self, open_lists: &[Option<usize>], point: Point) -> Option<(usize, Option<usize>)> {
+        for level in (0..self.pseudo_menu_lists.len()).rev() {
+            if !self.pseudo_menu_lists[level].in_bounds(point) {
+                continue;
+            }
+
+            let item = self
+                .list_items(open_lists, level)
+                .and_then(|items| items.iter().position(|item| item.internal.in_bounds(point)));
+            return Some((level, item));
+        }
+
+        None
+    }
+
+    /// Highlights the item at `index` in the list at `level`, and opens it if it is a submenu.
+    fn highlight(&self, state: &mut MenuState, level: usize, index: usize) {
+        let Some(item) = self.list_items(&state.open_lists, level).and_then(|items| items.get(index)) else {
+            return;
+        };
+        let is_selectable = is_selectable_item(item);
+
+        // Keep the submenus of the highlighted item open.
+        if is_selectable && state.open_lists[level] == Some(index) {
+            return;
+        }
+
+        state.open_lists.truncate(level + 1);
+        state.open_lists[level] = if is_selectable { Some(index) } else { None };
+        if is_selectable && item.internal.as_any().is::<SubMenu>() {
+            state.open_lists.push(None);
+        }
+    }
+
+    /// Activates the item at `index` in the list at `level`.
+    ///
+    /// Submenus are opened, with their first item highlighted when they are opened with the keyboard.
+    /// Menu items emit [`CraftMessage::MenuItemActivated`] and close the menu.
+    fn activate(&self, state: &mut MenuState, level: usize, index: usize, from_keyboard: bool, event: &mut Event) {
+        let Some(item) = self.list_items(&state.open_lists, level).and_then(|items| items.get(index)) else {
+            return;
+        };
+        if !is_selectable_item(item) {
+            return;
+        }
+
+        if let Some(sub_menu) = item.internal.as_any().downcast_ref::<SubMenu>() {
+            state.open_lists.truncate(level + 1);
+            state.open_lists[level] = Some(index);
+            let first_item = if from_keyboard {
+                let sub_menu_items = sub_menu.element_data.children.get(SUB_MENU_ITEMS_START..).unwrap_or_default();
+                next_selectable_item(sub_menu_items, None, false)
+            } else {
+                None
+            };
+            state.open_lists.push(first_item);
+        } else if let Some(menu_item) = item.internal.as_any().downcast_ref::<MenuItem>() {
+            event.result_message(CraftMessage::MenuItemActivated(menu_item.activation_id()));
+            Self::close(state, event);
+        }
+    }
+
+    /// Closes the menu and its submenus, and gives the focus back to the element that had it before the menu opened.
+    fn close(state: &mut MenuState, event: &mut Event) {
+        state.open_lists.clear();
+        event.focus_action(match state.previous_focus.take() {
+            Some(previous_focus) => FocusAction::Set(previous_focus),
+            None => FocusAction::Unset,
+        });
+    }
+
+    generate_component_methods_no_children!();
+
+    // The trigger is always the first child, so the push methods only touch the items after it.
+
+    #[allow(dead_code)]
+    pub fn push<T>(mut self, component_specification: T) -> Self
+    where
+        T: Into<ComponentSpecification>,
+    {
+        self.element_data.child_specs.push(component_specification.into());
+
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn push_children<T>(mut self, children: Vec<T>) -> Self
+    where
+        T: Into<ComponentSpecification>,
+    {
+        self.element_data.child_specs.truncate(MENU_ITEMS_START);
+        self.element_data.child_specs.extend(children.into_iter().map(|x| x.into()));
+
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn extend_children<T>(mut self, children: Vec<T>) -> Self
+    where
+        T: Into<ComponentSpecification>,
+    {
+        self.element_data.child_specs.extend(children.into_iter().map(|x| x.into()));
+
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn push_in_place(&mut self, component_specification: ComponentSpecification) {
+        self.element_data.child_specs.push(component_specification);
+    }
+}
+
+impl ElementStyles for Menu {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
+
+/// Whether an item in a menu list can be highlighted and activated.
+fn is_selectable_item(item: &ElementBoxed) -> bool {
+    let item = item.internal.as_any();
+    if let Some(menu_item) = item.downcast_ref::<MenuItem>() {
+        return menu_item.is_selectable();
+    }
+    if let Some(sub_menu) = item.downcast_ref::<SubMenu>() {
+        return sub_menu.is_selectable();
+    }
+
+    false
+}
+
+/// Finds the next item that can be highlighted after `from`, wrapping around at either end.
+/// Starts from the first item, or the last item when `reverse` is set, if nothing is highlighted.
+fn next_selectable_item(items: &[ElementBoxed], from: Option<usize>, reverse: bool) -> Option<usize> {
+    let len = items.len();
+    for step in 1..=len {
+        let index = match (from, reverse) {
+            (None, false) => step - 1,
+            (None, true) => len - step,
+            (Some(from), false) => (from + step) % len,
+            (Some(from), true) => (from + len - step) % len,
+        };
+        if is_selectable_item(&items[index]) {
+            return Some(index);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,364 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::Event;
+use crate::components::Props;
+use crate::elements::element::Element;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::elements::Text;
+use crate::events::CraftMessage;
+use crate::generate_component_methods_no_children;
+use crate::geometry::{Point, Rectangle, TrblRectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::{AlignItems, Display, FlexDirection, Style, Unit};
+use crate::text::text_context::TextContext;
+#[cfg(feature = "accesskit")]
+use accesskit::{Role, Toggled};
+use kurbo::Affine;
+use peniko::Color;
+use std::any::Any;
+use std::sync::Arc;
+use taffy::{NodeId, TaffyTree};
+use winit::window::Window;
+
+/// The number of children that make up the row of a menu item: the check mark, the label and the shortcut.
+pub(crate) const MENU_ITEM_ROW_LENGTH: usize = 3;
+
+/// The background of a highlighted menu item, unless the item has a hover style.
+pub(crate) const MENU_ITEM_HIGHLIGHT_COLOR: Color = Color::from_rgb8(210, 225, 245);
+
+const MENU_ITEM_DISABLED_COLOR: Color = Color::from_rgb8(160, 160, 160);
+const MENU_ITEM_SHORTCUT_COLOR: Color = Color::from_rgb8(120, 120, 120);
+
+/// An entry in a [`Menu`](crate::elements::Menu) or [`SubMenu`](crate::elements::SubMenu).
+///
+/// Activating an item emits [`CraftMessage::MenuItemActivated`] from the menu and closes it.
+/// Use [`MenuItem::separator`] to draw a line between groups of items.
+#[derive(Clone, Default)]
+pub struct MenuItem {
+    pub element_data: ElementData,
+    label: String,
+    shortcut: Option<String>,
+    /// Whether the item shows a check mark. `None` if the item is not checkable.
+    checked: Option<bool>,
+    disabled: bool,
+    is_separator: bool,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct MenuItemState {}
+
+impl Element for MenuItem {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "MenuItem"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<Window>>,
+        scale_factor: f64,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+
+        self.draw_borders(renderer, element_state, scale_factor);
+        if self.is_selectable() {
+            draw_menu_item_highlight(self, renderer, element_state, scale_factor);
+        }
+        self.draw_children(renderer, text_context, element_state, pointer, window, scale_factor);
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+
+        for child in self.element_data.children.iter_mut() {
+            let child_node = child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&child_node);
+        }
+
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: Affine,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        for child in self.element_data.children.iter_mut() {
+            let taffy_child_node_id = child.internal.element_data().layout_item.taffy_node_id;
+            if taffy_child_node_id.is_none() {
+                continue;
+            }
+
+            child.internal.finalize_layout(
+                taffy_tree,
+                taffy_child_node_id.unwrap(),
+                self.element_data.layout_item.computed_box.position,
+                z_index,
+                transform,
+                element_state,
+                pointer,
+                text_context,
+                self.element_data.layout_item.clip_bounds,
+            );
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    /// The menu handles pointer and keyboard input for its items.
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(MenuItemState::default()),
+        }
+    }
+
+    #[cfg(feature = "accesskit")]
+    fn compute_accessibility_tree(
+        &mut self,
+        tree: &mut accesskit::TreeUpdate,
+        parent_index: Option<usize>,
+        _element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) {
+        let current_node_id = accesskit::NodeId(self.element_data().component_id);
+
+        let mut current_node = if self.is_separator {
+            accesskit::Node::new(Role::Splitter)
+        } else if let Some(checked) = self.checked {
+            let mut node = accesskit::Node::new(Role::MenuItemCheckBox);
+            node.set_toggled(if checked { Toggled::True } else { Toggled::False });
+            node
+        } else {
+            accesskit::Node::new(Role::MenuItem)
+        };
+        if !self.is_separator {
+            current_node.set_label(self.label.clone());
+        }
+        if self.disabled {
+            current_node.set_disabled();
+        }
+        if let Some(shortcut) = &self.shortcut {
+            current_node.set_keyboard_shortcut(shortcut.clone());
+        }
+
+        let padding_box =
+            self.element_data().layout_item.computed_box_transformed.padding_rectangle().scale(scale_factor);
+
+        current_node.set_bounds(accesskit::Rect {
+            x0: padding_box.left() as f64,
+            y0: padding_box.top() as f64,
+            x1: padding_box.right() as f64,
+            y1: padding_box.bottom() as f64,
+        });
+
+        if let Some(parent_index) = parent_index {
+            let parent_node = tree.nodes.get_mut(parent_index).unwrap();
+            parent_node.1.push_child(current_node_id);
+        }
+
+        // The label is exposed on the item, so the row's text elements are not added as children.
+        tree.nodes.push((current_node_id, current_node));
+    }
+
+    fn default_style(&self) -> Style {
+        if self.is_separator {
+            return default_menu_separator_style();
+        }
+
+        default_menu_item_style()
+    }
+}
+
+impl MenuItem {
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a MenuItemState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    pub fn new(label: &str) -> MenuItem {
+        let mut menu_item = MenuItem {
+            element_data: Default::default(),
+            label: label.to_string(),
+            shortcut: None,
+            checked: None,
+            disabled: false,
+            is_separator: false,
+        };
+        menu_item.update_row();
+        menu_item
+    }
+
+    /// Creates a line that separates groups of items. Separators can't be highlighted or activated.
+    pub fn separator() -> MenuItem {
+        MenuItem {
+            is_separator: true,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the shortcut label shown at the end of the item, for example "Ctrl+C".
+    ///
+    /// This is only a label, the shortcut itself needs to be handled by the application.
+    pub fn shortcut(mut self, shortcut: &str) -> Self {
+        self.shortcut = Some(shortcut.to_string());
+        self.update_row();
+        self
+    }
+
+    /// Makes the item checkable and shows a check mark when `checked` is true.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self.update_row();
+        self
+    }
+
+    /// Disabled items are drawn grayed out and can't be highlighted or activated.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self.update_row();
+        self
+    }
+
+    /// Whether the item can be highlighted and activated.
+    pub(crate) fn is_selectable(&self) -> bool {
+        !self.disabled && !self.is_separator
+    }
+
+    /// The value of [`CraftMessage::MenuItemActivated`] for this item.
+    pub(crate) fn activation_id(&self) -> String {
+        self.element_data.id.clone().unwrap_or_else(|| self.label.clone())
+    }
+
+    /// Rebuilds the check mark, label and shortcut children from the item's fields.
+    fn update_row(&mut self) {
+        if self.is_separator {
+            return;
+        }
+
+        let check_mark = if self.checked == Some(true) { "✓" } else { "" };
+        let shortcut = self.shortcut.as_deref().unwrap_or_default();
+        self.element_data.child_specs = menu_item_row(check_mark, &self.label, shortcut, self.disabled);
+    }
+
+    generate_component_methods_no_children!();
+}
+
+impl ElementStyles for MenuItem {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
+
+/// Creates the children that make up the row of a menu item.
+///
+/// The row always has [`MENU_ITEM_ROW_LENGTH`] children, so that the items of a submenu can follow it.
+pub(crate) fn menu_item_row(
+    check_mark: &str,
+    label: &str,
+    trailing: &str,
+    disabled: bool,
+) -> Vec<ComponentSpecification> {
+    let mut check_mark = Text::new(check_mark).disable_selection().width(Unit::Px(20.0)).flex_shrink(0.0);
+    let mut label = Text::new(label).disable_selection().flex_grow(1.0);
+    let mut trailing = Text::new(trailing)
+        .disable_selection()
+        .margin(Unit::Px(0.0), Unit::Px(0.0), Unit::Px(0.0), Unit::Px(24.0))
+        .color(MENU_ITEM_SHORTCUT_COLOR);
+
+    if disabled {
+        check_mark = check_mark.color(MENU_ITEM_DISABLED_COLOR);
+        label = label.color(MENU_ITEM_DISABLED_COLOR);
+        trailing = trailing.color(MENU_ITEM_DISABLED_COLOR);
+    }
+
+    vec![check_mark.component(), label.component(), trailing.component()]
+}
+
+/// Draws the highlight of a menu item that the pointer or keyboard is on.
+///
+/// The menu marks the highlighted item as hovered, so a hover style replaces the default highlight.
+pub(crate) fn draw_menu_item_highlight(
+    item: &dyn Element,
+    renderer: &mut RenderList,
+    element_state: &ElementStateStore,
+    scale_factor: f64,
+) {
+    let is_highlighted = item.get_base_state(element_state).base.hovered;
+    if !is_highlighted || item.element_data().hover_style.is_some() {
+        return;
+    }
+
+    let highlight = item.computed_box_transformed().border_rectangle().scale(scale_factor);
+    renderer.draw_rect(highlight, MENU_ITEM_HIGHLIGHT_COLOR);
+}
+
+pub(crate) fn default_menu_item_style() -> Style {
+    let mut default_style = Style::default();
+
+    *default_style.display_mut() = Display::Flex;
+    *default_style.flex_direction_mut() = FlexDirection::Row;
+    *default_style.align_items_mut() = Some(AlignItems::Center);
+    let vertical_padding = Unit::Px(4.0);
+    let horizontal_padding = Unit::Px(8.0);
+    *default_style.padding_mut() = TrblRectangle::new(vertical_padding, horizontal_padding, vertical_padding, Unit::Px(4.0));
+
+    default_style
+}
+
+fn default_menu_separator_style() -> Style {
+    let mut default_style = Style::default();
+
+    *default_style.height_mut() = Unit::Px(1.0);
+    *default_style.margin_mut() = TrblRectangle::new(Unit::Px(4.0), Unit::Px(0.0), Unit::Px(4.0), Unit::Px(0.0));
+    *default_style.background_mut() = Color::from_rgb8(200, 200, 200);
+
+    default_style
+}
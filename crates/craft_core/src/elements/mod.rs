@@ -1,13 +1,28 @@
+pub(crate) mod code_editor;
+pub(crate) mod color_picker;
 pub(crate) mod container;
+pub(crate) mod date_picker;
+pub(crate) mod dialog;
 pub(crate) mod dropdown;
 pub(crate) mod element;
 pub(crate) mod empty;
 pub(crate) mod image;
+pub(crate) mod menu;
+pub(crate) mod menu_item;
+pub(crate) mod number_input;
 pub(crate) mod overlay;
+mod picker;
+pub(crate) mod progress_bar;
 pub(crate) mod slider;
+pub(crate) mod spinner;
+pub(crate) mod split_pane;
+pub(crate) mod sub_menu;
 pub(crate) mod switch;
 pub(crate) mod text;
+pub(crate) mod time_picker;
 pub(crate) mod tinyvg;
+pub(crate) mod toast;
+pub(crate) mod tooltip;
 
 #[allow(clippy::module_inception)]
 pub(crate) mod text_input;
@@ -24,7 +39,12 @@ mod scroll_state;
 mod thumb;
 
 pub use crate::elements::canvas::Canvas;
+pub use crate::elements::code_editor::CodeEditor;
+pub use crate::elements::color_picker::ColorPicker;
 pub use crate::elements::container::Container;
+pub use crate::elements::container::ContainerMessage;
+pub use crate::elements::date_picker::DatePicker;
+pub use crate::elements::dialog::Dialog;
 pub use crate::elements::dropdown::Dropdown;
 pub use crate::elements::element::Element;
 pub use crate::elements::element::ElementBoxed;
@@ -33,11 +53,26 @@ pub use crate::elements::element_states::ElementState;
 pub use crate::elements::element_styles::ElementStyles;
 pub use crate::elements::font::Font;
 pub use crate::elements::image::Image;
+pub use crate::elements::menu::Menu;
+pub use crate::elements::menu_item::MenuItem;
+pub use crate::elements::number_input::NumberInput;
+pub use crate::elements::number_input::NumberMode;
 pub use crate::elements::overlay::Overlay;
+pub use crate::elements::progress_bar::ProgressBar;
 pub use crate::elements::slider::Slider;
 pub use crate::elements::slider::SliderDirection;
+pub use crate::elements::spinner::Spinner;
+pub use crate::elements::split_pane::Pane;
+pub use crate::elements::split_pane::SplitDirection;
+pub use crate::elements::split_pane::SplitPane;
+pub use crate::elements::sub_menu::SubMenu;
 pub use crate::elements::switch::Switch;
 pub use crate::elements::text::Text;
+pub use crate::elements::text::TextSpan;
 pub use crate::elements::text_input::TextInput;
 pub use crate::elements::text_input::TextInputMessage;
+pub use crate::elements::time_picker::TimePicker;
 pub use crate::elements::tinyvg::TinyVg;
+pub use crate::elements::toast::Toast;
+pub use crate::elements::toast::ToastSeverity;
+pub use crate::elements::tooltip::Tooltip;
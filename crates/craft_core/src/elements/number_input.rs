@@ -0,0 +1,397 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::Event;
+use crate::components::Props;
+use crate::elements::element::{resolve_clip_for_scrollable, Element};
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+#[cfg(feature = "accesskit")]
+use crate::elements::text_input::compute_text_input_accessibility_tree;
+use crate::elements::text_input::{
+    compute_text_input_layout, default_text_input_style, draw_text_input, finalize_text_input_layout,
+    on_text_input_event, text_input_state, TextInputState,
+};
+use crate::events::CraftMessage;
+use crate::generate_component_methods_no_children;
+use crate::geometry::{Point, Rectangle, TrblRectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::Style;
+use crate::text::text_context::TextContext;
+use crate::text::RangedStyles;
+use kurbo::Affine;
+use peniko::Color;
+use std::any::Any;
+use std::sync::Arc;
+use taffy::{NodeId, TaffyTree};
+use ui_events::keyboard::{Key, NamedKey};
+use ui_events::ScrollDelta;
+use winit::window::Window;
+
+const INVALID_BORDER_COLOR: Color = Color::from_rgb8(220, 50, 50);
+
+/// The kind of numbers a [`NumberInput`] accepts.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum NumberMode {
+    /// Whole numbers.
+    #[default]
+    Integer,
+    /// Numbers with a fractional part, shown with the given number of decimals.
+    Decimal(usize),
+}
+
+/// A text input for numbers.
+///
+/// The value can be typed, or stepped with the up and down arrow keys and the mouse wheel while the input is focused.
+/// Characters that can't be part of a number are ignored. While the text is not a number in the input's range,
+/// the input is drawn with its invalid style.
+///
+/// The input shows its value while it is not focused, so the text is clamped to the range once the input loses focus.
+/// Emits [`CraftMessage::NumberChanged`] when the value changes.
+#[derive(Clone)]
+pub struct NumberInput {
+    pub element_data: ElementData,
+    value: f64,
+    min: f64,
+    max: f64,
+    step: f64,
+    mode: NumberMode,
+    /// The style of the input while its text is not a valid number. This style will get merged with the default style + user style.
+    invalid_style: Style,
+}
+
+impl Element for NumberInput {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "NumberInput"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        _text_context: &mut TextContext,
+        element_state: &mut ElementStateStore,
+        _pointer: Option<Point>,
+        _window: Option<Arc<Window>>,
+        scale_factor: f64,
+    ) {
+        draw_text_input(self, renderer, element_state, scale_factor);
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        _scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+
+        let text = self.get_state(element_state).text();
+        if !self.is_valid(&text) {
+            self.element_data.style = Style::merge(&self.element_data.style, &self.invalid_style);
+        }
+
+        compute_text_input_layout(self, taffy_tree)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: Affine,
+        element_state: &mut ElementStateStore,
+        _pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        finalize_text_input_layout(
+            self,
+            taffy_tree,
+            root_node,
+            position,
+            z_index,
+            transform,
+            element_state,
+            text_input_state,
+            text_context,
+            clip_bounds,
+        );
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        let focused = self.get_base_state(element_state).base.focused;
+
+        match message {
+            CraftMessage::KeyboardInputEvent(keyboard_input) if focused && keyboard_input.state.is_down() => {
+                match &keyboard_input.key {
+                    Key::Named(NamedKey::ArrowUp) => {
+                        self.step_by(1, element_state, event);
+                        return;
+                    }
+                    Key::Named(NamedKey::ArrowDown) => {
+                        self.step_by(-1, element_state, event);
+                        return;
+                    }
+                    Key::Named(NamedKey::Enter) => {
+                        self.commit(element_state, event);
+                        return;
+                    }
+                    _ => {}
+                }
+            }
+            CraftMessage::PointerScroll(pointer_scroll) if focused => {
+                let delta = match pointer_scroll.delta {
+                    ScrollDelta::LineDelta(_x, y) => y as f64,
+                    ScrollDelta::PixelDelta(_x, y) => y as f64,
+                    ScrollDelta::PageDelta(_x, y) => y as f64,
+                };
+                if delta != 0.0 {
+                    // Scrolling up increases the value.
+                    self.step_by(if delta > 0.0 { 1 } else { -1 }, element_state, event);
+                    return;
+                }
+            }
+            _ => {}
+        }
+
+        on_text_input_event(self, message, element_state, text_input_state, text_context, should_style, event);
+
+        // Only report text that is a number, the invalid style tells the user about the rest.
+        if let Some(CraftMessage::TextInputChanged(text)) = &event.result_message {
+            event.result_message = self.parse(text).map(|value| CraftMessage::NumberChanged(self.clamp(value)));
+        }
+    }
+
+    fn resolve_clip(&mut self, clip_bounds: Option<Rectangle>) {
+        resolve_clip_for_scrollable(self, clip_bounds);
+    }
+
+    fn initialize_state(&mut self, scaling_factor: f64) -> ElementStateStoreItem {
+        let text = self.format(self.clamp(self.value));
+        let mut text_input_state =
+            TextInputState::new(self.style(), Some(text), RangedStyles::new(vec![]), scaling_factor);
+        text_input_state.filter = Some(self.character_filter());
+
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(text_input_state),
+        }
+    }
+
+    #[cfg(feature = "accesskit")]
+    fn compute_accessibility_tree(
+        &mut self,
+        tree: &mut accesskit::TreeUpdate,
+        parent_index: Option<usize>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) {
+        let text = self.get_state(element_state).text();
+        let value = self.parse(&text).unwrap_or(self.value);
+
+        let mut current_node = accesskit::Node::new(accesskit::Role::SpinButton);
+        current_node.set_numeric_value(value);
+        current_node.set_numeric_value_step(self.step);
+        if self.min.is_finite() {
+            current_node.set_min_numeric_value(self.min);
+        }
+        if self.max.is_finite() {
+            current_node.set_max_numeric_value(self.max);
+        }
+        if !self.is_valid(&text) {
+            current_node.set_invalid(accesskit::Invalid::True);
+        }
+
+        compute_text_input_accessibility_tree(
+            self,
+            current_node,
+            tree,
+            parent_index,
+            element_state,
+            text_input_state,
+            scale_factor,
+        );
+    }
+
+    fn update_state(&mut self, element_state: &mut ElementStateStore, reload_fonts: bool, scaling_factor: f64) {
+        let item = element_state.storage.get_mut(&self.element_data.component_id).unwrap();
+        let focused = item.base.focused;
+        let state: &mut TextInputState = item.data.as_mut().downcast_mut().unwrap();
+
+        state.update_scale(scaling_factor);
+        if reload_fonts {
+            state.reload_fonts();
+        }
+        state.update_style(self.style());
+        state.filter = Some(self.character_filter());
+
+        // Show the value from the view while the user is not typing,
+        // this also clamps or reverts the typed text once the input loses focus.
+        if !focused {
+            let text = self.format(self.clamp(self.value));
+            if state.text() != text {
+                state.set_text(&text);
+            }
+        }
+    }
+
+    fn default_style(&self) -> Style {
+        default_text_input_style()
+    }
+}
+
+impl NumberInput {
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a TextInputState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    fn get_state_mut<'a>(&self, element_state: &'a mut ElementStateStore) -> &'a mut TextInputState {
+        element_state.storage.get_mut(&self.element_data.component_id).unwrap().data.as_mut().downcast_mut().unwrap()
+    }
+
+    pub fn new(value: f64) -> Self {
+        let mut invalid_style = Style::default();
+        *invalid_style.border_color_mut() = TrblRectangle::new_all(INVALID_BORDER_COLOR);
+
+        Self {
+            element_data: ElementData::default(),
+            value,
+            min: f64::NEG_INFINITY,
+            max: f64::INFINITY,
+            step: 1.0,
+            mode: NumberMode::Integer,
+            invalid_style,
+        }
+    }
+
+    /// Set the minimum value. Unbounded by default.
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Set the maximum value. Unbounded by default.
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set how much the value changes with each arrow key press or mouse wheel tick. Defaults to 1.
+    ///
+    /// Steps that are not positive and finite are ignored.
+    pub fn step(mut self, step: f64) -> Self {
+        if step.is_finite() && step > 0.0 {
+            self.step = step;
+        }
+        self
+    }
+
+    /// Set the kind of numbers the input accepts. Defaults to [`NumberMode::Integer`].
+    pub fn mode(mut self, mode: NumberMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the style of the input while its text is not a number in its range.
+    pub fn invalid_style(mut self, invalid_style: Style) -> Self {
+        self.invalid_style = invalid_style;
+        self
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        value.max(self.min).min(self.max)
+    }
+
+    fn format(&self, value: f64) -> String {
+        match self.mode {
+            NumberMode::Integer => format!("{}", value.round()),
+            NumberMode::Decimal(decimals) => format!("{value:.decimals$}"),
+        }
+    }
+
+    fn parse(&self, text: &str) -> Option<f64> {
+        let text = text.trim();
+        match self.mode {
+            NumberMode::Integer => text.parse::<i64>().ok().map(|value| value as f64),
+            NumberMode::Decimal(_) => text.parse::<f64>().ok().filter(|value| value.is_finite()),
+        }
+    }
+
+    fn is_valid(&self, text: &str) -> bool {
+        self.parse(text).is_some_and(|value| value >= self.min && value <= self.max)
+    }
+
+    /// Accepts the characters that can be part of a number, so that the other typed, pasted and IME committed
+    /// characters are ignored.
+    fn character_filter(&self) -> Arc<dyn Fn(char) -> bool + Send + Sync> {
+        let negative = self.min < 0.0;
+        let decimal = matches!(self.mode, NumberMode::Decimal(decimals) if decimals > 0);
+        Arc::new(move |character| match character {
+            '0'..='9' => true,
+            '-' => negative,
+            '.' => decimal,
+            _ => false,
+        })
+    }
+
+    /// Moves the value by a number of steps, snapping it to the steps counted from the minimum.
+    fn step_by(&self, steps: i32, element_state: &mut ElementStateStore, event: &mut Event) {
+        let state = self.get_state_mut(element_state);
+        let current_value = self.parse(&state.text()).unwrap_or(self.value);
+
+        let base = if self.min.is_finite() { self.min } else { 0.0 };
+        let current_step = ((current_value - base) / self.step).round();
+        let value = self.clamp(base + (current_step + steps as f64) * self.step);
+
+        state.set_text(&self.format(value));
+        event.prevent_defaults();
+        event.prevent_propagate();
+        event.result_message(CraftMessage::NumberChanged(value));
+    }
+
+    /// Replaces the typed text with the clamped value, or with the last value if the text is not a number.
+    fn commit(&self, element_state: &mut ElementStateStore, event: &mut Event) {
+        let state = self.get_state_mut(element_state);
+        let parsed_value = self.parse(&state.text()).map(|value| self.clamp(value));
+
+        state.set_text(&self.format(parsed_value.unwrap_or(self.value)));
+        event.prevent_defaults();
+        event.prevent_propagate();
+        if let Some(value) = parsed_value {
+            event.result_message(CraftMessage::NumberChanged(value));
+        }
+    }
+
+    generate_component_methods_no_children!();
+}
+
+impl ElementStyles for NumberInput {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
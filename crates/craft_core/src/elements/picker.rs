@@ -0,0 +1,121 @@
+use crate::components::component::ComponentSpecification;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::elements::text_input::{TextInput, TextInputState};
+use crate::geometry::Rectangle;
+use crate::reactive::element_state_store::ElementStateStore;
+use crate::renderer::renderer::RenderList;
+use crate::renderer::Brush;
+use crate::style::{Display, Style, Unit};
+use kurbo::{Affine, RoundedRect, Shape};
+use peniko::Color;
+
+// Shared pieces of the picker elements, which draw most of their parts themselves instead of using child elements.
+// The date and time pickers only use a text input as their field, so that values can be typed or pasted.
+
+pub(crate) const PICKER_BORDER_COLOR: Color = Color::from_rgb8(199, 199, 206);
+pub(crate) const PICKER_BACKGROUND_COLOR: Color = Color::WHITE;
+pub(crate) const PICKER_HOVER_COLOR: Color = Color::from_rgb8(232, 232, 238);
+pub(crate) const PICKER_SELECTED_COLOR: Color = Color::from_rgb8(30, 144, 255);
+pub(crate) const PICKER_SELECTED_TEXT_COLOR: Color = Color::WHITE;
+pub(crate) const PICKER_MUTED_TEXT_COLOR: Color = Color::from_rgb8(150, 150, 160);
+pub(crate) const PICKER_DISABLED_TEXT_COLOR: Color = Color::from_rgb8(200, 200, 205);
+
+/// The corner radius of the popover and its cells in logical pixels.
+pub(crate) const PICKER_RADIUS: f64 = 6.0;
+
+/// Fills a rounded rectangle given in logical window coordinates.
+pub(crate) fn fill_rounded_rect(
+    renderer: &mut RenderList,
+    rectangle: Rectangle,
+    radius: f64,
+    color: Color,
+    scale_factor: f64,
+) {
+    let rounded_rect = RoundedRect::new(
+        rectangle.left() as f64,
+        rectangle.top() as f64,
+        rectangle.right() as f64,
+        rectangle.bottom() as f64,
+        radius,
+    );
+    let mut path = rounded_rect.to_path(0.1);
+    path.apply_affine(Affine::scale(scale_factor));
+    renderer.fill_bez_path(path, Brush::Color(color));
+}
+
+/// Draws the background and the border of a popover.
+pub(crate) fn draw_popover_panel(renderer: &mut RenderList, panel: Rectangle, scale_factor: f64) {
+    fill_rounded_rect(renderer, panel, PICKER_RADIUS, PICKER_BORDER_COLOR, scale_factor);
+    let inner = Rectangle::new(panel.x + 1.0, panel.y + 1.0, panel.width - 2.0, panel.height - 2.0);
+    fill_rounded_rect(renderer, inner, PICKER_RADIUS - 1.0, PICKER_BACKGROUND_COLOR, scale_factor);
+}
+
+/// Shrinks a cell a little, so that the highlights of neighbouring cells don't touch.
+pub(crate) fn inset(rectangle: Rectangle) -> Rectangle {
+    Rectangle::new(rectangle.x + 2.0, rectangle.y + 2.0, rectangle.width - 4.0, rectangle.height - 4.0)
+}
+
+#[cfg(feature = "accesskit")]
+pub(crate) fn to_accesskit_rect(rectangle: Rectangle) -> accesskit::Rect {
+    accesskit::Rect {
+        x0: rectangle.left() as f64,
+        y0: rectangle.top() as f64,
+        x1: rectangle.right() as f64,
+        y1: rectangle.bottom() as f64,
+    }
+}
+
+/// The style of a date or time picker, which only holds the text field that shows the picked value.
+pub(crate) fn default_picker_field_style() -> Style {
+    let mut style = Style::default();
+    *style.display_mut() = Display::Block;
+    *style.width_mut() = Unit::Px(140.0);
+    *style.height_mut() = Unit::Px(32.0);
+
+    style
+}
+
+/// The text field of a date or time picker, which is the only child of the picker.
+pub(crate) fn picker_field(text: &str, placeholder: &str) -> ComponentSpecification {
+    TextInput::new(text)
+        .placeholder(placeholder)
+        // Enter picks the highlighted value instead of starting a new line.
+        .filter(|character| character != '\n')
+        .width(Unit::Percentage(100.0))
+        .height(Unit::Percentage(100.0))
+        .into()
+}
+
+/// Whether the text field of a date or time picker is focused.
+pub(crate) fn is_picker_field_focused(element_data: &ElementData, element_state: &ElementStateStore) -> bool {
+    element_data
+        .children
+        .first()
+        .and_then(|field| element_state.storage.get(&field.internal.component_id()))
+        .is_some_and(|item| item.base.focused)
+}
+
+/// Shows `text` in the text field of a date or time picker.
+/// The text that is being typed is kept, unless `force` is set because a value was picked in the popover.
+pub(crate) fn set_picker_field_text(
+    element_data: &ElementData,
+    element_state: &mut ElementStateStore,
+    text: &str,
+    force: bool,
+) {
+    let Some(item) =
+        element_data.children.first().and_then(|field| element_state.storage.get_mut(&field.internal.component_id()))
+    else {
+        return;
+    };
+    if item.base.focused && !force {
+        return;
+    }
+
+    if let Some(text_input_state) = item.data.downcast_mut::<TextInputState>() {
+        if text_input_state.text() != text {
+            text_input_state.set_text(text);
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::Event;
+use crate::components::Props;
+use crate::elements::element::Element;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::events::CraftMessage;
+use crate::geometry::borders::BorderSpec;
+use crate::geometry::{ElementBox, Point, Rectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::renderer::Brush;
+use crate::style::{Display, Style, Unit};
+use crate::text::text_context::TextContext;
+use crate::{generate_component_methods_no_children, palette};
+use kurbo::Affine;
+use peniko::Color;
+use std::any::Any;
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time;
+use taffy::{NodeId, TaffyTree};
+use time::{Duration, Instant};
+#[cfg(target_arch = "wasm32")]
+use web_time as time;
+use winit::window::Window;
+
+/// How long the segment of an indeterminate progress bar takes to slide across the track.
+const INDETERMINATE_PERIOD: Duration = Duration::from_millis(1500);
+/// The width of the segment of an indeterminate progress bar, relative to the track.
+const INDETERMINATE_SEGMENT_WIDTH: f32 = 0.3;
+
+/// A horizontal bar that shows the progress of a task.
+///
+/// The element's style is the track, the part of the track up to the value is filled with the value track color.
+/// An indeterminate progress bar is used when the progress is unknown, a segment slides along the track instead.
+#[derive(Clone)]
+pub struct ProgressBar {
+    pub element_data: ElementData,
+    /// The progress between 0 and `max`, or `None` if the progress bar is indeterminate.
+    value: Option<f64>,
+    max: f64,
+    value_track_color: Color,
+}
+
+#[derive(Clone, Copy)]
+pub struct ProgressBarState {
+    /// When the element was created, the indeterminate animation is timed from here.
+    started_at: Instant,
+}
+
+impl Element for ProgressBar {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "ProgressBar"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        _text_context: &mut TextContext,
+        element_state: &mut ElementStateStore,
+        _pointer: Option<Point>,
+        _window: Option<Arc<Window>>,
+        scale_factor: f64,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+
+        self.draw_borders(renderer, element_state, scale_factor);
+
+        let track = self.computed_box_transformed();
+        let mut value_track = track;
+        if let Some(value) = self.value {
+            let fraction = if self.max > 0.0 { (value / self.max).clamp(0.0, 1.0) } else { 0.0 };
+            value_track.size.width = track.size.width * fraction as f32;
+            if value_track.size.width > 0.0001 {
+                self.fill_value_track(renderer, value_track, scale_factor);
+            }
+            return;
+        }
+
+        // Slide a segment from just before the start of the track to just after its end.
+        let elapsed = self.get_state(element_state).started_at.elapsed();
+        let progress = elapsed.as_secs_f32() % INDETERMINATE_PERIOD.as_secs_f32() / INDETERMINATE_PERIOD.as_secs_f32();
+        let segment_width = track.size.width * INDETERMINATE_SEGMENT_WIDTH;
+        value_track.size.width = segment_width;
+        value_track.position.x += ((track.size.width + segment_width) * progress - segment_width) as f64;
+
+        renderer.push_layer(track.border_rectangle().scale(scale_factor));
+        self.fill_value_track(renderer, value_track, scale_factor);
+        renderer.pop_layer();
+
+        // The animation is driven by the element, so keep drawing frames while it is shown.
+        element_state.animation_frame_requested = true;
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        _element_state: &mut ElementStateStore,
+        _scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: Affine,
+        element_state: &mut ElementStateStore,
+        _pointer: Option<Point>,
+        _text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(ProgressBarState {
+                started_at: Instant::now(),
+            }),
+        }
+    }
+
+    #[cfg(feature = "accesskit")]
+    fn compute_accessibility_tree(
+        &mut self,
+        tree: &mut accesskit::TreeUpdate,
+        parent_index: Option<usize>,
+        _element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) {
+        let current_node_id = accesskit::NodeId(self.element_data().component_id);
+
+        let mut current_node = accesskit::Node::new(accesskit::Role::ProgressIndicator);
+        // Indeterminate progress bars have no value.
+        if let Some(value) = self.value {
+            // A max that isn't positive shows an empty bar, so it is reported as 0.
+            let max = if self.max > 0.0 { self.max } else { 0.0 };
+            current_node.set_numeric_value(value.clamp(0.0, max));
+            current_node.set_min_numeric_value(0.0);
+            current_node.set_max_numeric_value(max);
+        }
+
+        let padding_box =
+            self.element_data().layout_item.computed_box_transformed.padding_rectangle().scale(scale_factor);
+
+        current_node.set_bounds(accesskit::Rect {
+            x0: padding_box.left() as f64,
+            y0: padding_box.top() as f64,
+            x1: padding_box.right() as f64,
+            y1: padding_box.bottom() as f64,
+        });
+
+        if let Some(parent_index) = parent_index {
+            let parent_node = tree.nodes.get_mut(parent_index).unwrap();
+            parent_node.1.push_child(current_node_id);
+        }
+
+        tree.nodes.push((current_node_id, current_node));
+    }
+
+    fn default_style(&self) -> Style {
+        let mut style = Style::default();
+        *style.background_mut() = palette::css::LIGHT_GRAY;
+        *style.width_mut() = Unit::Px(150.0);
+        *style.height_mut() = Unit::Px(8.0);
+        *style.display_mut() = Display::Block;
+
+        style
+    }
+}
+
+impl ProgressBar {
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a ProgressBarState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    /// Creates a progress bar that is filled up to `value`, out of 100 by default.
+    pub fn new(value: f64) -> ProgressBar {
+        ProgressBar {
+            element_data: Default::default(),
+            value: Some(value),
+            max: 100.0,
+            value_track_color: palette::css::DODGER_BLUE,
+        }
+    }
+
+    /// Creates a progress bar for a task whose progress is unknown.
+    pub fn indeterminate() -> ProgressBar {
+        ProgressBar {
+            value: None,
+            ..ProgressBar::new(0.0)
+        }
+    }
+
+    /// Set the value of a full progress bar. Defaults to 100.
+    ///
+    /// A max that isn't positive, or is NaN, shows an empty bar.
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// The color of the filled part of the track.
+    pub fn value_track_color(mut self, color: Color) -> Self {
+        self.value_track_color = color;
+        self
+    }
+
+    /// Fills a part of the track, using the track's borders and radii so that the ends are rounded the same way.
+    fn fill_value_track(&self, renderer: &mut RenderList, value_track: ElementBox, scale_factor: f64) {
+        let style = self.element_data.current_style();
+        let borders = value_track.border;
+        let border_spec = BorderSpec::new(
+            value_track.border_rectangle(),
+            [borders.top, borders.right, borders.bottom, borders.left],
+            style.border_radius(),
+            style.border_color(),
+        );
+        let computed_border_spec = border_spec.compute_border_spec();
+        let mut background_path = computed_border_spec.build_background_path();
+        background_path.apply_affine(Affine::scale(scale_factor));
+        renderer.fill_bez_path(background_path, Brush::Color(self.value_track_color));
+    }
+
+    generate_component_methods_no_children!();
+}
+
+impl ElementStyles for ProgressBar {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
@@ -132,7 +132,12 @@ impl ScrollState {
         // Content Size = overflowed content size + padding
         // Scroll Height = Content Size
         let scroll_height = content_height + box_transformed.padding.bottom + box_transformed.padding.top;
-        let scroll_track_width = element_data.layout_item.scrollbar_size.width;
+        let left_scrollbar = element_data.style.has_left_scrollbar();
+        let scroll_track_width = if left_scrollbar {
+            element_data.style.scrollbar_width()
+        } else {
+            element_data.layout_item.scrollbar_size.width
+        };
 
         // The scroll track height is the height of the padding box.
         let scroll_track_height = client_height;
@@ -140,8 +145,13 @@ impl ScrollState {
         let max_scroll_y = (scroll_height - client_height).max(0.0);
         element_data.layout_item.max_scroll_y = max_scroll_y;
 
+        let scroll_track_x = if left_scrollbar {
+            box_transformed.padding_rectangle().left()
+        } else {
+            box_transformed.padding_rectangle().right() - scroll_track_width
+        };
         element_data.layout_item.computed_scroll_track = Rectangle::new(
-            box_transformed.padding_rectangle().right() - scroll_track_width,
+            scroll_track_x,
             box_transformed.padding_rectangle().top(),
             scroll_track_width,
             scroll_track_height,
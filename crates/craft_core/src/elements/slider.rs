@@ -39,6 +39,8 @@ pub struct Slider {
     pub min: f64,
     pub max: f64,
     pub direction: SliderDirection,
+    /// The value from the view, which the slider shows when it is created and whenever it changes.
+    value: f64,
 
     /// The color of the track to the left of the thumb. This may be disabled by setting this to `None`.
     value_track_color: Option<Color>,
@@ -53,6 +55,8 @@ pub struct Slider {
 pub struct SliderState {
     pub value: f64,
     pub dragging: bool,
+    /// The value from the view during the last update.
+    view_value: f64,
 }
 
 impl Element for Slider {
@@ -255,10 +259,24 @@ impl Element for Slider {
     fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
         ElementStateStoreItem {
             base: Default::default(),
-            data: Box::new(SliderState::default()),
+            data: Box::new(SliderState {
+                value: self.value,
+                view_value: self.value,
+                ..Default::default()
+            }),
         }
     }
 
+    fn update_state(&mut self, element_state: &mut ElementStateStore, _reload_fonts: bool, _scaling_factor: f64) {
+        let state = self.get_base_state_mut(element_state).data.as_mut().downcast_mut::<SliderState>().unwrap();
+        if state.view_value == self.value {
+            return;
+        }
+
+        state.view_value = self.value;
+        state.value = self.value;
+    }
+
     #[cfg(feature = "accesskit")]
     fn compute_accessibility_tree(
         &mut self,
@@ -379,6 +397,14 @@ impl Slider {
         self
     }
 
+    /// Set the value of the slider. Defaults to 0.
+    ///
+    /// The slider keeps the value that it is dragged to, until the view sets a different value.
+    pub fn value(mut self, value: f64) -> Self {
+        self.value = value;
+        self
+    }
+
     /// Set the slider direction.
     pub fn direction(mut self, direction: SliderDirection) -> Self {
         self.direction = direction;
@@ -460,6 +486,7 @@ impl Slider {
             min: 0.0,
             max: 100.0,
             direction: Default::default(),
+            value: 0.0,
             value_track_color: Some(palette::css::DODGER_BLUE),
             thumb,
             rounded: false,
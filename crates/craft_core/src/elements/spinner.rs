@@ -0,0 +1,268 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::Event;
+use crate::components::Props;
+use crate::elements::element::Element;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::events::CraftMessage;
+use crate::geometry::{Point, Rectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::renderer::Brush;
+use crate::style::{Display, Style, Unit};
+use crate::text::text_context::TextContext;
+use crate::{generate_component_methods_no_children, palette};
+use kurbo::{Affine, BezPath, Shape, Vec2};
+use peniko::Color;
+use std::any::Any;
+use std::f64::consts::{PI, TAU};
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time;
+use taffy::{NodeId, TaffyTree};
+use time::{Duration, Instant};
+#[cfg(target_arch = "wasm32")]
+use web_time as time;
+use winit::window::Window;
+
+/// How long the spinner takes to make a full turn.
+const SPINNER_PERIOD: Duration = Duration::from_millis(1000);
+/// The angle covered by the spinning arc.
+const SPINNER_ARC_SWEEP: f64 = PI / 2.0;
+
+/// A spinning arc that shows that work is being done.
+///
+/// The arc spins on a ring, which is drawn with the track color.
+/// Use [`ProgressBar`](crate::elements::ProgressBar) instead when the progress of the work is known.
+#[derive(Clone)]
+pub struct Spinner {
+    pub element_data: ElementData,
+    /// The color of the ring. This may be disabled by setting this to `None`.
+    track_color: Option<Color>,
+    /// The color of the spinning arc.
+    value_track_color: Color,
+    /// The thickness of the ring in pixels.
+    thickness: f32,
+}
+
+#[derive(Clone, Copy)]
+pub struct SpinnerState {
+    /// When the element was created, the animation is timed from here.
+    started_at: Instant,
+}
+
+impl Element for Spinner {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "Spinner"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        _text_context: &mut TextContext,
+        element_state: &mut ElementStateStore,
+        _pointer: Option<Point>,
+        _window: Option<Arc<Window>>,
+        scale_factor: f64,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+
+        self.draw_borders(renderer, element_state, scale_factor);
+
+        let content_rectangle = self.computed_box_transformed().content_rectangle().scale(scale_factor);
+        let center = kurbo::Point::new(
+            (content_rectangle.left() + content_rectangle.width / 2.0) as f64,
+            (content_rectangle.top() + content_rectangle.height / 2.0) as f64,
+        );
+        let radius = f32::min(content_rectangle.width, content_rectangle.height) as f64 / 2.0;
+        let thickness = f64::min(self.thickness as f64 * scale_factor, radius);
+        if radius <= 0.0 {
+            return;
+        }
+
+        if let Some(track_color) = self.track_color {
+            renderer.fill_bez_path(ring_segment(center, radius, thickness, 0.0, TAU), Brush::Color(track_color));
+        }
+
+        let elapsed = self.get_state(element_state).started_at.elapsed();
+        let turn = elapsed.as_secs_f64() % SPINNER_PERIOD.as_secs_f64() / SPINNER_PERIOD.as_secs_f64();
+        // Start at the top and turn clockwise.
+        let start_angle = turn * TAU - PI / 2.0;
+        renderer.fill_bez_path(
+            ring_segment(center, radius, thickness, start_angle, SPINNER_ARC_SWEEP),
+            Brush::Color(self.value_track_color),
+        );
+
+        // The animation is driven by the element, so keep drawing frames while it is shown.
+        element_state.animation_frame_requested = true;
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        _element_state: &mut ElementStateStore,
+        _scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: Affine,
+        element_state: &mut ElementStateStore,
+        _pointer: Option<Point>,
+        _text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(SpinnerState {
+                started_at: Instant::now(),
+            }),
+        }
+    }
+
+    #[cfg(feature = "accesskit")]
+    fn compute_accessibility_tree(
+        &mut self,
+        tree: &mut accesskit::TreeUpdate,
+        parent_index: Option<usize>,
+        _element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) {
+        let current_node_id = accesskit::NodeId(self.element_data().component_id);
+
+        // A spinner is an indeterminate progress indicator, so it has no value.
+        let mut current_node = accesskit::Node::new(accesskit::Role::ProgressIndicator);
+
+        let padding_box =
+            self.element_data().layout_item.computed_box_transformed.padding_rectangle().scale(scale_factor);
+
+        current_node.set_bounds(accesskit::Rect {
+            x0: padding_box.left() as f64,
+            y0: padding_box.top() as f64,
+            x1: padding_box.right() as f64,
+            y1: padding_box.bottom() as f64,
+        });
+
+        if let Some(parent_index) = parent_index {
+            let parent_node = tree.nodes.get_mut(parent_index).unwrap();
+            parent_node.1.push_child(current_node_id);
+        }
+
+        tree.nodes.push((current_node_id, current_node));
+    }
+
+    fn default_style(&self) -> Style {
+        let mut style = Style::default();
+        *style.width_mut() = Unit::Px(24.0);
+        *style.height_mut() = Unit::Px(24.0);
+        *style.display_mut() = Display::Block;
+
+        style
+    }
+}
+
+impl Spinner {
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a SpinnerState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    pub fn new() -> Spinner {
+        Spinner {
+            element_data: Default::default(),
+            track_color: Some(palette::css::LIGHT_GRAY),
+            value_track_color: palette::css::DODGER_BLUE,
+            thickness: 3.0,
+        }
+    }
+
+    /// The color of the ring the arc spins on. This may be disabled by setting this to `None`.
+    pub fn track_color(mut self, color: Option<Color>) -> Self {
+        self.track_color = color;
+        self
+    }
+
+    /// The color of the spinning arc.
+    pub fn value_track_color(mut self, color: Color) -> Self {
+        self.value_track_color = color;
+        self
+    }
+
+    /// Set the thickness of the ring in pixels. Defaults to 3.
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    generate_component_methods_no_children!();
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ElementStyles for Spinner {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
+
+/// Builds the part of a ring between `start_angle` and `start_angle + sweep_angle`, in radians.
+fn ring_segment(center: kurbo::Point, radius: f64, thickness: f64, start_angle: f64, sweep_angle: f64) -> BezPath {
+    let inner_radius = radius - thickness;
+    let outer_arc = kurbo::Arc::new(center, Vec2::new(radius, radius), start_angle, sweep_angle, 0.0);
+    let inner_arc =
+        kurbo::Arc::new(center, Vec2::new(inner_radius, inner_radius), start_angle + sweep_angle, -sweep_angle, 0.0);
+
+    let mut path = BezPath::new();
+    path.extend(outer_arc.path_elements(0.1));
+    path.line_to(center + Vec2::from_angle(start_angle + sweep_angle) * inner_radius);
+    path.extend(inner_arc.append_iter(0.1));
+    path.close_path();
+    path
+}
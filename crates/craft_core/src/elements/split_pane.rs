@@ -0,0 +1,545 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::Event;
+use crate::components::Props;
+use crate::elements::base_element_state::DUMMY_DEVICE_ID;
+use crate::elements::element::Element;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::events::CraftMessage;
+use crate::{generate_component_methods_no_children, palette};
+use crate::geometry::{Point, PointConverter, Rectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::{Display, FlexDirection, Style, Unit};
+use crate::text::text_context::TextContext;
+use kurbo::Affine;
+use peniko::Color;
+use std::any::Any;
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time;
+use taffy::{NodeId, TaffyTree};
+use time::Instant;
+#[cfg(target_arch = "wasm32")]
+use web_time as time;
+use winit::window::Window;
+
+/// The maximum time between two presses on the same divider for them to count as a double click.
+const DOUBLE_CLICK_SECONDS: f64 = 0.4;
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum SplitDirection {
+    /// The panes are placed side by side and the dividers are vertical.
+    #[default]
+    Horizontal,
+    /// The panes are stacked on top of each other and the dividers are horizontal.
+    Vertical,
+}
+
+/// The sizing of a pane in a [`SplitPane`].
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct Pane {
+    size: Option<f32>,
+    min_size: f32,
+    max_size: Option<f32>,
+}
+
+impl Pane {
+    /// Creates a flexible pane. Flexible panes evenly share the space that the fixed panes leave.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gives the pane a fixed initial size in pixels.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// The size in pixels that dragging a divider can't shrink the pane below. Defaults to 0.
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// The size in pixels that dragging a divider can't grow the pane above.
+    pub fn max_size(mut self, max_size: f32) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+}
+
+/// An element that places its children in panes separated by draggable dividers.
+///
+/// Dragging a divider resizes the panes on both sides of it and emits [`CraftMessage::PaneResized`]
+/// with the new size of every pane, so that the layout can be persisted.
+/// Double-clicking a divider resets the panes next to it to their initial sizes.
+#[derive(Clone)]
+pub struct SplitPane {
+    pub element_data: ElementData,
+    direction: SplitDirection,
+    /// The sizing of each child, in the same order as the children.
+    panes: Vec<Pane>,
+    divider_size: f32,
+    divider_color: Color,
+    /// The color of a divider while it is hovered or dragged.
+    divider_active_color: Color,
+    /// The taffy nodes of the dividers. The dividers are not elements, so they are not stored in the user tree.
+    divider_nodes: Vec<NodeId>,
+    /// The transformed bounds of the dividers, the divider at index `i` is between pane `i` and `i + 1`.
+    divider_rectangles: Vec<Rectangle>,
+}
+
+#[derive(Clone, Default)]
+pub struct SplitPaneState {
+    /// The size of each pane in pixels, or `None` if the pane is flexible.
+    sizes: Vec<Option<f32>>,
+    drag: Option<DividerDrag>,
+    /// The divider that was last pressed and when, used to detect double clicks.
+    last_divider_press: Option<(usize, Instant)>,
+}
+
+#[derive(Clone)]
+struct DividerDrag {
+    divider: usize,
+    /// The position of the pointer along the split direction when the drag started.
+    start_position: f32,
+    /// The computed size of every pane when the drag started.
+    start_sizes: Vec<f32>,
+}
+
+impl SplitPaneState {
+    /// The fixed size of a pane, or `None` if the pane is flexible.
+    pub(crate) fn pane_size(&self, index: usize) -> Option<f32> {
+        self.sizes.get(index).copied().flatten()
+    }
+}
+
+impl Element for SplitPane {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "SplitPane"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<Window>>,
+        scale_factor: f64,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+
+        self.draw_borders(renderer, element_state, scale_factor);
+        self.draw_children(renderer, text_context, element_state, pointer, window, scale_factor);
+
+        let state = self.get_state(element_state);
+        let dragged_divider = state.drag.as_ref().map(|drag| drag.divider);
+        for (index, divider) in self.divider_rectangles.iter().enumerate() {
+            let is_hovered = pointer.is_some_and(|pointer| divider.contains(&pointer));
+            let color = if is_hovered || dragged_divider == Some(index) {
+                self.divider_active_color
+            } else {
+                self.divider_color
+            };
+            renderer.draw_rect(divider.scale(scale_factor), color);
+        }
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+
+        // Reset the sizes when panes are added or removed.
+        let pane_count = self.element_data.children.len();
+        let sizes = {
+            let base_state = self.get_base_state_mut(element_state);
+            let state = base_state.data.as_mut().downcast_mut::<SplitPaneState>().unwrap();
+            if state.sizes.len() != pane_count {
+                state.sizes = (0..pane_count).map(|index| self.pane_at(index).size).collect();
+                state.drag = None;
+            }
+            state.sizes.clone()
+        };
+
+        let divider_style = self.divider_style();
+        self.divider_nodes.clear();
+        for (index, child) in self.element_data.children.iter_mut().enumerate() {
+            if index > 0 {
+                let divider_node = taffy_tree.new_leaf(divider_style.clone()).unwrap();
+                self.divider_nodes.push(divider_node);
+                self.element_data.layout_item.push_child(&Some(divider_node));
+            }
+
+            let pane = self.panes.get(index).copied().unwrap_or_default();
+            apply_pane_style(child.internal.style_mut(), self.direction, &pane, sizes[index]);
+
+            let child_node = child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&child_node);
+        }
+
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: Affine,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        let origin = self.element_data.layout_item.computed_box.position;
+        self.divider_rectangles = self
+            .divider_nodes
+            .iter()
+            .map(|divider_node| {
+                let layout = taffy_tree.layout(*divider_node).unwrap();
+                let divider_position = transform * (origin + Point::from_taffy_point(layout.location).to_vec2());
+                Rectangle::new(
+                    divider_position.x as f32,
+                    divider_position.y as f32,
+                    layout.size.width,
+                    layout.size.height,
+                )
+            })
+            .collect();
+
+        for child in self.element_data.children.iter_mut() {
+            let taffy_child_node_id = child.internal.element_data().layout_item.taffy_node_id;
+            if taffy_child_node_id.is_none() {
+                continue;
+            }
+
+            child.internal.finalize_layout(
+                taffy_tree,
+                taffy_child_node_id.unwrap(),
+                self.element_data.layout_item.computed_box.position,
+                z_index,
+                transform,
+                element_state,
+                pointer,
+                text_context,
+                self.element_data.layout_item.clip_bounds,
+            );
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+
+        let base_state = self.get_base_state_mut(element_state);
+        let state = base_state.data.as_mut().downcast_mut::<SplitPaneState>().unwrap();
+
+        match message {
+            CraftMessage::PointerButtonDown(pointer_button_update) => {
+                if !pointer_button_update.is_primary() {
+                    return;
+                }
+                let Some(divider) = self.divider_at(&pointer_button_update.state.position) else {
+                    return;
+                };
+
+                let now = Instant::now();
+                let is_double_click = state.last_divider_press.is_some_and(|(last_divider, last_press)| {
+                    last_divider == divider && now.duration_since(last_press).as_secs_f64() < DOUBLE_CLICK_SECONDS
+                });
+
+                if is_double_click {
+                    state.last_divider_press = None;
+                    state.sizes[divider] = self.pane_at(divider).size;
+                    state.sizes[divider + 1] = self.pane_at(divider + 1).size;
+                    event.result_message(CraftMessage::PaneResized(self.resolve_sizes(&state.sizes)));
+                    return;
+                }
+
+                state.last_divider_press = Some((divider, now));
+                state.drag = Some(DividerDrag {
+                    divider,
+                    start_position: self.main_axis_position(&pointer_button_update.state.position),
+                    start_sizes: self.pane_sizes(),
+                });
+                // FIXME: Turn pointer capture on with the correct device id.
+                base_state.base.pointer_capture.insert(DUMMY_DEVICE_ID, true);
+            }
+            CraftMessage::PointerMovedEvent(pointer_update) => {
+                let Some(drag) = &state.drag else {
+                    return;
+                };
+
+                let (before, after) = (drag.divider, drag.divider + 1);
+                let (before_pane, after_pane) = (self.pane_at(before), self.pane_at(after));
+                let start_sizes = &drag.start_sizes;
+
+                // The divider moves the space between the two panes, so their total size stays the same.
+                let total = start_sizes[before] + start_sizes[after];
+                let lower = f32::max(before_pane.min_size, total - after_pane.max_size.unwrap_or(f32::INFINITY));
+                let upper = f32::min(before_pane.max_size.unwrap_or(f32::INFINITY), total - after_pane.min_size);
+
+                let delta = self.main_axis_position(&pointer_update.current.position) - drag.start_position;
+                let before_size = (start_sizes[before] + delta).clamp(lower, upper.max(lower));
+                let after_size = total - before_size;
+
+                // Keep flexible panes flexible where possible, so that they still grow and shrink with the split pane.
+                if state.sizes[before].is_some() || state.sizes[after].is_none() {
+                    state.sizes[before] = Some(before_size);
+                }
+                if state.sizes[after].is_some() {
+                    state.sizes[after] = Some(after_size);
+                }
+
+                let mut sizes = start_sizes.clone();
+                sizes[before] = before_size;
+                sizes[after] = after_size;
+                event.result_message(CraftMessage::PaneResized(sizes));
+            }
+            CraftMessage::PointerButtonUp(_) => {
+                if state.drag.take().is_some() {
+                    // FIXME: Turn pointer capture on with the correct device id.
+                    base_state.base.pointer_capture.remove(&DUMMY_DEVICE_ID);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(SplitPaneState {
+                sizes: self.panes.iter().map(|pane| pane.size).collect(),
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn default_style(&self) -> Style {
+        let mut default_style = Style::default();
+
+        *default_style.display_mut() = Display::Flex;
+        *default_style.flex_direction_mut() = match self.direction {
+            SplitDirection::Horizontal => FlexDirection::Row,
+            SplitDirection::Vertical => FlexDirection::Column,
+        };
+
+        default_style
+    }
+}
+
+impl SplitPane {
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a SplitPaneState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    pub fn new(direction: SplitDirection) -> SplitPane {
+        SplitPane {
+            element_data: Default::default(),
+            direction,
+            panes: Vec::new(),
+            divider_size: 4.0,
+            divider_color: Color::from_rgb8(220, 220, 220),
+            divider_active_color: palette::css::DODGER_BLUE,
+            divider_nodes: Vec::new(),
+            divider_rectangles: Vec::new(),
+        }
+    }
+
+    /// Adds a child in a new pane with the given sizing.
+    pub fn pane<T>(mut self, component_specification: T, pane: Pane) -> Self
+    where
+        T: Into<ComponentSpecification>,
+    {
+        self.element_data.child_specs.push(component_specification.into());
+        self.panes.push(pane);
+
+        self
+    }
+
+    /// Sets the thickness of the dividers in pixels. Defaults to 4.
+    pub fn divider_size(mut self, divider_size: f32) -> Self {
+        self.divider_size = divider_size;
+        self
+    }
+
+    /// Sets the color of the dividers.
+    pub fn divider_color(mut self, color: Color) -> Self {
+        self.divider_color = color;
+        self
+    }
+
+    /// Sets the color of a divider while it is hovered or dragged.
+    pub fn divider_active_color(mut self, color: Color) -> Self {
+        self.divider_active_color = color;
+        self
+    }
+
+    /// The sizing of the pane at `index`. Children added without a [`Pane`] are flexible.
+    fn pane_at(&self, index: usize) -> Pane {
+        self.panes.get(index).copied().unwrap_or_default()
+    }
+
+    fn divider_style(&self) -> taffy::Style {
+        let size = taffy::Dimension::length(self.divider_size);
+        let size = match self.direction {
+            SplitDirection::Horizontal => taffy::Size {
+                width: size,
+                height: taffy::Dimension::auto(),
+            },
+            SplitDirection::Vertical => taffy::Size {
+                width: taffy::Dimension::auto(),
+                height: size,
+            },
+        };
+
+        taffy::Style {
+            size,
+            flex_shrink: 0.0,
+            ..Default::default()
+        }
+    }
+
+    fn divider_at(&self, point: &Point) -> Option<usize> {
+        self.divider_rectangles.iter().position(|divider| divider.contains(point))
+    }
+
+    fn main_axis_position(&self, point: &Point) -> f32 {
+        match self.direction {
+            SplitDirection::Horizontal => point.x as f32,
+            SplitDirection::Vertical => point.y as f32,
+        }
+    }
+
+    /// The computed size of every pane along the split direction.
+    fn pane_sizes(&self) -> Vec<f32> {
+        self.element_data
+            .children
+            .iter()
+            .map(|child| {
+                let size = child.internal.computed_box().size;
+                match self.direction {
+                    SplitDirection::Horizontal => size.width,
+                    SplitDirection::Vertical => size.height,
+                }
+            })
+            .collect()
+    }
+
+    /// Estimates the size of every pane before the next layout, with the flexible panes evenly sharing the space left.
+    fn resolve_sizes(&self, sizes: &[Option<f32>]) -> Vec<f32> {
+        let total: f32 = self.pane_sizes().iter().sum();
+        let fixed: f32 = sizes.iter().flatten().sum();
+        let flexible_count = sizes.iter().filter(|size| size.is_none()).count().max(1);
+        let flexible_size = f32::max((total - fixed) / flexible_count as f32, 0.0);
+
+        sizes.iter().map(|size| size.unwrap_or(flexible_size)).collect()
+    }
+
+    generate_component_methods_no_children!();
+
+    // Children pushed without a pane sizing are placed in flexible panes.
+
+    #[allow(dead_code)]
+    pub fn push<T>(self, component_specification: T) -> Self
+    where
+        T: Into<ComponentSpecification>,
+    {
+        self.pane(component_specification, Pane::default())
+    }
+
+    #[allow(dead_code)]
+    pub fn push_children<T>(mut self, children: Vec<T>) -> Self
+    where
+        T: Into<ComponentSpecification>,
+    {
+        self.element_data.child_specs.clear();
+        self.panes.clear();
+        self.extend_children(children)
+    }
+
+    #[allow(dead_code)]
+    pub fn extend_children<T>(mut self, children: Vec<T>) -> Self
+    where
+        T: Into<ComponentSpecification>,
+    {
+        for child in children {
+            self = self.push(child);
+        }
+
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn push_in_place(&mut self, component_specification: ComponentSpecification) {
+        self.element_data.child_specs.push(component_specification);
+        self.panes.push(Pane::default());
+    }
+}
+
+impl ElementStyles for SplitPane {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
+
+/// Sizes a pane along the split direction, fixed panes neither grow nor shrink.
+fn apply_pane_style(style: &mut Style, direction: SplitDirection, pane: &Pane, size: Option<f32>) {
+    let (flex_grow, flex_shrink, flex_basis) = match size {
+        Some(size) => (0.0, 0.0, Unit::Px(size)),
+        None => (1.0, 1.0, Unit::Px(0.0)),
+    };
+    *style.flex_grow_mut() = flex_grow;
+    *style.flex_shrink_mut() = flex_shrink;
+    *style.flex_basis_mut() = flex_basis;
+
+    let min_size = Unit::Px(pane.min_size);
+    let max_size = pane.max_size.map(Unit::Px).unwrap_or(Unit::Auto);
+    match direction {
+        SplitDirection::Horizontal => {
+            *style.min_width_mut() = min_size;
+            *style.max_width_mut() = max_size;
+        }
+        SplitDirection::Vertical => {
+            *style.min_height_mut() = min_size;
+            *style.max_height_mut() = max_size;
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::Event;
+use crate::components::Props;
+use crate::elements::element::Element;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::elements::menu_item::{default_menu_item_style, draw_menu_item_highlight, menu_item_row, MENU_ITEM_ROW_LENGTH};
+use crate::events::CraftMessage;
+use crate::generate_component_methods_no_children;
+use crate::geometry::{Point, Rectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::Style;
+use crate::text::text_context::TextContext;
+#[cfg(feature = "accesskit")]
+use accesskit::Role;
+use kurbo::Affine;
+use std::any::Any;
+use std::sync::Arc;
+use taffy::{NodeId, TaffyTree};
+use winit::window::Window;
+
+/// The index of the first item of a submenu in its children. The children before it make up the submenu's row.
+pub(crate) const SUB_MENU_ITEMS_START: usize = MENU_ITEM_ROW_LENGTH;
+
+/// An entry in a [`Menu`](crate::elements::Menu) that opens another list of items next to it.
+///
+/// The list opens when the submenu is hovered, clicked or activated with the right arrow key.
+/// The menu lays out and draws the list, the submenu itself only draws its row.
+#[derive(Clone, Default)]
+pub struct SubMenu {
+    pub element_data: ElementData,
+    label: String,
+    disabled: bool,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct SubMenuState {}
+
+impl Element for SubMenu {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "SubMenu"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<Window>>,
+        scale_factor: f64,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+
+        self.draw_borders(renderer, element_state, scale_factor);
+        if !self.disabled {
+            draw_menu_item_highlight(self, renderer, element_state, scale_factor);
+        }
+        for child in self.element_data.children.iter_mut().take(SUB_MENU_ITEMS_START) {
+            // Skip non-visual elements.
+            if child.internal.taffy_node_id().is_none() {
+                continue;
+            }
+            child.internal.draw(renderer, text_context, element_state, pointer, window.clone(), scale_factor);
+        }
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+
+        for child in self.element_data.children.iter_mut().take(SUB_MENU_ITEMS_START) {
+            let child_node = child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&child_node);
+        }
+
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: Affine,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        for child in self.element_data.children.iter_mut().take(SUB_MENU_ITEMS_START) {
+            let taffy_child_node_id = child.internal.element_data().layout_item.taffy_node_id;
+            if taffy_child_node_id.is_none() {
+                continue;
+            }
+
+            child.internal.finalize_layout(
+                taffy_tree,
+                taffy_child_node_id.unwrap(),
+                self.element_data.layout_item.computed_box.position,
+                z_index,
+                transform,
+                element_state,
+                pointer,
+                text_context,
+                self.element_data.layout_item.clip_bounds,
+            );
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    /// The menu handles pointer and keyboard input for its submenus.
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(SubMenuState::default()),
+        }
+    }
+
+    #[cfg(feature = "accesskit")]
+    fn compute_accessibility_tree(
+        &mut self,
+        tree: &mut accesskit::TreeUpdate,
+        parent_index: Option<usize>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) {
+        let current_node_id = accesskit::NodeId(self.element_data().component_id);
+
+        let mut current_node = accesskit::Node::new(Role::MenuItem);
+        current_node.set_label(self.label.clone());
+        current_node.set_has_popup(accesskit::HasPopup::Menu);
+        if self.disabled {
+            current_node.set_disabled();
+        }
+
+        // The items are only laid out while the submenu is open.
+        let is_expanded = self.items().iter().any(|item| item.taffy_node_id().is_some());
+        current_node.set_expanded(is_expanded);
+
+        let padding_box =
+            self.element_data().layout_item.computed_box_transformed.padding_rectangle().scale(scale_factor);
+
+        current_node.set_bounds(accesskit::Rect {
+            x0: padding_box.left() as f64,
+            y0: padding_box.top() as f64,
+            x1: padding_box.right() as f64,
+            y1: padding_box.bottom() as f64,
+        });
+
+        let current_index = tree.nodes.len();
+
+        if let Some(parent_index) = parent_index {
+            let parent_node = tree.nodes.get_mut(parent_index).unwrap();
+            parent_node.1.push_child(current_node_id);
+        }
+
+        tree.nodes.push((current_node_id, current_node));
+
+        if is_expanded {
+            for item in self.element_data_mut().children.iter_mut().skip(SUB_MENU_ITEMS_START) {
+                item.internal.compute_accessibility_tree(tree, Some(current_index), element_state, scale_factor);
+            }
+        }
+    }
+
+    fn default_style(&self) -> Style {
+        default_menu_item_style()
+    }
+}
+
+impl SubMenu {
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a SubMenuState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    pub fn new(label: &str) -> SubMenu {
+        let mut sub_menu = SubMenu {
+            element_data: Default::default(),
+            label: label.to_string(),
+            disabled: false,
+        };
+        sub_menu.update_row();
+        sub_menu
+    }
+
+    /// A disabled submenu is drawn grayed out and can't be opened.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self.update_row();
+        self
+    }
+
+    /// Whether the submenu can be highlighted and opened.
+    pub(crate) fn is_selectable(&self) -> bool {
+        !self.disabled
+    }
+
+    /// The items in the submenu's list.
+    pub(crate) fn items(&self) -> Vec<&dyn Element> {
+        self.element_data.children.iter().skip(SUB_MENU_ITEMS_START).map(|item| item.internal.as_ref()).collect()
+    }
+
+    /// Rebuilds the label and arrow children from the submenu's fields, keeping the items after them.
+    fn update_row(&mut self) {
+        let items = self.element_data.child_specs.split_off(self.element_data.child_specs.len().min(SUB_MENU_ITEMS_START));
+        self.element_data.child_specs = menu_item_row("", &self.label, "›", self.disabled);
+        self.element_data.child_specs.extend(items);
+    }
+
+    generate_component_methods_no_children!();
+
+    // The row is always the first children, so the push methods only touch the items after it.
+
+    #[allow(dead_code)]
+    pub fn push<T>(mut self, component_specification: T) -> Self
+    where
+        T: Into<ComponentSpecification>,
+    {
+        self.element_data.child_specs.push(component_specification.into());
+
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn push_children<T>(mut self, children: Vec<T>) -> Self
+    where
+        T: Into<ComponentSpecification>,
+    {
+        self.element_data.child_specs.truncate(SUB_MENU_ITEMS_START);
+        self.element_data.child_specs.extend(children.into_iter().map(|x| x.into()));
+
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn extend_children<T>(mut self, children: Vec<T>) -> Self
+    where
+        T: Into<ComponentSpecification>,
+    {
+        self.element_data.child_specs.extend(children.into_iter().map(|x| x.into()));
+
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn push_in_place(&mut self, component_specification: ComponentSpecification) {
+        self.element_data.child_specs.push(component_specification);
+    }
+}
+
+impl ElementStyles for SubMenu {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
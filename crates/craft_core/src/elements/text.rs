@@ -1,5 +1,5 @@
 use crate::components::component::ComponentSpecification;
-use crate::components::{Event, Props};
+use crate::components::{Event, FocusAction, Props};
 use crate::elements::element::{resolve_clip_for_scrollable, Element, ElementBoxed};
 use crate::elements::element_data::ElementData;
 use crate::elements::ElementStyles;
@@ -9,15 +9,19 @@ use crate::geometry::{Point, Rectangle};
 use crate::layout::layout_context::{LayoutContext, TaffyTextContext, TextHashKey};
 use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
 use crate::renderer::renderer::RenderList;
-use crate::style::Style;
+use crate::style::{FontFeature, FontStyle, FontVariation, Style, TextDecoration, TextDecorationStyle, TextOverflow, TextStyleProperty, Weight};
 use crate::text::text_context::{ColorBrush, TextContext};
 use crate::text::text_render_data;
 use crate::text::text_render_data::TextRender;
-use parley::{Alignment, AlignmentOptions, Selection};
+use parley::layout::cursor::Cursor;
+use parley::layout::Affinity;
+use parley::{AlignmentOptions, Selection, StyleSet};
+use peniko::Color;
 use rustc_hash::FxHasher;
 use std::any::Any;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
+use std::ops::Range;
 use std::sync::Arc;
 #[cfg(feature = "accesskit")]
 use {
@@ -33,17 +37,160 @@ use std::time;
 use taffy::{AvailableSpace, NodeId, Size, TaffyTree};
 use time::{Duration, Instant};
 use kurbo::Affine;
+use ui_events::keyboard::{Key, NamedKey};
 use winit::dpi;
 #[cfg(target_arch = "wasm32")]
 use web_time as time;
-use winit::window::Window;
+use winit::window::{CursorIcon, Window};
 
 // A stateful element that shows text.
 #[derive(Clone, Default)]
 pub struct Text {
     text: Option<String>,
+    /// The styled spans of the text and their byte ranges.
+    spans: Vec<(Range<usize>, TextSpan)>,
     element_data: ElementData,
     selectable: bool,
+    /// Whether a span is a link, which makes the text focusable.
+    has_links: bool,
+    text_overflow: TextOverflow,
+    max_lines: Option<usize>,
+}
+
+/// A run of styled text in a [`Text`], which may be a link.
+///
+/// Styles that are not set are inherited from the style of the `Text`.
+#[derive(Clone, Default, PartialEq)]
+pub struct TextSpan {
+    text: String,
+    styles: Vec<TextStyleProperty>,
+    /// How the decorations of the span are drawn, which is applied to all of them when the span is laid out.
+    decoration_style: Option<TextDecorationStyle>,
+    hover_color: Option<Color>,
+    background: Option<Color>,
+    link: Option<String>,
+}
+
+impl TextSpan {
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.styles.push(TextStyleProperty::Color(color));
+        self
+    }
+
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.styles.push(TextStyleProperty::FontSize(font_size));
+        self
+    }
+
+    pub fn font_weight(mut self, font_weight: Weight) -> Self {
+        self.styles.push(TextStyleProperty::FontWeight(font_weight));
+        self
+    }
+
+    pub fn font_style(mut self, font_style: FontStyle) -> Self {
+        self.styles.push(TextStyleProperty::FontStyle(font_style));
+        self
+    }
+
+    pub fn italic(self) -> Self {
+        self.font_style(FontStyle::Italic)
+    }
+
+    /// Slant the span as an oblique font style by an angle in degrees.
+    pub fn oblique_angle(mut self, angle: f32) -> Self {
+        self.styles.push(TextStyleProperty::FontObliqueAngle(angle));
+        self
+    }
+
+    /// Set the OpenType features of the span, which replace the features of the text.
+    pub fn font_features(mut self, features: &[FontFeature]) -> Self {
+        self.styles.push(TextStyleProperty::FontFeatures(features.to_vec()));
+        self
+    }
+
+    /// Set the variable font axis values of the span, which replace the values of the text.
+    pub fn font_variations(mut self, variations: &[FontVariation]) -> Self {
+        self.styles.push(TextStyleProperty::FontVariations(variations.to_vec()));
+        self
+    }
+
+    pub fn underline(mut self, thickness: f32, color: Color, offset: Option<f32>) -> Self {
+        self.styles.push(TextStyleProperty::Underline(true));
+        self.styles.push(TextStyleProperty::UnderlineSize(thickness));
+        self.styles.push(TextStyleProperty::UnderlineBrush(color));
+        if let Some(offset) = offset {
+            self.styles.push(TextStyleProperty::UnderlineOffset(offset));
+        }
+        self
+    }
+
+    pub fn strikethrough(mut self, thickness: f32, color: Color, offset: Option<f32>) -> Self {
+        self.styles.push(TextStyleProperty::Strikethrough(true));
+        self.styles.push(TextStyleProperty::StrikethroughSize(thickness));
+        self.styles.push(TextStyleProperty::StrikethroughBrush(color));
+        if let Some(offset) = offset {
+            self.styles.push(TextStyleProperty::StrikethroughOffset(offset));
+        }
+        self
+    }
+
+    pub fn overline(mut self, thickness: f32, color: Color, offset: Option<f32>) -> Self {
+        self.styles.push(TextStyleProperty::Overline(Some(TextDecoration {
+            thickness: Some(thickness),
+            color,
+            offset,
+            style: TextDecorationStyle::Solid,
+        })));
+        self
+    }
+
+    /// Set how the underline, strikethrough and overline of the span are drawn, whether they are set before or after.
+    pub fn decoration_style(mut self, style: TextDecorationStyle) -> Self {
+        self.decoration_style = Some(style);
+        self
+    }
+
+    /// Set the color that is drawn behind the span.
+    pub fn background(mut self, background: Color) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    /// Make the span a link, which emits [`CraftMessage::LinkClicked`] with `payload` when it is clicked.
+    ///
+    /// Links are underlined and show a pointer cursor while hovered. While the text is focused, the left and right
+    /// arrow keys move between its links and Enter clicks the focused link.
+    pub fn link(mut self, payload: &str) -> Self {
+        self.link = Some(payload.to_string());
+        self
+    }
+
+    /// Set the color of the span while it is a hovered link.
+    pub fn hover_color(mut self, hover_color: Color) -> Self {
+        self.hover_color = Some(hover_color);
+        self
+    }
+
+    /// Gives the decorations in `styles` the decoration style of the span.
+    fn apply_decoration_style(&self, styles: &mut Vec<TextStyleProperty>) {
+        let Some(style) = self.decoration_style else {
+            return;
+        };
+        for property in styles.iter_mut() {
+            if let TextStyleProperty::Overline(Some(overline)) = property {
+                overline.style = style;
+            }
+        }
+        styles.push(TextStyleProperty::UnderlineStyle(style));
+        styles.push(TextStyleProperty::StrikethroughStyle(style));
+    }
 }
 
 pub struct TextState {
@@ -51,6 +198,23 @@ pub struct TextState {
     selection: Selection,
     text: Option<String>,
     text_hash: Option<u64>,
+    spans: Vec<(Range<usize>, TextSpan)>,
+    /// The bounds of the spans that have a background or a link, relative to the text in physical pixels.
+    span_bounds: Vec<(usize, Rectangle)>,
+    /// The index of the span of the link under the pointer.
+    hovered_link: Option<usize>,
+    /// The index of the span of the link that the pointer was pressed on.
+    pressed_link: Option<usize>,
+    /// The index of the span of the link that Enter clicks while the text is focused.
+    focused_link: Option<usize>,
+    /// Whether the pointer cursor was set for a hovered link.
+    link_cursor: bool,
+    text_overflow: TextOverflow,
+    max_lines: Option<usize>,
+    /// The layout of the whole text, while `layout` is cut off.
+    untruncated_layout: Option<parley::Layout<ColorBrush>>,
+    /// The text of `layout` when it is cut off, and the length of the text it keeps before the ellipsis.
+    truncated_text: Option<(String, usize)>,
     text_render: Option<TextRender>,
     last_text_style: Style,
     layout: Option<parley::Layout<ColorBrush>>,
@@ -71,15 +235,48 @@ impl Text {
     pub fn new(text: &str) -> Text {
         Text {
             text: Some(text.to_string()),
+            spans: Vec::new(),
             element_data: Default::default(),
             selectable: true,
+            has_links: false,
+            text_overflow: TextOverflow::Clip,
+            max_lines: None,
         }
     }
 
+    /// Appends a styled span to the text, which is laid out together with the rest of the text.
+    pub fn span(mut self, mut span: TextSpan) -> Self {
+        let text = self.text.get_or_insert_with(String::new);
+        let range = text.len()..text.len() + span.text.len();
+        text.push_str(&std::mem::take(&mut span.text));
+        self.has_links |= span.link.is_some();
+        self.spans.push((range, span));
+        self
+    }
+
     pub fn disable_selection(mut self) -> Self {
         self.selectable = false;
         self
     }
+
+    /// Set how the text is cut off when it has more lines than `max_lines` or doesn't fit.
+    ///
+    /// With [`TextOverflow::Ellipsis`], the text is also cut off when it doesn't fit the height of the element,
+    /// or the width of the element if it doesn't wrap.
+    pub fn text_overflow(mut self, text_overflow: TextOverflow) -> Self {
+        self.text_overflow = text_overflow;
+        self
+    }
+
+    /// Set the maximum number of lines that are shown.
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines.max(1));
+        self
+    }
+
+    pub(crate) fn is_selectable(&self) -> bool {
+        self.selectable
+    }
 }
 
 impl Element for Text {
@@ -112,7 +309,7 @@ impl Element for Text {
             return;
         }
         let computed_box_transformed = self.computed_box_transformed();
-        let content_rectangle = computed_box_transformed.content_rectangle();
+        let content_rectangle = computed_box_transformed.content_rectangle().scale(scale_factor);
 
         self.draw_borders(renderer, element_state, scale_factor);
 
@@ -125,8 +322,23 @@ impl Element for Text {
             .downcast_mut()
             .unwrap();
 
+        let hovering_link = state.hovered_link.is_some();
+        if hovering_link != state.link_cursor {
+            let cursor = if hovering_link { CursorIcon::Pointer } else { CursorIcon::Default };
+            element_state.requested_cursor = Some(cursor.into());
+            state.link_cursor = hovering_link;
+        }
+
+        for (span, bounds) in &state.span_bounds {
+            if let Some(background) = state.spans[*span].1.background {
+                let bounds =
+                    Rectangle::new(content_rectangle.x + bounds.x, content_rectangle.y + bounds.y, bounds.width, bounds.height);
+                renderer.draw_rect(bounds, background);
+            }
+        }
+
         if let Some(text_render) = state.text_render.as_ref() {
-            renderer.draw_text(text_render.clone(), content_rectangle.scale(scale_factor), None, false);
+            renderer.draw_text(text_render.clone(), content_rectangle, None, false);
         }
     }
 
@@ -151,9 +363,10 @@ impl Element for Text {
             return;
         }
 
+        // The full text is the value, while the nodes of the layout have the text that is shown.
+        let text = state.laid_out_text();
         let layout = state.layout.as_mut();
         let mut access = LayoutAccessibility::default();
-        let text = state.text.as_ref().unwrap();
 
         let current_node_id = accesskit::NodeId(self.element_data().component_id);
 
@@ -171,7 +384,7 @@ impl Element for Text {
 
         if let Some(layout) = layout {
             access.build_nodes(
-                text,
+                &text,
                 layout,
                 tree,
                 &mut current_node,
@@ -181,6 +394,39 @@ impl Element for Text {
             );
         }
 
+        // A node for each link, over the bounds of its lines.
+        let content_box =
+            self.element_data().layout_item.computed_box_transformed.content_rectangle().scale(scale_factor);
+        for (index, (range, span)) in state.spans.iter().enumerate() {
+            if span.link.is_none() {
+                continue;
+            }
+            let mut link_node = accesskit::Node::new(Role::Link);
+            if let Some(label) = state.text.as_deref().and_then(|text| text.get(range.clone())) {
+                link_node.set_label(label);
+            }
+            link_node.add_action(Action::Click);
+            link_node.add_action(Action::Focus);
+            let bounds = state
+                .span_bounds
+                .iter()
+                .filter(|(span, _)| *span == index)
+                .map(|(_, bounds)| accesskit::Rect {
+                    x0: (content_box.x + bounds.x) as f64,
+                    y0: (content_box.y + bounds.y) as f64,
+                    x1: (content_box.x + bounds.right()) as f64,
+                    y1: (content_box.y + bounds.bottom()) as f64,
+                })
+                .reduce(|bounds, line_bounds| bounds.union(line_bounds));
+            if let Some(bounds) = bounds {
+                link_node.set_bounds(bounds);
+            }
+
+            let link_node_id = accesskit::NodeId(create_unique_element_id());
+            current_node.push_child(link_node_id);
+            tree.nodes.push((link_node_id, link_node));
+        }
+
         if let Some(parent_index) = parent_index {
             let parent_node = tree.nodes.get_mut(parent_index).unwrap();
             parent_node.1.push_child(current_node_id);
@@ -214,13 +460,14 @@ impl Element for Text {
         z_index: &mut u32,
         transform: Affine,
         element_state: &mut ElementStateStore,
-        _pointer: Option<Point>,
+        pointer: Option<Point>,
         text_context: &mut TextContext,
         clip_bounds: Option<Rectangle>,
     ) {
         let result = taffy_tree.layout(root_node).unwrap();
         self.resolve_box(position, transform, result, z_index);
         self.resolve_clip(clip_bounds);
+        let content_rectangle = self.computed_box_transformed().content_rectangle();
 
         self.finalize_borders(element_state);
 
@@ -237,9 +484,22 @@ impl Element for Text {
             state.layout(
                 state.last_requested_measure_key.unwrap().known_dimensions(),
                 state.last_requested_measure_key.unwrap().available_space(),
+                text_context,
             );
         }
 
+        // Lay out the text again when a link is hovered, to style it.
+        let hovered_link = pointer.and_then(|pointer| {
+            state.link_at(Point::new(pointer.x - content_rectangle.x as f64, pointer.y - content_rectangle.y as f64))
+        });
+        if hovered_link != state.hovered_link {
+            state.hovered_link = hovered_link;
+            let layout_key = state.current_layout_key.unwrap();
+            state.build_layout(text_context);
+            state.layout(layout_key.known_dimensions(), layout_key.available_space(), text_context);
+            state.current_render_key = None;
+        }
+
         state.try_update_text_render(text_context);
 
         let layout = state.layout.as_ref().unwrap();
@@ -256,6 +516,10 @@ impl Element for Text {
         self
     }
 
+    fn is_focusable(&self) -> bool {
+        self.has_links
+    }
+
     fn on_event(
         &self,
         message: &CraftMessage,
@@ -267,71 +531,131 @@ impl Element for Text {
         self.on_style_event(message, element_state, should_style, event);
         self.maybe_unset_focus(message, event);
 
-        if !self.selectable {
-            return;
-        }
-
         let base_state = self.get_base_state_mut(element_state);
+        let focused = base_state.base.focused;
 
         let state: &mut TextState = base_state.data.as_mut().downcast_mut().unwrap();
 
-        let _content_rect = self.computed_box().content_rectangle();
+        let content_rectangle = self.computed_box_transformed().content_rectangle();
+        let to_text_position = |x: f64, y: f64| Point::new(x - content_rectangle.x as f64, y - content_rectangle.y as f64);
+
+        // Handle links.
+        match message {
+            CraftMessage::PointerButtonDown(pointer_button) if pointer_button.is_primary() => {
+                let position = pointer_button.state.position;
+                state.pressed_link = state.link_at(to_text_position(position.x, position.y));
+                if let Some(link) = state.pressed_link {
+                    state.focused_link = Some(link);
+                    event.focus_action(FocusAction::Set(self.element_data.component_id));
+                }
+            }
+            CraftMessage::PointerButtonUp(pointer_button) if pointer_button.is_primary() => {
+                let position = pointer_button.state.position;
+                if let Some(link) = state.pressed_link.take() {
+                    // Releasing after selecting text doesn't open the link.
+                    if state.link_at(to_text_position(position.x, position.y)) == Some(link)
+                        && state.selection.is_collapsed()
+                    {
+                        let payload = state.spans[link].1.link.clone().unwrap();
+                        event.result_message(CraftMessage::LinkClicked(payload));
+                    }
+                }
+            }
+            CraftMessage::KeyboardInputEvent(keyboard_input) if focused && keyboard_input.state.is_down() => {
+                let links: Vec<usize> = state
+                    .spans
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (_, span))| span.link.is_some())
+                    .map(|(index, _)| index)
+                    .collect();
+                let position =
+                    state.focused_link.and_then(|focused_link| links.iter().position(|link| *link == focused_link));
+                match &keyboard_input.key {
+                    Key::Named(NamedKey::ArrowRight) => {
+                        if let Some(link) = links.get(position.map_or(0, |position| position + 1)) {
+                            state.focused_link = Some(*link);
+                        }
+                    }
+                    Key::Named(NamedKey::ArrowLeft) => {
+                        if let Some(link) = position
+                            .and_then(|position| position.checked_sub(1))
+                            .and_then(|position| links.get(position))
+                        {
+                            state.focused_link = Some(*link);
+                        }
+                    }
+                    Key::Named(NamedKey::Enter) => {
+                        if let Some(link) = links.get(position.unwrap_or(0)) {
+                            state.focused_link = Some(*link);
+                            let payload = state.spans[*link].1.link.clone().unwrap();
+                            event.result_message(CraftMessage::LinkClicked(payload));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+
+        if !self.selectable {
+            return;
+        }
 
         // Handle selection.
-        if self.selectable {
-            let text_position = self.computed_box_transformed().content_rectangle();
-
-            match message {
-                CraftMessage::PointerButtonDown(pointer_button) => {
-                    if pointer_button.is_primary() {
-                        state.pointer_down = true;
-                        state.cursor_reset();
-                        let now = Instant::now();
-                        if let Some(last) = state.last_click_time.take() {
-                            if now.duration_since(last).as_secs_f64() < 0.25 {
-                                state.click_count = (state.click_count + 1) % 4;
-                            } else {
-                                state.click_count = 1;
-                            }
+        let text_position = self.computed_box_transformed().content_rectangle();
+
+        match message {
+            CraftMessage::PointerButtonDown(pointer_button) => {
+                if pointer_button.is_primary() {
+                    state.pointer_down = true;
+                    state.cursor_reset();
+                    let now = Instant::now();
+                    if let Some(last) = state.last_click_time.take() {
+                        if now.duration_since(last).as_secs_f64() < 0.25 {
+                            state.click_count = (state.click_count + 1) % 4;
                         } else {
                             state.click_count = 1;
                         }
-                        state.last_click_time = Some(now);
-                        let click_count = state.click_count;
-                        let cursor_pos = state.cursor_pos;
-                        match click_count {
-                            2 => state.select_word_at_point(cursor_pos),
-                            3 => state.select_line_at_point(cursor_pos),
-                            _ => state.move_to_point(cursor_pos),
-                        }
-                        if click_count == 1 {
-                            base_state.base.pointer_capture.insert(DUMMY_DEVICE_ID, true);
-                        }
-                        event.prevent_defaults();
+                    } else {
+                        state.click_count = 1;
                     }
-                }
-                CraftMessage::PointerButtonUp(pointer_button) => {
-                    if pointer_button.is_primary() {
-                        state.pointer_down = false;
-                        state.cursor_reset();
-                        base_state.base.pointer_capture.insert(DUMMY_DEVICE_ID, false);
-                        event.prevent_defaults();
+                    state.last_click_time = Some(now);
+                    let click_count = state.click_count;
+                    let cursor_pos = state.cursor_pos;
+                    match click_count {
+                        2 => state.select_word_at_point(cursor_pos),
+                        3 => state.select_line_at_point(cursor_pos),
+                        _ => state.move_to_point(cursor_pos),
                     }
-                }
-                CraftMessage::PointerMovedEvent(pointer_moved) => {
-                    let prev_pos = state.cursor_pos;
-                    // NOTE: Cursor position should be relative to the top left of the text box.
-                    state.cursor_pos = pointer_moved.current.position - kurbo::Vec2::new(text_position.x as f64, text_position.y as f64);
-                    // macOS seems to generate a spurious move after selecting word?
-                    if state.pointer_down && prev_pos != state.cursor_pos {
-                        state.cursor_reset();
-                        let cursor_pos = state.cursor_pos;
-                        state.extend_selection_to_point(cursor_pos);
+                    if click_count == 1 {
+                        base_state.base.pointer_capture.insert(DUMMY_DEVICE_ID, true);
                     }
                     event.prevent_defaults();
                 }
-                _ => {}
             }
+            CraftMessage::PointerButtonUp(pointer_button) => {
+                if pointer_button.is_primary() {
+                    state.pointer_down = false;
+                    state.cursor_reset();
+                    base_state.base.pointer_capture.insert(DUMMY_DEVICE_ID, false);
+                    event.prevent_defaults();
+                }
+            }
+            CraftMessage::PointerMovedEvent(pointer_moved) => {
+                let prev_pos = state.cursor_pos;
+                // NOTE: Cursor position should be relative to the top left of the text box.
+                state.cursor_pos =
+                    pointer_moved.current.position - kurbo::Vec2::new(text_position.x as f64, text_position.y as f64);
+                // macOS seems to generate a spurious move after selecting word?
+                if state.pointer_down && prev_pos != state.cursor_pos {
+                    state.cursor_reset();
+                    let cursor_pos = state.cursor_pos;
+                    state.extend_selection_to_point(cursor_pos);
+                }
+                event.prevent_defaults();
+            }
+            _ => {}
         }
     }
 
@@ -341,11 +665,26 @@ impl Element for Text {
 
     fn initialize_state(&mut self, scaling_factor: f64) -> ElementStateStoreItem {
         let hash = hash_string(self.text.as_ref().unwrap());
+        let mut text = std::mem::take(&mut self.text);
+        let mut spans = std::mem::take(&mut self.spans);
+        if self.style().white_space().collapses() {
+            text = text.map(|text| collapse_white_space(&text, &mut spans));
+        }
         let text_state = TextState {
             scale_factor: scaling_factor as f32,
             selection: Selection::default(),
-            text: std::mem::take(&mut self.text),
+            text,
             text_hash: Some(hash),
+            spans,
+            span_bounds: Vec::new(),
+            hovered_link: None,
+            pressed_link: None,
+            focused_link: None,
+            link_cursor: false,
+            text_overflow: self.text_overflow,
+            max_lines: self.max_lines,
+            untruncated_layout: None,
+            truncated_text: None,
             text_render: None,
             last_text_style: *self.style(),
             layout: None,
@@ -392,7 +731,9 @@ impl Element for Text {
         let last_style = &state.last_text_style;
 
         let current_style = *base_state.base.current_style(self.element_data());
-        if last_style.color() != current_style.color() {
+        let color_changed = last_style.color() != current_style.color();
+        // Overriding the color would also override the colors of the spans, so rich text is laid out again instead.
+        if color_changed && self.spans.is_empty() {
             if let Some(text_render) = state.text_render.as_mut() {
                 text_render.override_brush = Some(ColorBrush::new(current_style.color()));
             }
@@ -404,15 +745,46 @@ impl Element for Text {
             current_style.font_size() != last_style.font_size()
                 || current_style.font_weight() != last_style.font_weight()
                 || current_style.font_style() != last_style.font_style()
+                || current_style.font_oblique_angle() != last_style.font_oblique_angle()
+                || current_style.font_features() != last_style.font_features()
+                || current_style.font_variations() != last_style.font_variations()
                 || current_style.font_family() != last_style.font_family()
                 || current_style.underline() != last_style.underline()
+                || current_style.strikethrough() != last_style.strikethrough()
+                || current_style.overline() != last_style.overline()
+                || current_style.line_height() != last_style.line_height()
+                || current_style.letter_spacing() != last_style.letter_spacing()
+                || current_style.word_spacing() != last_style.word_spacing()
+                || current_style.text_align() != last_style.text_align()
+                || current_style.direction() != last_style.direction()
+                || current_style.white_space() != last_style.white_space()
+                || (color_changed && !self.spans.is_empty())
         };
 
-        let text = std::mem::take(&mut self.text);
+        let mut text = std::mem::take(&mut self.text);
+        let mut spans = std::mem::take(&mut self.spans);
+        if current_style.white_space().collapses() {
+            text = text.map(|text| collapse_white_space(&text, &mut spans));
+        }
+        let spans_changed = state.spans != spans;
+
+        let overflow_changed = state.text_overflow != self.text_overflow || state.max_lines != self.max_lines;
 
-        if state.text_hash != Some(text_hash) || reload_fonts || style_changed || scale_factor_changed {
+        if state.text_hash != Some(text_hash)
+            || spans_changed
+            || overflow_changed
+            || reload_fonts
+            || style_changed
+            || scale_factor_changed
+        {
             state.text_hash = Some(text_hash);
             state.text = text;
+            state.spans = spans;
+            state.text_overflow = self.text_overflow;
+            state.max_lines = self.max_lines;
+            state.hovered_link = None;
+            state.pressed_link = None;
+            state.focused_link = None;
             state.layout = None;
             state.cache.clear();
             state.current_layout_key = None;
@@ -425,6 +797,35 @@ impl Element for Text {
     }
 }
 
+/// Collapses each run of white space into a single space, moving the spans along with their text.
+fn collapse_white_space(text: &str, spans: &mut [(Range<usize>, TextSpan)]) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    // The index in the collapsed text of each byte index in the text.
+    let mut indices = Vec::with_capacity(text.len() + 1);
+    let mut after_white_space = false;
+    for c in text.chars() {
+        indices.extend(std::iter::repeat_n(collapsed.len(), c.len_utf8()));
+        let white_space = c.is_whitespace();
+        if !(white_space && after_white_space) {
+            collapsed.push(if white_space { ' ' } else { c });
+        }
+        after_white_space = white_space;
+    }
+    indices.push(collapsed.len());
+
+    for (range, _) in spans {
+        *range = indices[range.start]..indices[range.end];
+    }
+    collapsed
+}
+
+/// The range of a span in a shown text of `text_length` bytes that keeps the first `kept` bytes of the text. The
+/// span that contains the last kept character also covers the ellipsis after it.
+fn shown_span_range(range: &Range<usize>, kept: usize, text_length: usize) -> Range<usize> {
+    let contains_last_kept = range.start < kept && kept <= range.end;
+    range.start.min(kept)..if contains_last_kept { text_length } else { range.end.min(kept) }
+}
+
 fn hash_string(text: &str) -> u64 {
     let mut hasher = FxHasher::default();
     text.hash(&mut hasher);
@@ -442,6 +843,88 @@ impl ElementStyles for Text {
 }
 
 impl TextState {
+    /// The text that is currently laid out.
+    pub(crate) fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// The text that is shown, which may be cut off.
+    fn shown_text(&self) -> &str {
+        self.truncated_text.as_ref().map(|(text, _)| text.as_str()).or(self.text.as_deref()).unwrap_or_default()
+    }
+
+    /// Whether a selection is being dragged from this text.
+    pub(crate) fn is_dragging_selection(&self) -> bool {
+        self.pointer_down && self.click_count == 1
+    }
+
+    /// The text that the layout is built from, which starts with the mark of the direction.
+    #[cfg(feature = "accesskit")]
+    fn laid_out_text(&self) -> String {
+        format!("{}{}", self.last_text_style.direction().mark(), self.shown_text())
+    }
+
+    /// Converts a byte index into the text to a byte index into the layout, which starts with the direction mark.
+    fn layout_index(&self, index: usize) -> usize {
+        self.last_text_style.direction().mark().len() + index
+    }
+
+    /// Converts a byte index into the layout to a byte index into the text.
+    fn text_index(&self, index: usize) -> usize {
+        index.saturating_sub(self.last_text_style.direction().mark().len())
+    }
+
+    /// The byte index that the selection started at.
+    pub(crate) fn selection_anchor(&self) -> usize {
+        self.text_index(self.selection.anchor().index())
+    }
+
+    pub(crate) fn selected_text(&self) -> Option<&str> {
+        if self.selection.is_collapsed() {
+            return None;
+        }
+        let range = self.selection.text_range();
+        self.shown_text().get(self.text_index(range.start)..self.text_index(range.end))
+    }
+
+    /// Selects the text from the byte index `anchor` to `focus`, which are clamped to the shown text.
+    pub(crate) fn select_range(&mut self, anchor: usize, focus: usize) {
+        let Some(layout) = self.layout.as_ref() else {
+            return;
+        };
+        let length = self.shown_text().len();
+        let (anchor, focus) = (anchor.min(length), focus.min(length));
+        let (anchor_affinity, focus_affinity) = if anchor <= focus {
+            (Affinity::Downstream, Affinity::Upstream)
+        } else {
+            (Affinity::Upstream, Affinity::Downstream)
+        };
+        self.selection = Selection::new(
+            Cursor::from_byte_index(layout, self.layout_index(anchor), anchor_affinity),
+            Cursor::from_byte_index(layout, self.layout_index(focus), focus_affinity),
+        );
+    }
+
+    pub(crate) fn select_all(&mut self) {
+        self.select_range(0, usize::MAX);
+    }
+
+    pub(crate) fn clear_selection(&mut self) {
+        if !self.selection.is_collapsed() {
+            self.selection = Selection::default();
+        }
+    }
+
+    /// The byte index of the text closest to `point`, which is relative to the text in logical pixels.
+    pub(crate) fn index_at_point(&self, point: Point) -> usize {
+        let Some(layout) = self.layout.as_ref() else {
+            return 0;
+        };
+        let scale_factor = layout.scale() as f64;
+        let cursor = Cursor::from_point(layout, (point.x * scale_factor) as f32, (point.y * scale_factor) as f32);
+        self.text_index(cursor.index())
+    }
+
     pub fn measure(
         &mut self,
         known_dimensions: Size<Option<f32>>,
@@ -449,11 +932,7 @@ impl TextState {
         text_context: &mut TextContext,
     ) -> Size<f32> {
         if self.layout.is_none() {
-            let mut builder = text_context.tree_builder(self.scale_factor, &self.last_text_style.to_text_style());
-            let text = &self.text.as_ref().unwrap();
-            builder.push_text(text);
-            let (layout, _) = builder.build();
-            self.layout = Some(layout);
+            self.build_layout(text_context);
         }
 
         let key = TextHashKey::new(known_dimensions, available_space);
@@ -469,7 +948,7 @@ impl TextState {
             }
         }
 
-        let size = self.layout(known_dimensions, available_space);
+        let size = self.layout(known_dimensions, available_space, text_context);
         let sw = dpi::LogicalUnit::from_physical::<f32, f32>(size.width, self.scale_factor as f64).0;
         let sh = dpi::LogicalUnit::from_physical::<f32, f32>(size.height, self.scale_factor as f64).0;
         Size {
@@ -478,14 +957,151 @@ impl TextState {
         }
     }
 
-    pub fn layout(&mut self, known_dimensions: Size<Option<f32>>, available_space: Size<AvailableSpace>) -> Size<f32> {
+    /// Creates the layout of the text and its spans, which still needs to be broken into lines.
+    fn build_layout(&mut self, text_context: &mut TextContext) {
+        let text = self.text.as_deref().unwrap();
+        self.layout = Some(self.create_layout(text, text.len(), text_context));
+        self.untruncated_layout = None;
+        self.truncated_text = None;
+    }
+
+    /// Creates a layout of `text`, which is the first `kept` bytes of the text possibly followed by an ellipsis,
+    /// which takes the style of the span that contains the last kept character.
+    ///
+    /// The layout starts with the mark of the direction, so its byte indices are offset by the length of the mark.
+    fn create_layout(&self, text: &str, kept: usize, text_context: &mut TextContext) -> parley::Layout<ColorBrush> {
+        let direction_mark = self.last_text_style.direction().mark();
+        let laid_out_text = format!("{direction_mark}{text}");
+        let mut style_set = StyleSet::new(self.last_text_style.font_size());
+        self.last_text_style.add_styles_to_style_set(&mut style_set);
+        let mut builder = text_context.layout_context.ranged_builder(
+            &mut text_context.font_context,
+            &laid_out_text,
+            self.scale_factor,
+            true,
+        );
+        for prop in style_set.inner().values() {
+            builder.push_default(prop.to_owned());
+        }
+
+        for (index, (range, span)) in self.spans.iter().enumerate() {
+            let range = shown_span_range(range, kept, text.len());
+            let range = self.layout_index(range.start)..self.layout_index(range.end);
+            let mut styles = span.styles.clone();
+            if self.hovered_link == Some(index) {
+                styles.push(TextStyleProperty::Underline(true));
+                styles.extend(span.hover_color.map(TextStyleProperty::Color));
+            }
+            span.apply_decoration_style(&mut styles);
+            TextStyleProperty::push_to_builder(&styles, self.last_text_style.text_brush(), range, &mut builder);
+        }
+
+        builder.build(&laid_out_text)
+    }
+
+    /// Cuts the text off after the lines that fit, and with an ellipsis at the last glyph that fits if the text
+    /// overflow is [`TextOverflow::Ellipsis`].
+    fn truncate(&mut self, width: Option<f32>, height: Option<f32>, wraps: bool, text_context: &mut TextContext) {
+        let ellipsis = self.text_overflow == TextOverflow::Ellipsis;
+        let layout = self.layout.as_ref().unwrap();
+        let line_count = layout.len();
+
+        let mut visible_lines = self.max_lines.unwrap_or(usize::MAX).min(line_count);
+        if let Some(height) = height.filter(|_| ellipsis) {
+            let fitting_lines = layout.lines().take_while(|line| line.metrics().max_coord <= height).count();
+            visible_lines = visible_lines.min(fitting_lines.max(1));
+        }
+        let Some(last_line) = visible_lines.checked_sub(1).and_then(|index| layout.get(index)) else {
+            return;
+        };
+
+        let line_range = self.text_index(last_line.text_range().start)..self.text_index(last_line.text_range().end);
+        let metrics = last_line.metrics();
+        let overflows = ellipsis && !wraps && width.is_some_and(|width| metrics.advance > width);
+        if visible_lines == line_count && !overflows {
+            return;
+        }
+
+        let text = self.text.as_deref().unwrap();
+        let suffix = if ellipsis { "…" } else { "" };
+        let cut = match width {
+            // Leave about an em for the ellipsis.
+            Some(width) if ellipsis => {
+                let x = width - self.last_text_style.font_size() * self.scale_factor;
+                let index = Cursor::from_point(layout, x, metrics.baseline).index();
+                self.text_index(index).clamp(line_range.start, line_range.end)
+            }
+            _ => line_range.end,
+        };
+
+        let mut truncate_at = |cut: usize| {
+            let kept_length = text[..cut].trim_end().len();
+            let truncated = format!("{}{suffix}", &text[..kept_length]);
+            let mut truncated_layout = self.create_layout(&truncated, kept_length, text_context);
+            truncated_layout.break_all_lines(if wraps { width } else { None });
+            let fits = truncated_layout.len() <= visible_lines
+                && (wraps
+                    || truncated_layout
+                        .get(visible_lines - 1)
+                        .is_none_or(|line| width.is_none_or(|width| line.metrics().advance <= width)));
+            (fits, truncated, kept_length, truncated_layout)
+        };
+
+        // If the text with the ellipsis doesn't fit, the last cut before it that does is bisected. The start of the
+        // line is kept even if it doesn't fit.
+        let mut truncation = truncate_at(cut);
+        if !truncation.0 && cut > line_range.start {
+            let cuts: Vec<usize> =
+                text[line_range.start..cut].char_indices().map(|(index, _)| line_range.start + index).collect();
+            let mut fitting = None;
+            let (mut low, mut high) = (0, cuts.len());
+            while low < high {
+                let middle = (low + high) / 2;
+                let candidate = truncate_at(cuts[middle]);
+                if candidate.0 {
+                    low = middle + 1;
+                    fitting = Some(candidate);
+                } else {
+                    high = middle;
+                }
+            }
+            truncation = fitting.unwrap_or_else(|| truncate_at(line_range.start));
+        }
+
+        let (_, truncated, kept_length, truncated_layout) = truncation;
+        self.untruncated_layout = self.layout.replace(truncated_layout);
+        self.truncated_text = Some((truncated, kept_length));
+    }
+
+    /// The index of the span of the link at `point`, which is relative to the text in logical pixels.
+    fn link_at(&self, point: Point) -> Option<usize> {
+        let point = Point::new(point.x * self.scale_factor as f64, point.y * self.scale_factor as f64);
+        self.span_bounds
+            .iter()
+            .find(|(span, bounds)| self.spans[*span].1.link.is_some() && bounds.contains(&point))
+            .map(|(span, _)| *span)
+    }
+
+    pub fn layout(
+        &mut self,
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        text_context: &mut TextContext,
+    ) -> Size<f32> {
         let key = TextHashKey::new(known_dimensions, available_space);
 
+        // Lay out the whole text, which may fit now.
+        if let Some(untruncated_layout) = self.untruncated_layout.take() {
+            self.layout = Some(untruncated_layout);
+            self.truncated_text = None;
+        }
         let layout = self.layout.as_mut().unwrap();
 
+        // Text that doesn't wrap can't be narrower than its longest line.
+        let wraps = self.last_text_style.white_space().wraps();
         let width_constraint = known_dimensions.width.or(match available_space.width {
-            AvailableSpace::MinContent => Some(layout.calculate_content_widths().min),
-            AvailableSpace::MaxContent => Some(layout.calculate_content_widths().max),
+            AvailableSpace::MinContent if wraps => Some(layout.calculate_content_widths().min),
+            AvailableSpace::MinContent | AvailableSpace::MaxContent => Some(layout.calculate_content_widths().max),
             AvailableSpace::Definite(width) => {
                 let scaled_width = dpi::PhysicalUnit::from_logical::<f32, f32>(width, self.scale_factor as f64).0;
                 Some(scaled_width)
@@ -500,8 +1116,12 @@ impl TextState {
                 Some(scaled_height)
             },
         });
-        layout.break_all_lines(width_constraint);
-        layout.align(width_constraint, Alignment::Start, AlignmentOptions::default());
+        layout.break_all_lines(if wraps { width_constraint } else { None });
+        self.truncate(width_constraint, height_constraint, wraps, text_context);
+
+        let layout = self.layout.as_mut().unwrap();
+        let alignment = self.last_text_style.text_align().to_parley_alignment(self.last_text_style.direction());
+        layout.align(width_constraint, alignment, AlignmentOptions::default());
 
         let width = layout.width();
         let height = layout.height().min(height_constraint.unwrap_or(f32::MAX));
@@ -521,6 +1141,25 @@ impl TextState {
         let layout = self.layout.as_ref().unwrap();
         self.text_render = Some(text_render_data::from_editor(layout));
         self.current_render_key = self.current_layout_key;
+
+        self.span_bounds.clear();
+        let text_length = self.shown_text().len();
+        let kept = self.truncated_text.as_ref().map_or(text_length, |(_, kept)| *kept);
+        let mark_length = self.last_text_style.direction().mark().len();
+        for (index, (range, span)) in self.spans.iter().enumerate() {
+            // The spans may be cut off with the text.
+            let range = shown_span_range(range, kept, text_length);
+            if (span.background.is_none() && span.link.is_none()) || range.is_empty() {
+                continue;
+            }
+            let selection = Selection::new(
+                Cursor::from_byte_index(layout, mark_length + range.start, Affinity::Downstream),
+                Cursor::from_byte_index(layout, mark_length + range.end, Affinity::Upstream),
+            );
+            selection.geometry_with(layout, |rect, _line| {
+                self.span_bounds.push((index, rect.into()));
+            });
+        }
     }
 
     pub fn cursor_reset(&mut self) {
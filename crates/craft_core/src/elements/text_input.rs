@@ -1,3 +1,4 @@
+use crate::clipboard::Clipboard;
 use crate::components::component::ComponentSpecification;
 use crate::components::{Event, FocusAction};
 use crate::components::{ImeAction, Props};
@@ -14,19 +15,21 @@ use crate::renderer::renderer::{RenderList, TextScroll};
 use crate::style::{Display, Style, Unit, Weight};
 use crate::CraftMessage;
 use std::any::Any;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
 use taffy::{AvailableSpace, NodeId, TaffyTree};
 
 use crate::layout::layout_context::TextHashKey;
-use crate::text::text_context::{TextContext};
+use crate::text::text_context::{ColorBrush, TextContext};
 use crate::text::text_render_data::TextRender;
-use crate::text::{text_render_data, RangedStyles, TextStyle};
+use crate::text::{same_font, text_render_data, RangedStyles, TextStyle};
+use crate::text::input_mask::InputMask;
 #[cfg(not(target_arch = "wasm32"))]
 use std::time;
 use time::{Duration, Instant};
 use kurbo::Affine;
-use parley::StyleProperty;
+use parley::{AlignmentOptions, StyleProperty};
 use ui_events::keyboard::{Key, Modifiers, NamedKey};
 use winit::dpi;
 #[cfg(target_arch = "wasm32")]
@@ -45,6 +48,13 @@ pub struct TextInput {
     use_text_value_on_update: bool,
     pub text: Option<String>,
     pub ranged_styles: Option<RangedStyles>,
+    password: Option<char>,
+    placeholder: Option<String>,
+    placeholder_style: Style,
+    max_length: Option<usize>,
+    read_only: bool,
+    filter: Option<Arc<dyn Fn(char) -> bool + Send + Sync>>,
+    input_mask: Option<InputMask>,
 }
 
 #[derive(Clone, Default, Debug)]
@@ -58,6 +68,8 @@ pub enum TextInputMessage {
     Copy,
     Paste,
     Cut,
+    Undo,
+    Redo,
     // TODO: Add more messages.
 }
 
@@ -67,6 +79,17 @@ pub struct TextInputState {
     #[allow(dead_code)]
     pub(crate) ime_state: ImeState,
     pub(crate) editor: PlainEditor,
+    /// The maximum number of characters, enforced when typing, pasting and committing IME text.
+    pub(crate) max_length: Option<usize>,
+    /// Whether the text can be selected and copied, but not edited.
+    pub(crate) read_only: bool,
+    /// Whether a typed, pasted or IME committed character is accepted.
+    pub(crate) filter: Option<Arc<dyn Fn(char) -> bool + Send + Sync>>,
+    /// The mask that the text is reformatted with after every edit.
+    pub(crate) input_mask: Option<InputMask>,
+    placeholder: Option<String>,
+    placeholder_style: Style,
+    placeholder_render: Option<TextRender>,
 
     cache: HashMap<TextHashKey, taffy::Size<f32>>,
     current_key: Option<TextHashKey>,
@@ -92,6 +115,13 @@ impl TextInput {
             element_data: ElementData::default(),
             use_text_value_on_update: true,
             ranged_styles: Some(RangedStyles::new(vec![])),
+            password: None,
+            placeholder: None,
+            placeholder_style: default_placeholder_style(),
+            max_length: None,
+            read_only: false,
+            filter: None,
+            input_mask: None,
         }
     }
 
@@ -104,6 +134,17 @@ impl TextInput {
     fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a TextInputState {
         element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
     }
+
+    /// Passes the password, placeholder, max length and read-only options to the state.
+    fn update_options(&self, state: &mut TextInputState) {
+        state.max_length = self.max_length;
+        state.read_only = self.read_only;
+        state.filter = self.filter.clone();
+        state.input_mask = self.input_mask.clone();
+        state.set_mask(self.password);
+        let placeholder_style = Style::merge(self.style(), &self.placeholder_style);
+        state.set_placeholder(self.placeholder.as_deref(), &placeholder_style);
+    }
 }
 
 impl Element for TextInput {
@@ -132,49 +173,7 @@ impl Element for TextInput {
         _window: Option<Arc<Window>>,
         scale_factor: f64,
     ) {
-        if !self.element_data.style.visible() {
-            return;
-        }
-        let computed_box_transformed = self.computed_box_transformed();
-        let content_rectangle = computed_box_transformed.content_rectangle();
-
-        self.draw_borders(renderer, element_state, scale_factor);
-
-        let is_scrollable = self.element_data.is_scrollable();
-
-        let element_data = self.element_data();
-        let padding_rectangle = element_data.layout_item.computed_box_transformed.padding_rectangle();
-        renderer.push_layer(padding_rectangle.scale(scale_factor));
-
-        let scroll_y = if let Some(state) =
-            element_state.storage.get(&self.element_data.component_id).unwrap().data.downcast_ref::<TextInputState>()
-        {
-            state.scroll_state.scroll_y
-        } else {
-            0.0
-        };
-
-        let text_scroll = if is_scrollable {
-            Some(TextScroll::new(scroll_y, self.element_data.layout_item.computed_scroll_track.height))
-        } else {
-            None
-        };
-
-        if let Some(state) = element_state
-            .storage
-            .get_mut(&self.element_data.component_id)
-            .unwrap()
-            .data
-            .downcast_mut::<TextInputState>()
-        {
-            if let Some(text_render) = state.text_render.as_ref() {
-                renderer.draw_text(text_render.clone(), content_rectangle.scale(scale_factor), text_scroll, state.cursor_visible);
-            }
-        }
-
-        renderer.pop_layer();
-
-        self.draw_scrollbar(renderer, scale_factor);
+        draw_text_input(self, renderer, element_state, scale_factor);
     }
 
     fn compute_layout(
@@ -184,13 +183,7 @@ impl Element for TextInput {
         _scale_factor: f64,
     ) -> Option<NodeId> {
         self.merge_default_style();
-        let style: taffy::Style = self.element_data.style.to_taffy_style();
-
-        self.element_data.layout_item.build_tree_with_context(
-            taffy_tree,
-            style,
-            LayoutContext::TextInput(TaffyTextInputContext::new(self.element_data.component_id)),
-        )
+        compute_text_input_layout(self, taffy_tree)
     }
 
     fn finalize_layout(
@@ -205,90 +198,86 @@ impl Element for TextInput {
         text_context: &mut TextContext,
         clip_bounds: Option<Rectangle>,
     ) {
-        let result = taffy_tree.layout(root_node).unwrap();
-        self.resolve_box(position, transform, result, z_index);
-        self.resolve_clip(clip_bounds);
-
-        self.finalize_borders(element_state);
-
-        let state: &mut TextInputState = element_state
-            .storage
-            .get_mut(&self.element_data.component_id)
-            .unwrap()
-            .data
-            .as_mut()
-            .downcast_mut()
-            .unwrap();
-
-        if state.current_key != state.last_requested_key {
-            state.layout(
-                state.last_requested_key.unwrap().known_dimensions(),
-                state.last_requested_key.unwrap().available_space(),
-                text_context,
-            );
-        }
-
-        let _layout = state.editor.try_layout().as_ref().unwrap();
-        let text_renderer = state.text_render.as_mut().unwrap();
-        for line in text_renderer.lines.iter_mut() {
-            line.selections.clear();
-        }
-        state.editor.selection_geometry_with(|rect, line| {
-            text_renderer.lines[line].selections.push(rect.into());
-        });
-        text_renderer.cursor = state.editor.cursor_geometry(1.0).map(|r| r.into());
-
-        self.element_data.layout_item.scrollbar_size =
-            Size::new(result.scrollbar_size.width, result.scrollbar_size.height);
-        self.element_data.layout_item.computed_scrollbar_size =
-            Size::new(result.scroll_width(), result.scroll_height());
-
-        if let Some(state) = element_state
-            .storage
-            .get_mut(&self.element_data.component_id)
-            .unwrap()
-            .data
-            .downcast_mut::<TextInputState>()
-        {
-            self.finalize_scrollbar(&mut state.scroll_state);
-        }
+        finalize_text_input_layout(
+            self,
+            taffy_tree,
+            root_node,
+            position,
+            z_index,
+            transform,
+            element_state,
+            text_input_state,
+            text_context,
+            clip_bounds,
+        );
     }
 
     fn as_any(&self) -> &dyn Any {
         self
     }
 
+    fn is_focusable(&self) -> bool {
+        true
+    }
+
     fn on_event(
         &self,
         message: &CraftMessage,
         element_state: &mut ElementStateStore,
-        _text_context: &mut TextContext,
+        text_context: &mut TextContext,
         should_style: bool,
         event: &mut Event,
     ) {
-        self.on_style_event(message, element_state, should_style, event);
-        self.maybe_unset_focus(message, event);
+        on_text_input_event(self, message, element_state, text_input_state, text_context, should_style, event);
+    }
 
-        let base_state = self.get_base_state_mut(element_state);
-        let state = base_state.data.as_mut().downcast_mut::<TextInputState>().unwrap();
-        state.is_active = true;
+    fn resolve_clip(&mut self, clip_bounds: Option<Rectangle>) {
+        resolve_clip_for_scrollable(self, clip_bounds);
+    }
 
-        state.scroll_state.on_event(message, &self.element_data, &mut base_state.base, event);
+    fn initialize_state(&mut self, scaling_factor: f64) -> ElementStateStoreItem {
+        let mut text = std::mem::take(&mut self.text);
+        if let (Some(text), Some(input_mask)) = (text.as_mut(), self.input_mask.as_ref()) {
+            *text = input_mask.apply(text);
+        }
+        let mut text_input_state =
+            TextInputState::new(self.style(), text, self.ranged_styles.clone().unwrap(), scaling_factor);
+        self.update_options(&mut text_input_state);
 
-        if !event.propagate {
-            return;
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(text_input_state),
         }
+    }
 
-        let scroll_y = state.scroll_state.scroll_y;
+    #[cfg(feature = "accesskit")]
+    fn compute_accessibility_tree(
+        &mut self,
+        tree: &mut accesskit::TreeUpdate,
+        parent_index: Option<usize>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) {
+        let role = if self.password.is_some() { accesskit::Role::PasswordInput } else { accesskit::Role::TextInput };
+        let mut current_node = accesskit::Node::new(role);
+        if self.read_only {
+            current_node.set_read_only();
+        }
+        if let Some(placeholder) = &self.placeholder {
+            current_node.set_placeholder(placeholder.as_str());
+        }
+        compute_text_input_accessibility_tree(
+            self,
+            current_node,
+            tree,
+            parent_index,
+            element_state,
+            text_input_state,
+            scale_factor,
+        );
+    }
 
-        let scale_factor = state.editor.try_layout().unwrap().scale() as f64;
-        let text_position = self.computed_box_transformed().content_rectangle();
-        let text_x = text_position.x;
-        let text_y = text_position.y;
-        let focused = element_state
-            .storage
-            .get(&self.element_data.component_id)
-            .unwrap().base.focused;
+    fn update_state(&mut self, element_state: &mut ElementStateStore, reload_fonts: bool, scaling_factor: f64) {
         let state: &mut TextInputState = element_state
             .storage
             .get_mut(&self.element_data.component_id)
@@ -298,338 +287,692 @@ impl Element for TextInput {
             .downcast_mut()
             .unwrap();
 
-        fn copy(drv: &mut PlainEditorDriver) {
-            #[cfg(all(any(target_os = "windows", target_os = "macos", target_os = "linux"), feature = "clipboard"))]
-            {
-                use clipboard_rs::{Clipboard, ClipboardContext};
-                if let Some(text) = drv.editor.selected_text() {
-                    let cb = ClipboardContext::new().unwrap();
-                    cb.set_text(text.to_owned()).ok();
-                }
-            }
+        state.update_scale(scaling_factor);
+        if reload_fonts {
+            state.reload_fonts();
         }
 
-        fn paste(drv: &mut PlainEditorDriver) {
-            #[cfg(all(any(target_os = "windows", target_os = "macos", target_os = "linux"), feature = "clipboard"))]
-            {
-                use clipboard_rs::{Clipboard, ClipboardContext};
-                let cb = ClipboardContext::new().unwrap();
-                let text = cb.get_text().unwrap_or_default();
-                drv.insert_or_replace_selection(&text);
+        if self.ranged_styles.as_ref() != Some(&state.editor.ranged_styles) {
+            let ranged_styles = std::mem::take(&mut self.ranged_styles);
+            if let Some(ranged_styles) = ranged_styles {
+                state.editor.set_ranged_styles(ranged_styles);
+                state.cache.clear();
             }
         }
 
-        fn cut(drv: &mut PlainEditorDriver) {
-            #[cfg(all(any(target_os = "windows", target_os = "macos", target_os = "linux"), feature = "clipboard"))]
-            {
-                use clipboard_rs::{Clipboard, ClipboardContext};
-                if let Some(text) = drv.editor.selected_text() {
-                    let cb = ClipboardContext::new().unwrap();
-                    cb.set_text(text.to_owned()).ok();
-                    drv.delete_selection();
-                }
-            }
-        }
+        state.update_style(self.style());
+        self.update_options(state);
+    }
 
-        let mut generate_text_changed_event = |editor: &mut PlainEditor| {
-            event.prevent_defaults();
-            event.prevent_propagate();
-            event.result_message(CraftMessage::TextInputChanged(editor.text().to_string()));
-        };
+    fn default_style(&self) -> Style {
+        default_text_input_style()
+    }
+}
 
-        if let CraftMessage::ElementMessage(msg) = message {
-            if let Some(msg) = msg.downcast_ref::<TextInputMessage>() {
-                let mut drv = state.driver(_text_context);
-                match msg {
-                    TextInputMessage::Copy => {
-                        copy(&mut drv);
-                    }
-                    TextInputMessage::Paste => {
-                        paste(&mut drv);
-                        state.cache.clear();
-                        generate_text_changed_event(&mut state.editor);
-                    }
-                    TextInputMessage::Cut => {
-                        cut(&mut drv);
-                        state.cache.clear();
-                        generate_text_changed_event(&mut state.editor);
-                    }
+/// Finds the [`TextInputState`] in the state of an element that is built on a text input.
+pub(crate) type TextInputStateFn = fn(&mut Box<dyn Any + Send>) -> &mut TextInputState;
+
+/// The [`TextInputStateFn`] of the elements whose state is a [`TextInputState`].
+pub(crate) fn text_input_state(data: &mut Box<dyn Any + Send>) -> &mut TextInputState {
+    data.downcast_mut().unwrap()
+}
+
+/// Draws the text, selection and cursor of an element whose state is a [`TextInputState`].
+pub(crate) fn draw_text_input(
+    element: &mut dyn Element,
+    renderer: &mut RenderList,
+    element_state: &mut ElementStateStore,
+    scale_factor: f64,
+) {
+    if !element.element_data().style.visible() {
+        return;
+    }
+
+    element.draw_borders(renderer, element_state, scale_factor);
+    draw_text_input_content(element, renderer, element_state, text_input_state, scale_factor);
+    element.draw_scrollbar(renderer, scale_factor);
+}
+
+/// Draws the text, selection and cursor of a text input, clipped to its padding box.
+pub(crate) fn draw_text_input_content(
+    element: &mut dyn Element,
+    renderer: &mut RenderList,
+    element_state: &mut ElementStateStore,
+    state_fn: TextInputStateFn,
+    scale_factor: f64,
+) {
+    let computed_box_transformed = element.computed_box_transformed();
+    let content_rectangle = computed_box_transformed.content_rectangle();
+
+    let is_scrollable = element.element_data().is_scrollable();
+
+    let element_data = element.element_data();
+    let padding_rectangle = element_data.layout_item.computed_box_transformed.padding_rectangle();
+    renderer.push_layer(padding_rectangle.scale(scale_factor));
+
+    let component_id = element_data.component_id;
+    let state = state_fn(&mut element_state.storage.get_mut(&component_id).unwrap().data);
+
+    let text_scroll = if is_scrollable {
+        Some(TextScroll::new(state.scroll_state.scroll_y, element_data.layout_item.computed_scroll_track.height))
+    } else {
+        None
+    };
+
+    if let Some(placeholder_render) = state.placeholder_render.as_ref() {
+        let mut placeholder_render = placeholder_render.clone();
+        placeholder_render.override_brush = Some(ColorBrush::new(state.placeholder_style.color()));
+        renderer.draw_text(placeholder_render, content_rectangle.scale(scale_factor), text_scroll, false);
+    }
+    if let Some(text_render) = state.text_render.as_ref() {
+        renderer.draw_text(
+            text_render.clone(),
+            content_rectangle.scale(scale_factor),
+            text_scroll,
+            state.cursor_visible,
+        );
+    }
+
+    renderer.pop_layer();
+}
+
+/// Creates the layout node of a text input, which is measured by its [`TextInputState`].
+///
+/// The caller is expected to merge the default style first.
+pub(crate) fn compute_text_input_layout(
+    element: &mut dyn Element,
+    taffy_tree: &mut TaffyTree<LayoutContext>,
+) -> Option<NodeId> {
+    let style: taffy::Style = element.element_data().style.to_taffy_style();
+    let component_id = element.element_data().component_id;
+
+    element.element_data_mut().layout_item.build_tree_with_context(
+        taffy_tree,
+        style,
+        LayoutContext::TextInput(TaffyTextInputContext::new(component_id)),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn finalize_text_input_layout(
+    element: &mut dyn Element,
+    taffy_tree: &mut TaffyTree<LayoutContext>,
+    root_node: NodeId,
+    position: Point,
+    z_index: &mut u32,
+    transform: Affine,
+    element_state: &mut ElementStateStore,
+    state_fn: TextInputStateFn,
+    text_context: &mut TextContext,
+    clip_bounds: Option<Rectangle>,
+) {
+    let result = taffy_tree.layout(root_node).unwrap();
+    element.resolve_box(position, transform, result, z_index);
+    element.resolve_clip(clip_bounds);
+
+    element.finalize_borders(element_state);
+
+    let component_id = element.element_data().component_id;
+    let state = state_fn(&mut element_state.storage.get_mut(&component_id).unwrap().data);
+
+    if state.current_key != state.last_requested_key {
+        state.layout(
+            state.last_requested_key.unwrap().known_dimensions(),
+            state.last_requested_key.unwrap().available_space(),
+            text_context,
+        );
+    }
+
+    let _layout = state.editor.try_layout().as_ref().unwrap();
+    let text_renderer = state.text_render.as_mut().unwrap();
+    for line in text_renderer.lines.iter_mut() {
+        line.selections.clear();
+    }
+    state.editor.selection_geometry_with(|rect, line| {
+        text_renderer.lines[line].selections.push(rect.into());
+    });
+    text_renderer.cursor = state.editor.cursor_geometry(1.0).map(|r| r.into());
+
+    element.element_data_mut().layout_item.scrollbar_size =
+        Size::new(result.scrollbar_size.width, result.scrollbar_size.height);
+    element.element_data_mut().layout_item.computed_scrollbar_size =
+        Size::new(result.scroll_width(), result.scroll_height());
+
+    let state = state_fn(&mut element_state.storage.get_mut(&component_id).unwrap().data);
+    element.finalize_scrollbar(&mut state.scroll_state);
+}
+
+/// Copies the selection to `clipboard`.
+fn copy(drv: &mut PlainEditorDriver, clipboard: &Clipboard) {
+    if let Some(text) = drv.editor.selected_text() {
+        clipboard.set_text(text);
+    }
+}
+
+/// Inserts the text of `clipboard` in place of the selection.
+fn paste(state: &mut TextInputState, text_context: &mut TextContext, clipboard: &Clipboard) {
+    let text = clipboard.text().unwrap_or_default();
+    state.insert(&text, text_context);
+}
+
+/// Moves the selection to `clipboard`.
+fn cut(drv: &mut PlainEditorDriver, clipboard: &Clipboard) {
+    if let Some(text) = drv.editor.selected_text() {
+        clipboard.set_text(text);
+        drv.delete_selection();
+    }
+}
+
+/// Handles editing, selection, clipboard and IME input for an element whose state is a [`TextInputState`].
+pub(crate) fn on_text_input_event(
+    element: &dyn Element,
+    message: &CraftMessage,
+    element_state: &mut ElementStateStore,
+    state_fn: TextInputStateFn,
+    _text_context: &mut TextContext,
+    should_style: bool,
+    event: &mut Event,
+) {
+    element.on_style_event(message, element_state, should_style, event);
+    element.maybe_unset_focus(message, event);
+
+    let base_state = element.get_base_state_mut(element_state);
+    let state = state_fn(&mut base_state.data);
+    state.is_active = true;
+
+    state.scroll_state.on_event(message, element.element_data(), &mut base_state.base, event);
+
+    if !event.propagate {
+        return;
+    }
+
+    let scroll_y = state.scroll_state.scroll_y;
+
+    let scale_factor = state.editor.try_layout().unwrap().scale() as f64;
+    let text_position = element.computed_box_transformed().content_rectangle();
+    let text_x = text_position.x;
+    let text_y = text_position.y;
+    let component_id = element.element_data().component_id;
+    let focused = element_state
+        .storage
+        .get(&component_id)
+        .unwrap().base.focused;
+    let state = state_fn(&mut element_state.storage.get_mut(&component_id).unwrap().data);
+
+    let clipboard = event.window.clipboard().clone();
+
+    let read_only = state.read_only;
+    let masked = state.editor.mask().is_some();
+
+    let mut generate_text_changed_event = |editor: &mut PlainEditor| {
+        event.prevent_defaults();
+        event.prevent_propagate();
+        event.result_message(CraftMessage::TextInputChanged(editor.text().to_string()));
+    };
+
+    if let CraftMessage::ElementMessage(msg) = message {
+        if let Some(msg) = msg.downcast_ref::<TextInputMessage>() {
+            let mut drv = state.driver(_text_context);
+            match msg {
+                TextInputMessage::Copy if !masked => {
+                    copy(&mut drv, &clipboard);
+                }
+                TextInputMessage::Paste if !read_only => {
+                    paste(state, _text_context, &clipboard);
+                    state.cache.clear();
+                    generate_text_changed_event(&mut state.editor);
+                }
+                TextInputMessage::Cut if !read_only && !masked => {
+                    cut(&mut drv, &clipboard);
+                    state.apply_input_mask(_text_context);
+                    state.cache.clear();
+                    generate_text_changed_event(&mut state.editor);
                 }
+                TextInputMessage::Undo if !read_only => {
+                    drv.undo();
+                    state.cache.clear();
+                    generate_text_changed_event(&mut state.editor);
+                }
+                TextInputMessage::Redo if !read_only => {
+                    drv.redo();
+                    state.cache.clear();
+                    generate_text_changed_event(&mut state.editor);
+                }
+                _ => (),
             }
         }
+    }
 
-        match message {
-            CraftMessage::KeyboardInputEvent(keyboard_input) if !state.editor.is_composing() => {
-                state.modifiers = Some(keyboard_input.modifiers);
-                if !keyboard_input.state.is_down() {
-                    return;
-                }
+    match message {
+        CraftMessage::KeyboardInputEvent(keyboard_input) if !state.editor.is_composing() => {
+            state.modifiers = Some(keyboard_input.modifiers);
+            if !keyboard_input.state.is_down() {
+                return;
+            }
 
-                if !focused {
-                    return;
-                }
+            if !focused {
+                return;
+            }
 
-                state.cursor_reset();
-                #[allow(unused)]
-                let (shift, action_mod) = state
-                    .modifiers
-                    .map(|mods| (mods.shift(), if cfg!(target_os = "macos") { mods.meta() } else { mods.ctrl() }))
-                    .unwrap_or_default();
-
-                let mut drv = state.driver(_text_context);
-
-                match &keyboard_input.key {
-                    Key::Character(c) if action_mod && matches!(c.as_str(), "c" | "x" | "v") => {
-                        match c.to_lowercase().as_str() {
-                            "c" => copy(&mut drv),
-                            "x" => {
-                                cut(&mut drv);
-                                state.cache.clear();
-                                generate_text_changed_event(&mut state.editor);
-                            }
-                            "v" => {
-                                paste(&mut drv);
-                                state.cache.clear();
-                                generate_text_changed_event(&mut state.editor);
-                            }
-                            _ => (),
+            state.cursor_reset();
+            #[allow(unused)]
+            let (shift, action_mod) = state
+                .modifiers
+                .map(|mods| (mods.shift(), if cfg!(target_os = "macos") { mods.meta() } else { mods.ctrl() }))
+                .unwrap_or_default();
+
+            let mut drv = state.driver(_text_context);
+
+            match &keyboard_input.key {
+                Key::Character(c) if action_mod && matches!(c.as_str(), "c" | "x" | "v") => {
+                    match c.to_lowercase().as_str() {
+                        "c" if !masked => copy(&mut drv, &clipboard),
+                        "x" if !read_only && !masked => {
+                            cut(&mut drv, &clipboard);
+                            state.apply_input_mask(_text_context);
+                            state.cache.clear();
+                            generate_text_changed_event(&mut state.editor);
                         }
-                    }
-                    Key::Character(c) if action_mod && matches!(c.to_lowercase().as_str(), "a") => {
-                        if shift {
-                            drv.collapse_selection();
-                        } else {
-                            drv.select_all();
+                        "v" if !read_only => {
+                            paste(state, _text_context, &clipboard);
+                            state.cache.clear();
+                            generate_text_changed_event(&mut state.editor);
                         }
+                        _ => (),
                     }
-                    Key::Named(NamedKey::ArrowLeft) => {
-                        if action_mod {
-                            if shift {
-                                drv.select_word_left();
-                            } else {
-                                drv.move_word_left();
-                            }
-                        } else if shift {
-                            drv.select_left();
+                }
+                Key::Character(c) if action_mod && matches!(c.to_lowercase().as_str(), "z" | "y") => {
+                    if !read_only {
+                        if shift || c.to_lowercase() == "y" {
+                            drv.redo();
                         } else {
-                            drv.move_left();
+                            drv.undo();
                         }
+                        state.cache.clear();
+                        generate_text_changed_event(&mut state.editor);
                     }
-                    Key::Named(NamedKey::ArrowRight) => {
-                        if action_mod {
-                            if shift {
-                                drv.select_word_right();
-                            } else {
-                                drv.move_word_right();
-                            }
-                        } else if shift {
-                            drv.select_right();
-                        } else {
-                            drv.move_right();
-                        }
+                }
+                Key::Character(c) if action_mod && matches!(c.to_lowercase().as_str(), "a") => {
+                    if shift {
+                        drv.collapse_selection();
+                    } else {
+                        drv.select_all();
                     }
-                    Key::Named(NamedKey::ArrowUp) => {
+                }
+                Key::Named(NamedKey::ArrowLeft) => {
+                    if action_mod {
                         if shift {
-                            drv.select_up();
+                            drv.select_word_left();
                         } else {
-                            drv.move_up();
+                            drv.move_word_left();
                         }
+                    } else if shift {
+                        drv.select_left();
+                    } else {
+                        drv.move_left();
                     }
-                    Key::Named(NamedKey::ArrowDown) => {
+                }
+                Key::Named(NamedKey::ArrowRight) => {
+                    if action_mod {
                         if shift {
-                            drv.select_down();
+                            drv.select_word_right();
                         } else {
-                            drv.move_down();
+                            drv.move_word_right();
                         }
+                    } else if shift {
+                        drv.select_right();
+                    } else {
+                        drv.move_right();
                     }
-                    Key::Named(NamedKey::Home) => {
-                        if action_mod {
-                            if shift {
-                                drv.select_to_text_start();
-                            } else {
-                                drv.move_to_text_start();
-                            }
-                        } else if shift {
-                            drv.select_to_line_start();
-                        } else {
-                            drv.move_to_line_start();
-                        }
+                }
+                Key::Named(NamedKey::ArrowUp) => {
+                    if shift {
+                        drv.select_up();
+                    } else {
+                        drv.move_up();
                     }
-                    Key::Named(NamedKey::End) => {
-                        let mut drv = state.driver(_text_context);
-
-                        if action_mod {
-                            if shift {
-                                drv.select_to_text_end();
-                            } else {
-                                drv.move_to_text_end();
-                            }
-                        } else if shift {
-                            drv.select_to_line_end();
-                        } else {
-                            drv.move_to_line_end();
-                        }
+                }
+                Key::Named(NamedKey::ArrowDown) => {
+                    if shift {
+                        drv.select_down();
+                    } else {
+                        drv.move_down();
                     }
-                    Key::Named(NamedKey::Delete) => {
-                        if action_mod {
-                            drv.delete_word();
-                            state.cache.clear();
+                }
+                Key::Named(NamedKey::Home) => {
+                    if action_mod {
+                        if shift {
+                            drv.select_to_text_start();
                         } else {
-                            drv.delete();
-                            state.cache.clear();
+                            drv.move_to_text_start();
                         }
-                        generate_text_changed_event(&mut state.editor);
+                    } else if shift {
+                        drv.select_to_line_start();
+                    } else {
+                        drv.move_to_line_start();
                     }
-                    Key::Named(NamedKey::Backspace) => {
-                        if action_mod {
-                            drv.backdelete_word();
-                            state.cache.clear();
+                }
+                Key::Named(NamedKey::End) => {
+                    let mut drv = state.driver(_text_context);
+
+                    if action_mod {
+                        if shift {
+                            drv.select_to_text_end();
                         } else {
-                            drv.backdelete();
-                            state.cache.clear();
+                            drv.move_to_text_end();
                         }
-                        generate_text_changed_event(&mut state.editor);
+                    } else if shift {
+                        drv.select_to_line_end();
+                    } else {
+                        drv.move_to_line_end();
                     }
-                    Key::Named(NamedKey::Enter) => {
-                        drv.insert_or_replace_selection("\n");
+                }
+                Key::Named(NamedKey::Delete) if !read_only => {
+                    if action_mod {
+                        drv.delete_word();
+                        state.cache.clear();
+                    } else {
+                        drv.delete();
                         state.cache.clear();
-                        generate_text_changed_event(&mut state.editor);
                     }
-                    Key::Character(s) => {
-                        drv.insert_or_replace_selection(s);
+                    state.apply_input_mask(_text_context);
+                    generate_text_changed_event(&mut state.editor);
+                }
+                Key::Named(NamedKey::Backspace) if !read_only => {
+                    if action_mod {
+                        drv.backdelete_word();
+                        state.cache.clear();
+                    } else {
+                        drv.backdelete();
                         state.cache.clear();
-                        generate_text_changed_event(&mut state.editor);
                     }
-                    _ => (),
+                    state.apply_input_mask(_text_context);
+                    generate_text_changed_event(&mut state.editor);
+                }
+                // Enter is left to the ancestors when the filter rejects new lines, for example to pick a date.
+                Key::Named(NamedKey::Enter)
+                    if !read_only && state.filter.as_ref().is_none_or(|filter| filter('\n')) =>
+                {
+                    state.insert("\n", _text_context);
+                    state.cache.clear();
+                    generate_text_changed_event(&mut state.editor);
+                }
+                Key::Character(s) if !read_only => {
+                    state.insert(s, _text_context);
+                    state.cache.clear();
+                    generate_text_changed_event(&mut state.editor);
                 }
+                _ => (),
             }
-            // WindowEvent::Touch(Touch {
-            //     phase, location, ..
-            // }) if !self.editor.is_composing() => {
-            //     let mut drv = self.editor.driver(&mut self.font_cx, &mut self.layout_cx);
-            //     use winit::event::TouchPhase::*;
-            //     match phase {
-            //         Started => {
-            //             // TODO: start a timer to convert to a SelectWordAtPoint
-            //             drv.move_to_point(location.x as f32, location.y as f32);
-            //         }
-            //         Cancelled => {
-            //             drv.collapse_selection();
-            //         }
-            //         Moved => {
-            //             // TODO: cancel SelectWordAtPoint timer
-            //             drv.extend_selection_to_point(
-            //                 location.x as f32,
-            //                 location.y as f32,
-            //             );
-            //         }
-            //         Ended => (),
-            //     }
-            // }
-            CraftMessage::PointerButtonDown(pointer_button) => {
-                if pointer_button.is_primary() {
-                    event.focus_action(FocusAction::Set(self.component_id()));
-                    state.pointer_down = true;
-                    state.cursor_reset();
-                    if !state.editor.is_composing() {
-                        let now = Instant::now();
-                        if let Some(last) = state.last_click_time.take() {
-                            if now.duration_since(last).as_secs_f64() < 0.25 {
-                                state.click_count = (state.click_count + 1) % 4;
-                            } else {
-                                state.click_count = 1;
-                            }
+        }
+        // WindowEvent::Touch(Touch {
+        //     phase, location, ..
+        // }) if !self.editor.is_composing() => {
+        //     let mut drv = self.editor.driver(&mut self.font_cx, &mut self.layout_cx);
+        //     use winit::event::TouchPhase::*;
+        //     match phase {
+        //         Started => {
+        //             // TODO: start a timer to convert to a SelectWordAtPoint
+        //             drv.move_to_point(location.x as f32, location.y as f32);
+        //         }
+        //         Cancelled => {
+        //             drv.collapse_selection();
+        //         }
+        //         Moved => {
+        //             // TODO: cancel SelectWordAtPoint timer
+        //             drv.extend_selection_to_point(
+        //                 location.x as f32,
+        //                 location.y as f32,
+        //             );
+        //         }
+        //         Ended => (),
+        //     }
+        // }
+        CraftMessage::PointerButtonDown(pointer_button) => {
+            if pointer_button.is_primary() {
+                event.focus_action(FocusAction::Set(component_id));
+                state.pointer_down = true;
+                state.cursor_reset();
+                if !state.editor.is_composing() {
+                    let now = Instant::now();
+                    if let Some(last) = state.last_click_time.take() {
+                        if now.duration_since(last).as_secs_f64() < 0.25 {
+                            state.click_count = (state.click_count + 1) % 4;
                         } else {
                             state.click_count = 1;
                         }
-                        state.last_click_time = Some(now);
-                        let click_count = state.click_count;
-                        let cursor_pos = state.cursor_pos;
-                        let mut drv = state.driver(_text_context);
-                        let cursor_x = cursor_pos.x as f32;
-                        let cursor_y = cursor_pos.y as f32;
-                        match click_count {
-                            2 => drv.select_word_at_point(cursor_x, cursor_y),
-                            3 => drv.select_line_at_point(cursor_x, cursor_y),
-                            _ => drv.move_to_point(cursor_x, cursor_y),
-                        }
+                    } else {
+                        state.click_count = 1;
                     }
-                }
-            }
-            CraftMessage::PointerButtonUp(pointer_button) => {
-                if pointer_button.is_primary() {
-                    state.pointer_down = false;
-                    state.cursor_reset();
-                }
-            }
-            CraftMessage::PointerMovedEvent(pointer_moved) => {
-                let prev_pos = state.cursor_pos;
-                // NOTE: Cursor position should be relative to the top left of the text box.
-                let cursor_pos = pointer_moved.current.position;
-                let cursor_pos: Point = (
-                    cursor_pos.x as f32 - text_x,
-                    cursor_pos.y as f32 - text_y,
-                ).into();
-                let mut cursor_pos = Point::new(cursor_pos.x * scale_factor, cursor_pos.y * scale_factor);
-                cursor_pos.y += scroll_y as f64;
-                state.cursor_pos = cursor_pos;
-                // macOS seems to generate a spurious move after selecting word?
-                if state.pointer_down && prev_pos != state.cursor_pos && !state.editor.is_composing() {
-                    state.cursor_reset();
+                    state.last_click_time = Some(now);
+                    let click_count = state.click_count;
                     let cursor_pos = state.cursor_pos;
-                    state.driver(_text_context).extend_selection_to_point(cursor_pos.x as f32, cursor_pos.y as f32);
+                    let mut drv = state.driver(_text_context);
+                    let cursor_x = cursor_pos.x as f32;
+                    let cursor_y = cursor_pos.y as f32;
+                    match click_count {
+                        2 => drv.select_word_at_point(cursor_x, cursor_y),
+                        3 => drv.select_line_at_point(cursor_x, cursor_y),
+                        _ => drv.move_to_point(cursor_x, cursor_y),
+                    }
                 }
             }
-            CraftMessage::ImeEvent(Ime::Disabled) => {
-                state.driver(_text_context).clear_compose();
-                state.cache.clear();
+        }
+        CraftMessage::PointerButtonUp(pointer_button) => {
+            if pointer_button.is_primary() {
+                state.pointer_down = false;
+                state.cursor_reset();
             }
-            CraftMessage::ImeEvent(Ime::Commit(text)) => {
-                state.driver(_text_context).insert_or_replace_selection(text);
-                state.cache.clear();
-                generate_text_changed_event(&mut state.editor);
+        }
+        CraftMessage::PointerMovedEvent(pointer_moved) => {
+            let prev_pos = state.cursor_pos;
+            // NOTE: Cursor position should be relative to the top left of the text box.
+            let cursor_pos = pointer_moved.current.position;
+            let cursor_pos: Point = (
+                cursor_pos.x as f32 - text_x,
+                cursor_pos.y as f32 - text_y,
+            ).into();
+            let mut cursor_pos = Point::new(cursor_pos.x * scale_factor, cursor_pos.y * scale_factor);
+            cursor_pos.y += scroll_y as f64;
+            state.cursor_pos = cursor_pos;
+            // macOS seems to generate a spurious move after selecting word?
+            if state.pointer_down && prev_pos != state.cursor_pos && !state.editor.is_composing() {
+                state.cursor_reset();
+                let cursor_pos = state.cursor_pos;
+                state.driver(_text_context).extend_selection_to_point(cursor_pos.x as f32, cursor_pos.y as f32);
             }
-            CraftMessage::ImeEvent(Ime::Preedit(text, cursor)) => {
-                if text.is_empty() {
-                    state.driver(_text_context).clear_compose();
-                } else {
-                    state.driver(_text_context).set_compose(text, *cursor);
-                }
-                state.cache.clear();
+        }
+        CraftMessage::ImeEvent(Ime::Disabled) => {
+            state.driver(_text_context).clear_compose();
+            state.cache.clear();
+        }
+        CraftMessage::ImeEvent(Ime::Commit(text)) if !read_only => {
+            state.insert(text, _text_context);
+            state.cache.clear();
+            generate_text_changed_event(&mut state.editor);
+        }
+        CraftMessage::ImeEvent(Ime::Preedit(text, cursor)) if !read_only => {
+            if text.is_empty() {
+                state.driver(_text_context).clear_compose();
+            } else {
+                state.driver(_text_context).set_compose(text, *cursor);
             }
-            _ => {}
+            state.cache.clear();
         }
-        let ime = state.editor.ime_cursor_area();
-        event.ime_action(ImeAction::Set(Rectangle::new(
-            ime.x0 as f32,
-            ime.y0 as f32,
-            ime.width() as f32,
-            ime.height() as f32,
-        )));
+        _ => {}
     }
+    let ime = state.editor.ime_cursor_area();
+    event.ime_action(ImeAction::Set(Rectangle::new(
+        ime.x0 as f32,
+        ime.y0 as f32,
+        ime.width() as f32,
+        ime.height() as f32,
+    )));
+}
 
-    fn resolve_clip(&mut self, clip_bounds: Option<Rectangle>) {
-        resolve_clip_for_scrollable(self, clip_bounds);
+/// Adds the node of a text input and the nodes of its text runs to the accessibility tree.
+#[cfg(feature = "accesskit")]
+pub(crate) fn compute_text_input_accessibility_tree(
+    element: &dyn Element,
+    mut current_node: accesskit::Node,
+    tree: &mut accesskit::TreeUpdate,
+    parent_index: Option<usize>,
+    element_state: &mut ElementStateStore,
+    state_fn: TextInputStateFn,
+    scale_factor: f64,
+) {
+    let component_id = element.element_data().component_id;
+    let state = state_fn(&mut element_state.storage.get_mut(&component_id).unwrap().data);
+
+    if state.editor.try_layout().is_none() {
+        return;
     }
 
-    fn initialize_state(&mut self, scaling_factor: f64) -> ElementStateStoreItem {
-        let mut editor = PlainEditor::new(self.style().font_size());
+    let editor = &mut state.editor;
+
+    let current_node_id = accesskit::NodeId(component_id);
+
+    let padding_box = element.element_data().layout_item.computed_box_transformed.padding_rectangle().scale(scale_factor);
+
+    current_node.set_bounds(accesskit::Rect {
+        x0: padding_box.left() as f64,
+        y0: padding_box.top() as f64,
+        x1: padding_box.right() as f64,
+        y1: padding_box.bottom() as f64,
+    });
+
+    editor.try_accessibility(
+        tree,
+        &mut current_node,
+        || accesskit::NodeId(create_unique_element_id()),
+        padding_box.x as f64,
+        padding_box.y as f64,
+    );
+
+    if let Some(parent_index) = parent_index {
+        let parent_node = tree.nodes.get_mut(parent_index).unwrap();
+        parent_node.1.push_child(current_node_id);
+    }
+
+    tree.nodes.push((current_node_id, current_node));
+}
+
+pub(crate) fn default_text_input_style() -> Style {
+    let mut style = Style::default();
+    *style.display_mut() = Display::Block;
+    const BORDER_COLOR: Color = Color::from_rgb8(199, 199, 206);
+    *style.border_color_mut() = TrblRectangle::new_all(BORDER_COLOR);
+    *style.border_width_mut() = TrblRectangle::new_all(Unit::Px(1.0));
+    *style.border_radius_mut() = [(5.0, 5.0); 4];
+    let padding = Unit::Px(4.0);
+    *style.padding_mut() = TrblRectangle::new_all(padding);
+
+    style
+}
+
+fn default_placeholder_style() -> Style {
+    let mut style = Style::default();
+    const PLACEHOLDER_COLOR: Color = Color::from_rgb8(142, 142, 147);
+    *style.color_mut() = PLACEHOLDER_COLOR;
+
+    style
+}
+
+impl TextInput {
+    generate_component_methods_no_children!();
+
+    /// Whether the text input will update the editor every update with the user provided text.
+    /// NOTE: The editor will always use the user provided text on initialization.
+    pub fn use_text_value_on_update(mut self, use_initial_text_value: bool) -> Self {
+        self.use_text_value_on_update = use_initial_text_value;
+        self
+    }
+
+    /// Displays every character as `mask_char`, while the real text is kept in the editor.
+    /// Copying and cutting are disabled.
+    pub fn password(mut self, mask_char: char) -> Self {
+        self.password = Some(mask_char);
+        self
+    }
+
+    /// Set the text that is shown while the text input is empty.
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Set the style of the placeholder. This style will get merged with the style of the text input.
+    pub fn placeholder_style(mut self, placeholder_style: Style) -> Self {
+        self.placeholder_style = placeholder_style;
+        self
+    }
+
+    /// Set the maximum number of characters, which is enforced when typing, pasting and committing IME text.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Allows the text to be selected and copied, but not edited.
+    pub fn read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
+    /// Only accepts the typed, pasted and IME committed characters for which `filter` returns true.
+    pub fn filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(char) -> bool + Send + Sync + 'static,
+    {
+        self.filter = Some(Arc::new(filter));
+        self
+    }
+
+    /// Formats the text with a pattern like `(999) 999-9999`, inserting the literal characters automatically.
+    ///
+    /// `9` accepts a digit, `a` a letter and `*` a letter or digit. Any other character is a literal,
+    /// and `\` makes the next character a literal.
+    pub fn input_mask(mut self, pattern: &str) -> Self {
+        self.input_mask = Some(InputMask::new(pattern));
+        self
+    }
+}
+
+impl ElementStyles for TextInput {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
+
+impl TextInputState {
+    pub(crate) fn new(style: &Style, text: Option<String>, ranged_styles: RangedStyles, scaling_factor: f64) -> Self {
+        let mut editor = PlainEditor::new(style.font_size());
         editor.set_scale(scaling_factor as f32);
         let style_set = editor.edit_styles();
-        self.style().add_styles_to_style_set(style_set);
-        editor.set_ranged_styles(self.ranged_styles.clone().unwrap());
-        
-        let text_input_state = TextInputState {
+        style.add_styles_to_style_set(style_set);
+        editor.set_alignment(style.text_align().to_parley_alignment(style.direction()));
+        editor.set_direction(style.direction());
+        editor.set_wrap(style.white_space().wraps());
+        editor.set_ranged_styles(ranged_styles);
+
+        TextInputState {
             ime_state: ImeState::default(),
             is_active: false,
             scroll_state: ScrollState::default(),
             editor,
+            max_length: None,
+            read_only: false,
+            filter: None,
+            input_mask: None,
+            placeholder: None,
+            placeholder_style: Style::default(),
+            placeholder_render: None,
             cache: Default::default(),
             current_key: None,
             last_requested_key: None,
             text_render: None,
-            new_text: std::mem::take(&mut self.text),
-            new_style: TextStyle::from(self.style()),
+            new_text: text,
+            new_style: TextStyle::from(style),
             last_click_time: None,
             click_count: 0,
             pointer_down: false,
@@ -638,132 +981,131 @@ impl Element for TextInput {
             modifiers: None,
             start_time: None,
             blink_period: Default::default(),
-        };
-
-        ElementStateStoreItem {
-            base: Default::default(),
-            data: Box::new(text_input_state),
         }
     }
 
-    #[cfg(feature = "accesskit")]
-    fn compute_accessibility_tree(
-        &mut self,
-        tree: &mut accesskit::TreeUpdate,
-        parent_index: Option<usize>,
-        element_state: &mut ElementStateStore,
-        scale_factor: f64,
-    ) {
-        let state: &mut TextInputState = element_state
-            .storage
-            .get_mut(&self.element_data.component_id)
-            .unwrap()
-            .data
-            .as_mut()
-            .downcast_mut()
-            .unwrap();
-
-        if state.editor.try_layout().is_none() {
-            return;
-        }
+    /// Replaces the text of the editor, the layout is updated the next time the text input is measured.
+    pub(crate) fn set_text(&mut self, text: &str) {
+        self.new_text = Some(text.to_string());
+        self.cache.clear();
+    }
 
-        let editor = &mut state.editor;
+    /// The current text, including changes that have not been laid out yet.
+    pub(crate) fn text(&self) -> String {
+        self.new_text.clone().unwrap_or_else(|| self.editor.text().to_string())
+    }
 
-        let current_node_id = accesskit::NodeId(self.element_data().component_id);
+    pub(crate) fn set_mask(&mut self, mask: Option<char>) {
+        if self.editor.mask() != mask {
+            self.editor.set_mask(mask);
+            self.cache.clear();
+        }
+    }
 
-        let mut current_node = accesskit::Node::new(accesskit::Role::TextInput);
-        let padding_box = self.element_data().layout_item.computed_box_transformed.padding_rectangle().scale(scale_factor);
+    pub(crate) fn set_placeholder(&mut self, placeholder: Option<&str>, style: &Style) {
+        if self.placeholder.as_deref() != placeholder || !same_font(&self.placeholder_style, style) {
+            self.placeholder = placeholder.map(str::to_string);
+            self.cache.clear();
+        }
+        self.placeholder_style = *style;
+    }
 
-        current_node.set_bounds(accesskit::Rect {
-            x0: padding_box.left() as f64,
-            y0: padding_box.top() as f64,
-            x1: padding_box.right() as f64,
-            y1: padding_box.bottom() as f64,
-        });
+    /// The text that the editor is laid out with next, including the IME preedit text.
+    pub(crate) fn layout_text(&self) -> &str {
+        self.new_text.as_deref().unwrap_or_else(|| self.editor.raw_text())
+    }
 
-        editor.try_accessibility(
-            tree,
-            &mut current_node,
-            || accesskit::NodeId(create_unique_element_id()),
-            padding_box.x as f64,
-            padding_box.y as f64,
-        );
+    /// Whether the text was replaced since the editor was last laid out.
+    pub(crate) fn has_new_text(&self) -> bool {
+        self.new_text.is_some()
+    }
 
-        if let Some(parent_index) = parent_index {
-            let parent_node = tree.nodes.get_mut(parent_index).unwrap();
-            parent_node.1.push_child(current_node_id);
+    pub(crate) fn set_ranged_styles(&mut self, ranged_styles: RangedStyles) {
+        if ranged_styles != self.editor.ranged_styles {
+            self.editor.set_ranged_styles(ranged_styles);
+            self.cache.clear();
         }
-
-        tree.nodes.push((current_node_id, current_node));
     }
 
-    fn update_state(&mut self, element_state: &mut ElementStateStore, _reload_fonts: bool, scaling_factor: f64) {
-        let state: &mut TextInputState = element_state
-            .storage
-            .get_mut(&self.element_data.component_id)
-            .unwrap()
-            .data
-            .as_mut()
-            .downcast_mut()
-            .unwrap();
+    /// Runs an edit on the editor and measures the text again on the next layout.
+    pub(crate) fn edit(&mut self, text_context: &mut TextContext, edit: impl FnOnce(&mut PlainEditorDriver)) {
+        edit(&mut self.driver(text_context));
+        self.cache.clear();
+    }
 
-        if let Some(layout) = state.editor.try_layout() {
-            if layout.scale() != scaling_factor as f32 {
-                state.editor.set_scale(scaling_factor as f32);
-                state.cache.clear();
-                state.new_text = Some(state.editor.text().to_string());
-            }
-        }
-        
-        if self.ranged_styles.as_ref() != Some(&state.editor.ranged_styles) {
-            let ranged_styles = std::mem::take(&mut self.ranged_styles);
-            if let Some(ranged_styles) = ranged_styles {
-                state.editor.set_ranged_styles(ranged_styles);
-                state.cache.clear();
+    /// Inserts `text` in place of the selection, without the characters that the filter rejects
+    /// and shortened to the maximum length.
+    fn insert(&mut self, text: &str, text_context: &mut TextContext) {
+        let mut text: String = match &self.filter {
+            Some(filter) => text.chars().filter(|c| filter(*c)).collect(),
+            None => text.to_string(),
+        };
+        if let Some(max_length) = self.max_length {
+            let selected_length = self.editor.selected_text().map_or(0, |selected| selected.chars().count());
+            let length = self.editor.text().chars().count() - selected_length;
+            if let Some((end, _)) = text.char_indices().nth(max_length.saturating_sub(length)) {
+                text.truncate(end);
             }
         }
-        
-        if TextStyle::from(self.style()) != state.new_style {
-            state.new_style = TextStyle::from(self.style());
-            state.cache.clear();
-            state.new_text = Some(state.editor.text().to_string());
-            let styles = state.editor.edit_styles();
-            styles.insert(StyleProperty::FontSize(state.new_style.font_size));
+        if text.is_empty() && self.editor.selection_byte_range().is_empty() {
+            return;
         }
+        self.driver(text_context).insert_or_replace_selection(&text);
+        self.apply_input_mask(text_context);
     }
 
-    fn default_style(&self) -> Style {
-        let mut style = Style::default();
-        *style.display_mut() = Display::Block;
-        const BORDER_COLOR: Color = Color::from_rgb8(199, 199, 206);
-        *style.border_color_mut() = TrblRectangle::new_all(BORDER_COLOR);
-        *style.border_width_mut() = TrblRectangle::new_all(Unit::Px(1.0));
-        *style.border_radius_mut() = [(5.0, 5.0); 4];
-        let padding = Unit::Px(4.0);
-        *style.padding_mut() = TrblRectangle::new_all(padding);
-
-        style
+    /// Reformats the text with the input mask after an edit, keeping the caret after the same entered characters.
+    fn apply_input_mask(&mut self, text_context: &mut TextContext) {
+        let Some(input_mask) = &self.input_mask else {
+            return;
+        };
+        let text = self.editor.raw_text();
+        let formatted = input_mask.apply(text);
+        if formatted == text {
+            return;
+        }
+        let caret = input_mask.format(&input_mask.unmask(&text[..self.editor.focus_byte_index()])).len();
+        self.driver(text_context).amend_last_edit(&formatted, caret);
     }
-}
 
-impl TextInput {
-    generate_component_methods_no_children!();
+    pub(crate) fn update_scale(&mut self, scaling_factor: f64) {
+        if let Some(layout) = self.editor.try_layout() {
+            if layout.scale() != scaling_factor as f32 {
+                self.editor.set_scale(scaling_factor as f32);
+                self.cache.clear();
+                self.new_text = Some(self.editor.text().to_string());
+            }
+        }
+    }
 
-    /// Whether the text input will update the editor every update with the user provided text.
-    /// NOTE: The editor will always use the user provided text on initialization.
-    pub fn use_text_value_on_update(mut self, use_initial_text_value: bool) -> Self {
-        self.use_text_value_on_update = use_initial_text_value;
-        self
+    /// Lays the text out again, so that it uses the fonts that were registered since.
+    pub(crate) fn reload_fonts(&mut self) {
+        self.cache.clear();
+        self.new_text = Some(self.editor.text().to_string());
     }
-}
 
-impl ElementStyles for TextInput {
-    fn styles_mut(&mut self) -> &mut Style {
-        self.element_data.current_style_mut()
+    pub(crate) fn update_style(&mut self, style: &Style) {
+        if TextStyle::from(style) != self.new_style {
+            self.new_style = TextStyle::from(style);
+            self.cache.clear();
+            self.new_text = Some(self.editor.text().to_string());
+            let styles = self.editor.edit_styles();
+            styles.insert(StyleProperty::FontSize(self.new_style.font_size));
+            styles.insert(StyleProperty::LineHeight(parley::LineHeight::FontSizeRelative(self.new_style.line_height)));
+            styles.insert(StyleProperty::LetterSpacing(self.new_style.letter_spacing));
+            styles.insert(StyleProperty::WordSpacing(self.new_style.word_spacing));
+            styles.insert(StyleProperty::FontFeatures(parley::FontSettings::List(Cow::Owned(
+                style.parley_font_features(),
+            ))));
+            styles.insert(StyleProperty::FontVariations(parley::FontSettings::List(Cow::Owned(
+                style.parley_font_variations(),
+            ))));
+            self.editor.set_alignment(self.new_style.text_align.to_parley_alignment(self.new_style.direction));
+            self.editor.set_direction(self.new_style.direction);
+            self.editor.set_wrap(self.new_style.white_space.wraps());
+        }
     }
-}
 
-impl TextInputState {
     pub fn measure(
         &mut self,
         known_dimensions: taffy::Size<Option<f32>>,
@@ -806,9 +1148,13 @@ impl TextInputState {
             self.editor.try_layout().unwrap().scale() as f64
         };
 
+        // Text that doesn't wrap can't be narrower than its longest line.
+        let wraps = self.new_style.white_space.wraps();
         let width_constraint = known_dimensions.width.or(match available_space.width {
-            AvailableSpace::MinContent => Some(self.editor.try_layout().unwrap().calculate_content_widths().min),
-            AvailableSpace::MaxContent => Some(self.editor.try_layout().unwrap().calculate_content_widths().max),
+            AvailableSpace::MinContent if wraps => Some(self.editor.try_layout().unwrap().calculate_content_widths().min),
+            AvailableSpace::MinContent | AvailableSpace::MaxContent => {
+                Some(self.editor.try_layout().unwrap().calculate_content_widths().max)
+            }
             AvailableSpace::Definite(width) => {
                 let scaled_width = dpi::PhysicalUnit::from_logical::<f32, f32>(width, scale_factor).0;
                 Some(scaled_width)
@@ -828,11 +1174,31 @@ impl TextInputState {
         self.editor.refresh_layout(&mut text_context.font_context, &mut text_context.layout_context);
 
         let layout = self.editor.try_layout().unwrap();
-        let width = layout.width();
-        let height = layout.height();
+        let mut width = layout.width();
+        let mut height = layout.height();
 
         self.text_render = Some(text_render_data::from_editor(layout));
 
+        self.placeholder_render = None;
+        if let Some(placeholder) = self.placeholder.as_ref().filter(|_| self.editor.raw_text().is_empty()) {
+            // The empty editor has no content width, so the placeholder only wraps at a definite width.
+            let placeholder_width = known_dimensions.width.or(match available_space.width {
+                AvailableSpace::Definite(width) => Some(dpi::PhysicalUnit::from_logical::<f32, f32>(width, scale_factor).0),
+                _ => None,
+            });
+            let mut builder = text_context.tree_builder(scale_factor as f32, &self.placeholder_style.to_text_style());
+            builder.push_text(self.new_style.direction.mark());
+            builder.push_text(placeholder);
+            let (mut layout, _) = builder.build();
+            layout.break_all_lines(placeholder_width);
+            let alignment = self.new_style.text_align.to_parley_alignment(self.new_style.direction);
+            layout.align(placeholder_width, alignment, AlignmentOptions::default());
+
+            width = width.max(layout.width());
+            height = height.max(layout.height());
+            self.placeholder_render = Some(text_render_data::from_editor(&layout));
+        }
+
         let sw = dpi::LogicalUnit::from_physical::<f32, f32>(width, scale_factor).0;
         let sh = dpi::LogicalUnit::from_physical::<f32, f32>(height, scale_factor).0;
 
@@ -882,3 +1248,115 @@ impl TextInputState {
         self.editor.driver(&mut text_context.font_context, &mut text_context.layout_context)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{copy, cut, paste, TextInputState};
+    use crate::clipboard::Clipboard;
+    use crate::style::Style;
+    use crate::text::input_mask::InputMask;
+    use crate::text::text_context::TextContext;
+    use crate::text::RangedStyles;
+    use std::sync::Arc;
+
+    /// A state with the text formatted by `pattern` and the caret at the byte index `caret`.
+    fn masked_state(pattern: &str, text: &str, caret: usize, text_context: &mut TextContext) -> TextInputState {
+        let mut state = TextInputState::new(&Style::default(), None, RangedStyles::default(), 1.0);
+        state.input_mask = Some(InputMask::new(pattern));
+        state.editor.set_text(text);
+        state.edit(text_context, |drv| drv.move_to_byte(caret));
+        state
+    }
+
+    fn backspace(state: &mut TextInputState, text_context: &mut TextContext) {
+        state.edit(text_context, |drv| drv.backdelete());
+        state.apply_input_mask(text_context);
+    }
+
+    #[test]
+    fn backspace_over_a_literal_moves_before_it() {
+        let mut text_context = TextContext::with_test_font();
+        let mut state = masked_state("(999) 999-9999", "(123) 456", 6, &mut text_context);
+
+        // The deleted space is inserted again by the mask.
+        backspace(&mut state, &mut text_context);
+        assert_eq!(state.editor.raw_text(), "(123) 456");
+        assert_eq!(state.editor.focus_byte_index(), 4);
+
+        // So the next backspace deletes the digit before the literals.
+        backspace(&mut state, &mut text_context);
+        assert_eq!(state.editor.raw_text(), "(124) 56");
+        assert_eq!(state.editor.focus_byte_index(), 3);
+    }
+
+    #[test]
+    fn paste_in_the_middle_moves_the_following_characters() {
+        let mut text_context = TextContext::with_test_font();
+        let mut state = masked_state("(999) 999-9999", "(123) 456", 2, &mut text_context);
+
+        state.insert("78", &mut text_context);
+        assert_eq!(state.editor.raw_text(), "(178) 234-56");
+        assert_eq!(state.editor.focus_byte_index(), 4);
+    }
+
+    #[test]
+    fn typing_the_next_literal_is_dropped() {
+        let mut text_context = TextContext::with_test_font();
+        let mut state = masked_state("99/99", "12", 2, &mut text_context);
+
+        state.insert("/", &mut text_context);
+        assert_eq!(state.editor.raw_text(), "12");
+        assert_eq!(state.editor.focus_byte_index(), 2);
+
+        state.insert("3", &mut text_context);
+        assert_eq!(state.editor.raw_text(), "12/3");
+        assert_eq!(state.editor.focus_byte_index(), 4);
+    }
+
+    #[test]
+    fn rejected_text_is_not_an_edit() {
+        let mut text_context = TextContext::with_test_font();
+        let mut state = TextInputState::new(&Style::default(), None, RangedStyles::default(), 1.0);
+        state.filter = Some(Arc::new(|character| character.is_ascii_digit()));
+        state.max_length = Some(3);
+        state.editor.set_text("12");
+        state.edit(&mut text_context, |drv| drv.move_to_byte(2));
+
+        state.insert("ab", &mut text_context);
+        assert!(!state.editor.can_undo());
+
+        state.insert("34", &mut text_context);
+        state.insert("5", &mut text_context);
+        assert_eq!(state.editor.raw_text(), "123");
+
+        // A single undo takes back the last character that was inserted.
+        state.edit(&mut text_context, |drv| drv.undo());
+        assert_eq!(state.editor.raw_text(), "12");
+    }
+
+    #[test]
+    fn copy_cut_and_paste_go_through_the_clipboard() {
+        let mut text_context = TextContext::with_test_font();
+        let clipboard = Clipboard::memory();
+        let mut state = TextInputState::new(&Style::default(), None, RangedStyles::default(), 1.0);
+        state.editor.set_text("Hello world");
+
+        state.edit(&mut text_context, |drv| {
+            drv.select_byte_range(0, 5);
+            copy(drv, &clipboard);
+        });
+        assert_eq!(clipboard.text().as_deref(), Some("Hello"));
+        assert_eq!(state.editor.raw_text(), "Hello world");
+
+        state.edit(&mut text_context, |drv| {
+            drv.select_byte_range(5, 11);
+            cut(drv, &clipboard);
+        });
+        assert_eq!(clipboard.text().as_deref(), Some(" world"));
+        assert_eq!(state.editor.raw_text(), "Hello");
+
+        state.edit(&mut text_context, |drv| drv.move_to_byte(0));
+        paste(&mut state, &mut text_context, &clipboard);
+        assert_eq!(state.editor.raw_text(), " worldHello");
+    }
+}
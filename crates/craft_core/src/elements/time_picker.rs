@@ -0,0 +1,651 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::Props;
+use crate::components::{Event, FocusAction};
+use crate::elements::element::Element;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+#[cfg(feature = "accesskit")]
+use crate::elements::picker::to_accesskit_rect;
+use crate::elements::picker::{
+    default_picker_field_style, draw_popover_panel, fill_rounded_rect, inset, is_picker_field_focused, picker_field,
+    set_picker_field_text, PICKER_HOVER_COLOR, PICKER_MUTED_TEXT_COLOR, PICKER_RADIUS, PICKER_SELECTED_COLOR,
+    PICKER_SELECTED_TEXT_COLOR,
+};
+use crate::events::CraftMessage;
+use crate::generate_component_methods_no_children;
+use crate::geometry::{Point, Rectangle, Size};
+use crate::layout::layout_context::LayoutContext;
+use crate::layout::popover::Placement;
+#[cfg(feature = "accesskit")]
+use crate::reactive::element_id::create_unique_element_id;
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::Style;
+use crate::text::label_cache::LabelCache;
+use crate::text::text_context::TextContext;
+use chrono::{NaiveTime, Timelike};
+use kurbo::Affine;
+use std::any::Any;
+use std::sync::Arc;
+use taffy::{NodeId, TaffyTree};
+use ui_events::keyboard::{Key, NamedKey};
+use winit::window::Window;
+
+const CELL_WIDTH: f32 = 36.0;
+const CELL_HEIGHT: f32 = 28.0;
+/// The number of hours or minutes in a row.
+const COLUMNS: usize = 6;
+const PANEL_PADDING: f32 = 8.0;
+/// The height of the "Hour" and "Minute" titles.
+const SECTION_TITLE_HEIGHT: f32 = 24.0;
+const PANEL_WIDTH: f32 = COLUMNS as f32 * CELL_WIDTH + 2.0 * PANEL_PADDING;
+
+/// A part of the time that is picked separately.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum TimeSegment {
+    #[default]
+    Hour,
+    Minute,
+}
+
+/// A text field for a time that opens a panel with the hours and minutes to pick one.
+///
+/// The time can be typed or pasted in the `format` of the picker, also with minutes that are not a multiple of the
+/// minute step. The panel is drawn in the overlay layer under the field. While the field is focused, the up and down
+/// arrow keys change the highlighted hour or minute, the left and right arrow keys switch between them, Enter picks
+/// the highlighted time and Escape closes the panel.
+///
+/// Emits [`CraftMessage::TimeChanged`] when an hour or a minute is picked, or when the typed text is a time.
+#[derive(Clone)]
+pub struct TimePicker {
+    pub element_data: ElementData,
+    /// The time that is picked when the element is created.
+    value: Option<NaiveTime>,
+    /// The minutes that can be picked are multiples of this.
+    minute_step: u32,
+    /// How the picked time is shown in and read from the field, see [`chrono::format::strftime`].
+    format: String,
+    /// The text shown in the field while no time is picked.
+    placeholder: String,
+    /// Where the panel is placed relative to the field.
+    panel_placement: Placement,
+    /// Where the panel was placed in window coordinates, `None` while it is closed.
+    panel: Option<Rectangle>,
+}
+
+pub struct TimePickerState {
+    is_open: bool,
+    selected: Option<NaiveTime>,
+    /// The time from the view during the last update.
+    view_value: Option<NaiveTime>,
+    /// The segment that the arrow keys change.
+    segment: TimeSegment,
+    /// The hour and minute that are highlighted while the panel is open.
+    focused_hour: u32,
+    focused_minute: u32,
+    hovered: Option<(TimeSegment, u32)>,
+    /// Whether the field shows the picked time even while it is focused, because it was picked in the panel or
+    /// changed by the view.
+    update_field: bool,
+    labels: LabelCache,
+}
+
+impl TimePickerState {
+    pub(crate) fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+
+impl Element for TimePicker {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    /// An open panel claims every point in the window, so that a click outside of it closes the panel.
+    fn in_bounds(&self, point: Point) -> bool {
+        if self.panel.is_some() {
+            return true;
+        }
+
+        let element_data = self.element_data();
+        let rect = element_data.layout_item.computed_box_transformed.border_rectangle();
+
+        if let Some(clip) = element_data.layout_item.clip_bounds {
+            match rect.intersection(&clip) {
+                Some(bounds) => bounds.contains(&point),
+                None => false,
+            }
+        } else {
+            rect.contains(&point)
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "TimePicker"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<Window>>,
+        scale_factor: f64,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+
+        self.draw_borders(renderer, element_state, scale_factor);
+        self.draw_children(renderer, text_context, element_state, pointer, window, scale_factor);
+
+        let Some(panel) = self.panel else {
+            return;
+        };
+
+        let style = *self.element_data.current_style();
+        let state = self.get_state_mut(element_state);
+
+        renderer.start_overlay();
+        draw_popover_panel(renderer, panel, scale_factor);
+
+        for (segment, title) in [(TimeSegment::Hour, "Hour"), (TimeSegment::Minute, "Minute")] {
+            let title_rectangle = Rectangle::new(
+                panel.x + PANEL_PADDING + 4.0,
+                self.section_top(panel, segment),
+                PANEL_WIDTH - 2.0 * PANEL_PADDING,
+                SECTION_TITLE_HEIGHT,
+            );
+            state.labels.draw(
+                renderer,
+                text_context,
+                &style,
+                title,
+                PICKER_MUTED_TEXT_COLOR,
+                title_rectangle,
+                false,
+                scale_factor,
+            );
+
+            let (selected, focused) = match segment {
+                TimeSegment::Hour => (state.selected.map(|time| time.hour()), state.focused_hour),
+                TimeSegment::Minute => (state.selected.map(|time| time.minute()), state.focused_minute),
+            };
+            for (index, value) in self.segment_values(segment).enumerate() {
+                let rectangle = self.cell_rectangle(panel, segment, index);
+                let is_highlighted =
+                    (state.segment == segment && focused == value) || state.hovered == Some((segment, value));
+
+                let color = if selected == Some(value) {
+                    fill_rounded_rect(renderer, inset(rectangle), PICKER_RADIUS, PICKER_SELECTED_COLOR, scale_factor);
+                    PICKER_SELECTED_TEXT_COLOR
+                } else {
+                    if is_highlighted {
+                        fill_rounded_rect(renderer, inset(rectangle), PICKER_RADIUS, PICKER_HOVER_COLOR, scale_factor);
+                    }
+                    style.color()
+                };
+                let text = format!("{value:02}");
+                state.labels.draw(renderer, text_context, &style, &text, color, rectangle, true, scale_factor);
+            }
+        }
+        renderer.end_overlay();
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+        self.update_field(element_state);
+
+        for child in self.element_data.children.iter_mut() {
+            let child_node = child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&child_node);
+        }
+
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: Affine,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        let origin = self.element_data.layout_item.computed_box.position;
+        for child in self.element_data.children.iter_mut() {
+            let Some(taffy_child_node_id) = child.internal.element_data().layout_item.taffy_node_id else {
+                continue;
+            };
+
+            child.internal.finalize_layout(
+                taffy_tree,
+                taffy_child_node_id,
+                origin,
+                z_index,
+                transform,
+                element_state,
+                pointer,
+                text_context,
+                self.element_data.layout_item.clip_bounds,
+            );
+        }
+
+        self.panel = if self.get_state(element_state).is_open {
+            let anchor = self.computed_box_transformed().border_rectangle();
+            let size = Size::new(PANEL_WIDTH, self.panel_height());
+            Some(self.panel_placement.resolve(anchor, size, element_state.viewport))
+        } else {
+            None
+        };
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+
+        // The field is focused instead of the picker, so that it can be typed in.
+        let focused = is_picker_field_focused(&self.element_data, element_state);
+        let field = self.computed_box_transformed().border_rectangle();
+        let state = self.get_state_mut(element_state);
+
+        match message {
+            CraftMessage::PointerButtonDown(pointer_button) => {
+                let Some(panel) = self.panel else {
+                    return;
+                };
+                event.prevent_propagate();
+
+                let position = Point::new(pointer_button.state.position.x, pointer_button.state.position.y);
+                if !panel.contains(&position) && !field.contains(&position) {
+                    state.is_open = false;
+                    event.focus_action(FocusAction::Unset);
+                }
+            }
+            CraftMessage::PointerButtonUp(pointer_button) => {
+                if !message.clicked() {
+                    return;
+                }
+
+                // Clicking in the open field only moves the caret.
+                let position = Point::new(pointer_button.state.position.x, pointer_button.state.position.y);
+                if field.contains(&position) {
+                    if !state.is_open {
+                        self.open(state);
+                    }
+                    return;
+                }
+
+                let Some(panel) = self.panel else {
+                    return;
+                };
+                event.prevent_propagate();
+                match self.panel_target(panel, position) {
+                    // Picking an hour keeps the panel open, so that a minute can be picked next.
+                    Some((TimeSegment::Hour, hour)) => {
+                        state.focused_hour = hour;
+                        state.segment = TimeSegment::Minute;
+                        self.select(state, event);
+                    }
+                    Some((TimeSegment::Minute, minute)) => {
+                        state.focused_minute = minute;
+                        self.select(state, event);
+                        state.is_open = false;
+                    }
+                    None => {}
+                }
+            }
+            CraftMessage::PointerMovedEvent(pointer_update) => {
+                let Some(panel) = self.panel else {
+                    return;
+                };
+
+                let position = Point::new(pointer_update.current.position.x, pointer_update.current.position.y);
+                state.hovered = self.panel_target(panel, position);
+            }
+            CraftMessage::KeyboardInputEvent(keyboard_input) => {
+                if !focused || !keyboard_input.state.is_down() {
+                    return;
+                }
+
+                if !state.is_open {
+                    if matches!(&keyboard_input.key, Key::Named(NamedKey::Enter | NamedKey::ArrowDown)) {
+                        self.open(state);
+                        event.prevent_propagate();
+                    }
+                    return;
+                }
+                event.prevent_propagate();
+
+                match &keyboard_input.key {
+                    Key::Named(NamedKey::Escape) => state.is_open = false,
+                    Key::Named(NamedKey::ArrowLeft | NamedKey::ArrowRight) => {
+                        state.segment = match state.segment {
+                            TimeSegment::Hour => TimeSegment::Minute,
+                            TimeSegment::Minute => TimeSegment::Hour,
+                        };
+                    }
+                    Key::Named(NamedKey::ArrowUp) => self.step(state, -1),
+                    Key::Named(NamedKey::ArrowDown) => self.step(state, 1),
+                    Key::Named(NamedKey::Enter) => {
+                        self.select(state, event);
+                        state.is_open = false;
+                    }
+                    _ => {}
+                }
+            }
+            CraftMessage::TextInputChanged(text) => {
+                event.prevent_propagate();
+                let Ok(time) = NaiveTime::parse_from_str(text.trim(), &self.format) else {
+                    return;
+                };
+                if state.selected == Some(time) {
+                    return;
+                }
+
+                // The panel follows the typed time, but stays open.
+                state.selected = Some(time);
+                self.focus_time(state, time);
+                event.result_message(CraftMessage::TimeChanged(time));
+            }
+            _ => {}
+        }
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(TimePickerState {
+                is_open: false,
+                selected: self.value,
+                view_value: self.value,
+                segment: TimeSegment::Hour,
+                focused_hour: 0,
+                focused_minute: 0,
+                hovered: None,
+                update_field: false,
+                labels: LabelCache::default(),
+            }),
+        }
+    }
+
+    fn update_state(&mut self, element_state: &mut ElementStateStore, _reload_fonts: bool, _scaling_factor: f64) {
+        let state = self.get_base_state_mut(element_state).data.as_mut().downcast_mut::<TimePickerState>().unwrap();
+        if state.view_value == self.value {
+            return;
+        }
+
+        state.view_value = self.value;
+        state.selected = self.value;
+        state.update_field = true;
+    }
+
+    #[cfg(feature = "accesskit")]
+    fn compute_accessibility_tree(
+        &mut self,
+        tree: &mut accesskit::TreeUpdate,
+        parent_index: Option<usize>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) {
+        let current_node_id = accesskit::NodeId(self.element_data().component_id);
+        let state = self.get_state(element_state);
+
+        let mut current_node = accesskit::Node::new(accesskit::Role::TimeInput);
+        if let Some(selected) = state.selected {
+            current_node.set_value(selected.format(&self.format).to_string());
+        }
+        current_node.set_has_popup(accesskit::HasPopup::Dialog);
+        current_node.set_expanded(self.panel.is_some());
+        current_node.add_action(accesskit::Action::Click);
+
+        let padding_box =
+            self.element_data().layout_item.computed_box_transformed.padding_rectangle().scale(scale_factor);
+
+        current_node.set_bounds(accesskit::Rect {
+            x0: padding_box.left() as f64,
+            y0: padding_box.top() as f64,
+            x1: padding_box.right() as f64,
+            y1: padding_box.bottom() as f64,
+        });
+
+        // The panel is exposed as a grid of hours and a grid of minutes.
+        if let Some(panel) = self.panel {
+            for (segment, label) in [(TimeSegment::Hour, "Hour"), (TimeSegment::Minute, "Minute")] {
+                let selected = state.selected.map(|time| match segment {
+                    TimeSegment::Hour => time.hour(),
+                    TimeSegment::Minute => time.minute(),
+                });
+
+                let grid_id = accesskit::NodeId(create_unique_element_id());
+                let mut grid = accesskit::Node::new(accesskit::Role::Grid);
+                grid.set_label(label);
+                for (index, value) in self.segment_values(segment).enumerate() {
+                    let cell_id = accesskit::NodeId(create_unique_element_id());
+                    let mut cell = accesskit::Node::new(accesskit::Role::GridCell);
+                    cell.set_label(format!("{value:02}"));
+                    cell.set_bounds(to_accesskit_rect(self.cell_rectangle(panel, segment, index).scale(scale_factor)));
+                    cell.set_selected(selected == Some(value));
+                    grid.push_child(cell_id);
+                    tree.nodes.push((cell_id, cell));
+                }
+
+                current_node.push_child(grid_id);
+                tree.nodes.push((grid_id, grid));
+            }
+        }
+
+        let current_index = tree.nodes.len();
+
+        if let Some(parent_index) = parent_index {
+            let parent_node = tree.nodes.get_mut(parent_index).unwrap();
+            parent_node.1.push_child(current_node_id);
+        }
+
+        tree.nodes.push((current_node_id, current_node));
+
+        for child in self.element_data.children.iter_mut() {
+            child.internal.compute_accessibility_tree(tree, Some(current_index), element_state, scale_factor);
+        }
+    }
+
+    fn default_style(&self) -> Style {
+        default_picker_field_style()
+    }
+}
+
+impl TimePicker {
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a TimePickerState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    fn get_state_mut<'a>(&self, element_state: &'a mut ElementStateStore) -> &'a mut TimePickerState {
+        self.get_base_state_mut(element_state).data.as_mut().downcast_mut().unwrap()
+    }
+
+    /// Creates a time picker with `value` picked.
+    pub fn new(value: Option<NaiveTime>) -> TimePicker {
+        let mut time_picker = TimePicker {
+            element_data: Default::default(),
+            value,
+            minute_step: 5,
+            format: "%H:%M".to_string(),
+            placeholder: "HH:MM".to_string(),
+            panel_placement: Placement::default().offset(4.0),
+            panel: None,
+        };
+        time_picker.set_field_spec();
+
+        time_picker
+    }
+
+    /// Set the step between the minutes that can be picked. Defaults to 5.
+    pub fn minute_step(mut self, minute_step: u32) -> Self {
+        self.minute_step = minute_step.clamp(1, 30);
+        self
+    }
+
+    /// Set how the picked time is shown in the field, see [`chrono::format::strftime`]. Defaults to `%H:%M`.
+    pub fn format(mut self, format: &str) -> Self {
+        self.format = format.to_string();
+        self.set_field_spec();
+        self
+    }
+
+    /// Set the text shown in the field while no time is picked.
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = placeholder.to_string();
+        self.set_field_spec();
+        self
+    }
+
+    /// Sets where the panel is placed relative to the field.
+    /// By default, the panel is placed below the field and flips above it when there is not enough room.
+    pub fn panel_placement(mut self, placement: Placement) -> Self {
+        self.panel_placement = placement;
+        self
+    }
+
+    /// The picked time in the format of the picker.
+    fn field_text(&self, selected: Option<NaiveTime>) -> String {
+        selected.map(|selected| selected.format(&self.format).to_string()).unwrap_or_default()
+    }
+
+    fn set_field_spec(&mut self) {
+        self.element_data.child_specs = vec![picker_field(&self.field_text(self.value), &self.placeholder)];
+    }
+
+    /// Shows the picked time in the field, unless it is being typed.
+    fn update_field(&self, element_state: &mut ElementStateStore) {
+        let state = self.get_state_mut(element_state);
+        let text = self.field_text(state.selected);
+        let force = std::mem::take(&mut state.update_field);
+        set_picker_field_text(&self.element_data, element_state, &text, force);
+    }
+
+    /// The hours or the minutes that can be picked, in order.
+    fn segment_values(&self, segment: TimeSegment) -> impl Iterator<Item = u32> {
+        match segment {
+            TimeSegment::Hour => (0..24).step_by(1),
+            TimeSegment::Minute => (0..60).step_by(self.minute_step as usize),
+        }
+    }
+
+    fn segment_rows(&self, segment: TimeSegment) -> usize {
+        self.segment_values(segment).count().div_ceil(COLUMNS)
+    }
+
+    /// The top of the title of a segment.
+    fn section_top(&self, panel: Rectangle, segment: TimeSegment) -> f32 {
+        let hour_section_top = panel.y + PANEL_PADDING;
+        match segment {
+            TimeSegment::Hour => hour_section_top,
+            TimeSegment::Minute => {
+                hour_section_top + SECTION_TITLE_HEIGHT + self.segment_rows(TimeSegment::Hour) as f32 * CELL_HEIGHT
+            }
+        }
+    }
+
+    fn panel_height(&self) -> f32 {
+        let rows = self.segment_rows(TimeSegment::Hour) + self.segment_rows(TimeSegment::Minute);
+        2.0 * PANEL_PADDING + 2.0 * SECTION_TITLE_HEIGHT + rows as f32 * CELL_HEIGHT
+    }
+
+    /// The rectangle of the value at `index` in a segment, counting from the top left.
+    fn cell_rectangle(&self, panel: Rectangle, segment: TimeSegment, index: usize) -> Rectangle {
+        let row = (index / COLUMNS) as f32;
+        let column = (index % COLUMNS) as f32;
+        Rectangle::new(
+            panel.x + PANEL_PADDING + column * CELL_WIDTH,
+            self.section_top(panel, segment) + SECTION_TITLE_HEIGHT + row * CELL_HEIGHT,
+            CELL_WIDTH,
+            CELL_HEIGHT,
+        )
+    }
+
+    /// Finds the hour or minute at `point`.
+    fn panel_target(&self, panel: Rectangle, point: Point) -> Option<(TimeSegment, u32)> {
+        [TimeSegment::Hour, TimeSegment::Minute].into_iter().find_map(|segment| {
+            self.segment_values(segment)
+                .enumerate()
+                .find(|(index, _)| self.cell_rectangle(panel, segment, *index).contains(&point))
+                .map(|(_, value)| (segment, value))
+        })
+    }
+
+    fn open(&self, state: &mut TimePickerState) {
+        state.is_open = true;
+        state.hovered = None;
+        state.segment = TimeSegment::Hour;
+        self.focus_time(state, state.selected.unwrap_or_default());
+    }
+
+    /// Highlights the hour of `time` and the closest minute before it that can be picked.
+    fn focus_time(&self, state: &mut TimePickerState, time: NaiveTime) {
+        state.focused_hour = time.hour();
+        state.focused_minute = time.minute() - time.minute() % self.minute_step;
+    }
+
+    /// Moves the highlight of the current segment, wrapping around at either end.
+    fn step(&self, state: &mut TimePickerState, by: i32) {
+        match state.segment {
+            TimeSegment::Hour => state.focused_hour = (state.focused_hour as i32 + by).rem_euclid(24) as u32,
+            TimeSegment::Minute => {
+                let step = self.minute_step as i32;
+                let minutes = 60 - 60 % step + if 60 % step == 0 { 0 } else { step };
+                state.focused_minute = (state.focused_minute as i32 + by * step).rem_euclid(minutes) as u32;
+            }
+        }
+    }
+
+    /// Picks the highlighted hour and minute.
+    fn select(&self, state: &mut TimePickerState, event: &mut Event) {
+        let Some(time) = NaiveTime::from_hms_opt(state.focused_hour, state.focused_minute, 0) else {
+            return;
+        };
+
+        state.selected = Some(time);
+        state.update_field = true;
+        event.result_message(CraftMessage::TimeChanged(time));
+    }
+
+    generate_component_methods_no_children!();
+}
+
+impl ElementStyles for TimePicker {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
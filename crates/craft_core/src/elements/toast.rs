@@ -0,0 +1,648 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::Props;
+use crate::components::{ComponentId, Event};
+use crate::elements::element::Element;
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::elements::{Container, Text};
+use crate::events::{CraftMessage, EventDispatchType, Message, UserMessage};
+use crate::generate_component_methods;
+use crate::geometry::{Point, Rectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::layout::popover::{Placement, PlacementAlignment, PlacementSide};
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::{AlignItems, Display, FlexDirection, Style, Unit, Weight};
+use crate::text::text_context::TextContext;
+#[cfg(feature = "accesskit")]
+use accesskit::{Live, Role};
+use kurbo::Affine;
+use peniko::Color;
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time;
+use taffy::{NodeId, Position, TaffyTree};
+use time::{Duration, Instant};
+use ui_events::pointer::PointerButton;
+#[cfg(target_arch = "wasm32")]
+use web_time as time;
+use winit::window::Window;
+
+/// The index of the action button in the children of a toast.
+const TOAST_ACTION_INDEX: usize = 1;
+
+const DEFAULT_TOAST_TIMEOUT: Duration = Duration::from_secs(5);
+/// How long toasts take to slide in after being pushed, and to slide out after being dismissed.
+const TOAST_ANIMATION_DURATION: Duration = Duration::from_millis(200);
+const TOAST_SLIDE_DISTANCE: f32 = 48.0;
+/// The distance between the toasts and the edges of the window.
+const TOAST_MARGIN: f32 = 16.0;
+const TOAST_WIDTH: f32 = 320.0;
+
+const TOAST_BACKGROUND_COLOR: Color = Color::from_rgb8(255, 255, 255);
+const TOAST_BORDER_COLOR: Color = Color::from_rgb8(220, 220, 220);
+const TOAST_TEXT_COLOR: Color = Color::from_rgb8(30, 30, 30);
+const TOAST_CLOSE_COLOR: Color = Color::from_rgb8(120, 120, 120);
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub enum ToastSeverity {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastSeverity {
+    fn color(self) -> Color {
+        match self {
+            ToastSeverity::Info => Color::from_rgb8(59, 130, 246),
+            ToastSeverity::Success => Color::from_rgb8(34, 160, 80),
+            ToastSeverity::Warning => Color::from_rgb8(220, 150, 0),
+            ToastSeverity::Error => Color::from_rgb8(220, 50, 50),
+        }
+    }
+}
+
+/// Creates the message that is sent when a toast's action is clicked.
+type ToastActionMessage = Arc<dyn Fn() -> Box<UserMessage> + Send + Sync>;
+
+/// A transient notification shown in the bottom right corner of the window.
+///
+/// Push toasts with [`Event::push_toast`] or [`WindowContext::push_toast`](crate::WindowContext::push_toast).
+/// A toast is dismissed when its timeout runs out, when its close button is clicked or when its action is clicked.
+/// Hovering a toast pauses its timeout.
+#[derive(Clone)]
+pub struct Toast {
+    severity: ToastSeverity,
+    text: String,
+    action: Option<(String, ToastActionMessage)>,
+    timeout: Option<Duration>,
+    /// The component that pushed the toast, the action message is sent to it.
+    pub(crate) origin: Option<ComponentId>,
+}
+
+impl fmt::Debug for Toast {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Toast")
+            .field("severity", &self.severity)
+            .field("text", &self.text)
+            .field("action", &self.action.as_ref().map(|(label, _)| label))
+            .field("timeout", &self.timeout)
+            .field("origin", &self.origin)
+            .finish()
+    }
+}
+
+impl Toast {
+    pub fn new(severity: ToastSeverity, text: &str) -> Self {
+        Toast {
+            severity,
+            text: text.to_string(),
+            action: None,
+            timeout: Some(DEFAULT_TOAST_TIMEOUT),
+            origin: None,
+        }
+    }
+
+    /// Adds a button to the toast.
+    ///
+    /// Clicking it dismisses the toast and sends `message` to the component that pushed the toast,
+    /// which receives it in `on_user_message`.
+    pub fn action<M>(mut self, label: &str, message: M) -> Self
+    where
+        M: Clone + Send + Sync + 'static,
+    {
+        let action_message: ToastActionMessage = Arc::new(move || Box::new(message.clone()));
+        self.action = Some((label.to_string(), action_message));
+        self
+    }
+
+    /// Sets how long the toast is shown, not counting the time it is hovered. Defaults to 5 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Shows the toast until it is dismissed with its close button or action.
+    pub fn no_timeout(mut self) -> Self {
+        self.timeout = None;
+        self
+    }
+}
+
+/// The toasts that are currently shown, owned by the app.
+///
+/// The toasts are rebuilt into a [`ToastStack`] next to the user's root on every view update,
+/// so that they can animate, time out and be dismissed independently of the user's state.
+#[derive(Default)]
+pub(crate) struct ToastQueue {
+    toasts: Vec<ActiveToast>,
+    next_key: u64,
+    last_update: Option<Instant>,
+}
+
+struct ActiveToast {
+    key: u64,
+    toast: Toast,
+    shown_at: Instant,
+    /// The time left before the toast is dismissed, or `None` if it has no timeout.
+    remaining: Option<Duration>,
+    dismissed_at: Option<Instant>,
+}
+
+impl ToastQueue {
+    pub(crate) fn push(&mut self, toast: Toast) {
+        self.toasts.push(ActiveToast {
+            key: self.next_key,
+            remaining: toast.timeout,
+            toast,
+            shown_at: Instant::now(),
+            dismissed_at: None,
+        });
+        self.next_key += 1;
+    }
+
+    /// Counts down the timeouts and removes the toasts that finished sliding out.
+    ///
+    /// The stack's state tells which toast is hovered and which toasts were dismissed since the last update.
+    pub(crate) fn update(&mut self, stack_state: Option<&mut ToastStackState>) {
+        let now = Instant::now();
+        let elapsed = self.last_update.map(|last_update| now.duration_since(last_update)).unwrap_or_default();
+        self.last_update = Some(now);
+
+        let (hovered, dismissed) = match stack_state {
+            Some(stack_state) => (stack_state.hovered, std::mem::take(&mut stack_state.dismissed)),
+            None => (None, Vec::new()),
+        };
+
+        for toast in self.toasts.iter_mut().filter(|toast| toast.dismissed_at.is_none()) {
+            if dismissed.contains(&toast.key) {
+                toast.dismissed_at = Some(now);
+            } else if hovered != Some(toast.key) {
+                if let Some(remaining) = toast.remaining.as_mut() {
+                    *remaining = remaining.saturating_sub(elapsed);
+                    if remaining.is_zero() {
+                        toast.dismissed_at = Some(now);
+                    }
+                }
+            }
+        }
+
+        self.toasts.retain(|toast| {
+            toast.dismissed_at.is_none_or(|dismissed_at| now.duration_since(dismissed_at) < TOAST_ANIMATION_DURATION)
+        });
+    }
+
+    /// Creates the toast stack that shows the current toasts, or `None` if there are no toasts.
+    ///
+    /// The app mounts the stack after the user's root, so the user's tree keeps its place when toasts come and go.
+    pub(crate) fn view(&self) -> Option<ComponentSpecification> {
+        if self.toasts.is_empty() {
+            return None;
+        }
+
+        let now = Instant::now();
+        let entries = self
+            .toasts
+            .iter()
+            .map(|toast| ToastStackEntry {
+                key: toast.key,
+                toast: toast.toast.clone(),
+            })
+            .collect();
+
+        let mut toast_stack = ToastStack::new(entries, self.is_animating(now));
+        for toast in &self.toasts {
+            let progress = match toast.dismissed_at {
+                Some(dismissed_at) => 1.0 - animation_progress(now.duration_since(dismissed_at)),
+                None => animation_progress(now.duration_since(toast.shown_at)),
+            };
+            toast_stack = toast_stack.push(toast_card(&toast.toast, progress).key(&format!("toast_{}", toast.key)));
+        }
+
+        Some(toast_stack.component())
+    }
+
+    /// Whether a toast is sliding in or out.
+    fn is_animating(&self, now: Instant) -> bool {
+        self.toasts
+            .iter()
+            .any(|toast| toast.dismissed_at.is_some() || now.duration_since(toast.shown_at) < TOAST_ANIMATION_DURATION)
+    }
+
+    /// Whether a toast ran out of time since the last update.
+    ///
+    /// The toasts don't draw frames while they wait, so the app checks this to redraw the window and dismiss them.
+    pub(crate) fn is_timeout_due(&self) -> bool {
+        let Some(last_update) = self.last_update else {
+            return false;
+        };
+        let next_deadline = self
+            .toasts
+            .iter()
+            .filter(|toast| toast.dismissed_at.is_none())
+            .filter_map(|toast| toast.remaining)
+            .min()
+            .map(|remaining| last_update + remaining);
+        next_deadline.is_some_and(|deadline| deadline <= Instant::now())
+    }
+}
+
+fn animation_progress(elapsed: Duration) -> f32 {
+    (elapsed.as_secs_f32() / TOAST_ANIMATION_DURATION.as_secs_f32()).clamp(0.0, 1.0)
+}
+
+/// Creates the row of a toast: the text, the action button if there is one, and the close button.
+///
+/// `progress` goes from 0 to 1 as the toast slides and fades in.
+fn toast_card(toast: &Toast, progress: f32) -> ComponentSpecification {
+    let severity_color = toast.severity.color();
+
+    let mut card = Container::new()
+        .display(Display::Flex)
+        .flex_direction(FlexDirection::Row)
+        .align_items(AlignItems::Center)
+        .gap(Unit::Px(12.0))
+        .width(Unit::Px(TOAST_WIDTH))
+        .padding(Unit::Px(10.0), Unit::Px(12.0), Unit::Px(10.0), Unit::Px(12.0))
+        .margin(Unit::Px(0.0), Unit::Px(-(1.0 - progress) * TOAST_SLIDE_DISTANCE), Unit::Px(0.0), Unit::Px(0.0))
+        .background(TOAST_BACKGROUND_COLOR.multiply_alpha(progress))
+        .border_width(Unit::Px(1.0), Unit::Px(1.0), Unit::Px(1.0), Unit::Px(4.0))
+        .border_color(TOAST_BORDER_COLOR.multiply_alpha(progress))
+        .border_color_left(severity_color.multiply_alpha(progress))
+        .border_radius(6.0, 6.0, 6.0, 6.0)
+        .push(
+            Text::new(&toast.text).disable_selection().flex_grow(1.0).color(TOAST_TEXT_COLOR.multiply_alpha(progress)),
+        );
+
+    if let Some((label, _)) = &toast.action {
+        card = card.push(
+            Text::new(label)
+                .disable_selection()
+                .font_weight(Weight::BOLD)
+                .color(severity_color.multiply_alpha(progress)),
+        );
+    }
+
+    card.push(Text::new("✕").disable_selection().color(TOAST_CLOSE_COLOR.multiply_alpha(progress))).component()
+}
+
+#[derive(Clone)]
+struct ToastStackEntry {
+    key: u64,
+    toast: Toast,
+}
+
+/// Shows the toasts, which are its children, over the user's root.
+///
+/// The stack itself takes no space. The toasts are placed in window space, so they stay in the corner when the
+/// root scrolls.
+#[derive(Clone, Default)]
+pub(crate) struct ToastStack {
+    pub element_data: ElementData,
+    /// The toasts in the same order as the children.
+    entries: Vec<ToastStackEntry>,
+    /// Whether a toast is sliding in or out, which takes a frame for every step of the animation.
+    animating: bool,
+    /// A pseudo list that places the toasts in the corner of the window, this is not stored in the user tree.
+    pseudo_toast_list: Container,
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct ToastStackState {
+    /// The toast under the pointer, its timeout is paused.
+    pub(crate) hovered: Option<u64>,
+    /// The toasts that were dismissed since the toast queue was last updated.
+    pub(crate) dismissed: Vec<u64>,
+}
+
+impl Element for ToastStack {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "ToastStack"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<Window>>,
+        scale_factor: f64,
+    ) {
+        let hovered = pointer.and_then(|pointer| self.card_at(pointer)).map(|index| self.entries[index].key);
+        self.get_state_mut(element_state).hovered = hovered;
+
+        if self.entries.is_empty() {
+            return;
+        }
+
+        renderer.start_overlay();
+        for card in self.element_data.children.iter_mut() {
+            if card.internal.taffy_node_id().is_none() {
+                continue;
+            }
+            card.internal.draw(renderer, text_context, element_state, pointer, window.clone(), scale_factor);
+        }
+        renderer.end_overlay();
+
+        // Keep drawing frames while the toasts animate. The app redraws the window when a toast times out.
+        if self.animating {
+            if let Some(window) = window.as_ref() {
+                window.request_redraw();
+            }
+        }
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+
+        let card_nodes: Vec<NodeId> = self
+            .element_data
+            .children
+            .iter_mut()
+            .filter_map(|card| card.internal.compute_layout(taffy_tree, element_state, scale_factor))
+            .collect();
+
+        self.pseudo_toast_list.element_data.layout_item.taffy_node_id = None;
+        if !card_nodes.is_empty() {
+            // The list is not in the element tree, so it borrows the stack's state.
+            self.pseudo_toast_list.element_data.component_id = self.element_data.component_id;
+            let mut toast_list_style = default_toast_list_style();
+            toast_placement().apply_to_style(&mut toast_list_style);
+            self.pseudo_toast_list.element_data.style = toast_list_style;
+
+            let toast_list_node = taffy_tree.new_with_children(toast_list_style.to_taffy_style(), &card_nodes).unwrap();
+            self.pseudo_toast_list.element_data.layout_item.taffy_node_id = Some(toast_list_node);
+            self.element_data.layout_item.push_child(&Some(toast_list_node));
+        }
+
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: Affine,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        let Some(toast_list_node) = self.pseudo_toast_list.element_data.layout_item.taffy_node_id else {
+            return;
+        };
+
+        // Anchor the list to the bottom right corner of the window.
+        let viewport = element_state.viewport;
+        let anchor = Rectangle::new(viewport.right() - TOAST_MARGIN, viewport.bottom(), 0.0, 0.0);
+        toast_placement().finalize_popover(
+            &mut self.pseudo_toast_list,
+            taffy_tree,
+            toast_list_node,
+            anchor,
+            z_index,
+            element_state,
+            pointer,
+            text_context,
+        );
+        let toast_list_position = self.pseudo_toast_list.element_data.layout_item.computed_box.position;
+
+        for card in self.element_data.children.iter_mut() {
+            let Some(card_node) = card.internal.taffy_node_id() else {
+                continue;
+            };
+            card.internal.finalize_layout(
+                taffy_tree,
+                card_node,
+                toast_list_position,
+                z_index,
+                // The list is placed in window space, so the toasts are as well.
+                Affine::IDENTITY,
+                element_state,
+                pointer,
+                text_context,
+                None,
+            );
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    /// Handles clicks on the action and close buttons of the toasts.
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+
+        let CraftMessage::PointerButtonUp(pointer_button) = message else {
+            return;
+        };
+        if pointer_button.button != Some(PointerButton::Primary) {
+            return;
+        }
+
+        let position = pointer_button.state.position;
+        let Some(index) = self.card_at(Point::new(position.x, position.y)) else {
+            return;
+        };
+        let entry = &self.entries[index];
+        let card = self.element_data.children[index].internal.as_ref();
+        let buttons = card.children();
+        let is_clicked = |button: Option<&&dyn Element>| {
+            button.is_some_and(|button| {
+                button.computed_box_transformed().border_rectangle().contains(&Point::new(position.x, position.y))
+            })
+        };
+
+        if let Some((_, action_message)) = &entry.toast.action {
+            if is_clicked(buttons.get(TOAST_ACTION_INDEX)) {
+                if let Some(origin) = entry.toast.origin {
+                    event.add_effect(EventDispatchType::Direct(origin), Message::UserMessage(action_message()));
+                }
+                self.get_state_mut(element_state).dismissed.push(entry.key);
+                return;
+            }
+        }
+
+        if is_clicked(buttons.last()) {
+            self.get_state_mut(element_state).dismissed.push(entry.key);
+        }
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(ToastStackState::default()),
+        }
+    }
+
+    /// The toasts are exposed as live regions, so that screen readers announce them when they are pushed.
+    #[cfg(feature = "accesskit")]
+    fn compute_accessibility_tree(
+        &mut self,
+        tree: &mut accesskit::TreeUpdate,
+        parent_index: Option<usize>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) {
+        let current_node_id = accesskit::NodeId(self.element_data().component_id);
+        let mut current_node = accesskit::Node::new(Role::GenericContainer);
+        let padding_box =
+            self.element_data().layout_item.computed_box_transformed.padding_rectangle().scale(scale_factor);
+        current_node.set_bounds(to_accesskit_rect(padding_box));
+
+        let current_index = tree.nodes.len();
+        if let Some(parent_index) = parent_index {
+            let parent_node = tree.nodes.get_mut(parent_index).unwrap();
+            parent_node.1.push_child(current_node_id);
+        }
+        tree.nodes.push((current_node_id, current_node));
+
+        let cards = self.element_data.children.iter();
+        for (card, entry) in cards.zip(self.entries.iter()) {
+            let card = card.internal.as_ref();
+            let card_node_id = accesskit::NodeId(card.component_id());
+
+            let mut card_node = if entry.toast.severity == ToastSeverity::Error {
+                let mut node = accesskit::Node::new(Role::Alert);
+                node.set_live(Live::Assertive);
+                node
+            } else {
+                let mut node = accesskit::Node::new(Role::Status);
+                node.set_live(Live::Polite);
+                node
+            };
+            card_node.set_label(entry.toast.text.clone());
+            card_node
+                .set_bounds(to_accesskit_rect(card.computed_box_transformed().padding_rectangle().scale(scale_factor)));
+
+            let buttons = card.children();
+            let mut button_labels: Vec<(&dyn Element, String)> = Vec::new();
+            if let (Some((label, _)), Some(action_button)) = (&entry.toast.action, buttons.get(TOAST_ACTION_INDEX)) {
+                button_labels.push((*action_button, label.clone()));
+            }
+            if let Some(close_button) = buttons.last() {
+                button_labels.push((*close_button, "Dismiss".to_string()));
+            }
+
+            tree.nodes[current_index].1.push_child(card_node_id);
+            for (button, label) in button_labels {
+                let button_node_id = accesskit::NodeId(button.component_id());
+                let mut button_node = accesskit::Node::new(Role::Button);
+                button_node.set_label(label);
+                button_node.set_bounds(to_accesskit_rect(
+                    button.computed_box_transformed().padding_rectangle().scale(scale_factor),
+                ));
+                card_node.push_child(button_node_id);
+                tree.nodes.push((button_node_id, button_node));
+            }
+            tree.nodes.push((card_node_id, card_node));
+        }
+    }
+
+    fn default_style(&self) -> Style {
+        let mut default_style = Style::default();
+
+        // The stack doesn't take space from the user's root, the toasts are placed by their own list.
+        *default_style.display_mut() = Display::Block;
+        *default_style.position_mut() = Position::Absolute;
+        *default_style.width_mut() = Unit::Px(0.0);
+        *default_style.height_mut() = Unit::Px(0.0);
+
+        default_style
+    }
+}
+
+impl ToastStack {
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a ToastStackState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    fn get_state_mut<'a>(&self, element_state: &'a mut ElementStateStore) -> &'a mut ToastStackState {
+        element_state.storage.get_mut(&self.element_data.component_id).unwrap().data.as_mut().downcast_mut().unwrap()
+    }
+
+    fn new(entries: Vec<ToastStackEntry>, animating: bool) -> ToastStack {
+        ToastStack {
+            element_data: Default::default(),
+            entries,
+            animating,
+            pseudo_toast_list: Container::new(),
+        }
+    }
+
+    /// The index of the toast under the point.
+    fn card_at(&self, point: Point) -> Option<usize> {
+        self.element_data
+            .children
+            .iter()
+            .position(|card| card.internal.computed_box_transformed().border_rectangle().contains(&point))
+    }
+
+    generate_component_methods!();
+}
+
+impl ElementStyles for ToastStack {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
+
+fn default_toast_list_style() -> Style {
+    let mut default_style = Style::default();
+
+    *default_style.display_mut() = Display::Flex;
+    *default_style.flex_direction_mut() = FlexDirection::Column;
+    *default_style.align_items_mut() = Some(AlignItems::FlexEnd);
+    *default_style.gap_mut() = [Unit::Px(8.0), Unit::Px(8.0)];
+
+    default_style
+}
+
+fn toast_placement() -> Placement {
+    Placement::new(PlacementSide::Top, PlacementAlignment::End).offset(TOAST_MARGIN).flip(false)
+}
+
+#[cfg(feature = "accesskit")]
+fn to_accesskit_rect(rectangle: Rectangle) -> accesskit::Rect {
+    accesskit::Rect {
+        x0: rectangle.left() as f64,
+        y0: rectangle.top() as f64,
+        x1: rectangle.right() as f64,
+        y1: rectangle.bottom() as f64,
+    }
+}
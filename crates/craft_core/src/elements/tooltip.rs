@@ -0,0 +1,396 @@
+use crate::components::component::ComponentSpecification;
+use crate::components::Event;
+use crate::components::Props;
+use crate::elements::element::{resolve_clip_for_scrollable, Element};
+use crate::elements::element_data::ElementData;
+use crate::elements::element_styles::ElementStyles;
+use crate::elements::text::TextState;
+use crate::events::CraftMessage;
+use crate::generate_component_methods_no_children;
+use crate::geometry::{Point, Rectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::layout::popover::{Placement, PlacementAlignment, PlacementSide};
+use crate::reactive::element_state_store::{ElementStateStore, ElementStateStoreItem};
+use crate::renderer::renderer::RenderList;
+use crate::style::Style;
+use crate::text::text_context::TextContext;
+#[cfg(feature = "accesskit")]
+use accesskit::{Action, Role};
+use kurbo::Affine;
+use std::any::Any;
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time;
+use taffy::{NodeId, TaffyTree};
+use time::{Duration, Instant};
+#[cfg(target_arch = "wasm32")]
+use web_time as time;
+use winit::window::Window;
+
+/// The index of the tooltip content in the children.
+const TOOLTIP_CONTENT_INDEX: usize = 0;
+
+/// An element that shows a tooltip next to its children after they have been hovered for a while.
+///
+/// The tooltip is drawn in the overlay layer, so it is not clipped by scroll containers.
+/// Any element can be wrapped with [`Tooltip`] by calling `.tooltip(content)` on it.
+#[derive(Clone)]
+pub struct Tooltip {
+    pub element_data: ElementData,
+    /// How long the children need to be hovered before the tooltip is shown.
+    delay: Duration,
+    /// Where the tooltip is placed relative to the children.
+    placement: Placement,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct TooltipState {
+    /// When the pointer started hovering the children.
+    hover_start: Option<Instant>,
+    /// Whether the tooltip is shown.
+    is_visible: bool,
+    /// The tooltip stays hidden after a scroll until the pointer leaves the element.
+    is_dismissed: bool,
+}
+
+impl TooltipState {
+    fn is_pending(&self) -> bool {
+        self.hover_start.is_some() && !self.is_visible && !self.is_dismissed
+    }
+}
+
+impl Element for Tooltip {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn name(&self) -> &'static str {
+        "Tooltip"
+    }
+
+    fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        window: Option<Arc<Window>>,
+        scale_factor: f64,
+    ) {
+        if !self.element_data.style.visible() {
+            return;
+        }
+        let state = *self.get_state(element_state);
+
+        // We draw the borders before we start any layers, so that we don't clip the borders.
+        self.draw_borders(renderer, element_state, scale_factor);
+        self.maybe_start_layer(renderer, scale_factor);
+        {
+            for child in self.element_data.children.iter_mut().skip(TOOLTIP_CONTENT_INDEX + 1) {
+                // Skip non-visual elements.
+                if child.internal.taffy_node_id().is_none() {
+                    continue;
+                }
+                child.internal.draw(renderer, text_context, element_state, pointer, window.clone(), scale_factor);
+            }
+        }
+        self.maybe_end_layer(renderer);
+        self.draw_scrollbar(renderer, scale_factor);
+
+        if state.is_visible {
+            if let Some(content) = self.element_data.children.get_mut(TOOLTIP_CONTENT_INDEX) {
+                renderer.start_overlay();
+                content.internal.draw(renderer, text_context, element_state, pointer, window.clone(), scale_factor);
+                renderer.end_overlay();
+            }
+        } else if state.is_pending() {
+            // Keep drawing frames until the hover delay has passed.
+            if let Some(window) = window.as_ref() {
+                window.request_redraw();
+            }
+        }
+    }
+
+    fn compute_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) -> Option<NodeId> {
+        self.merge_default_style();
+
+        let delay = self.delay;
+        let base_state = self.get_base_state_mut(element_state);
+        let is_hovered = base_state.base.hovered;
+        let state = base_state.data.as_mut().downcast_mut::<TooltipState>().unwrap();
+        if !is_hovered {
+            *state = TooltipState::default();
+        } else if let Some(hover_start) = state.hover_start {
+            state.is_visible = !state.is_dismissed && hover_start.elapsed() >= delay;
+        }
+        let is_visible = state.is_visible;
+
+        let placement = self.placement;
+        for (index, child) in self.element_data.children.iter_mut().enumerate() {
+            if index == TOOLTIP_CONTENT_INDEX {
+                if !is_visible {
+                    continue;
+                }
+                placement.apply_to_style(child.internal.style_mut());
+            }
+
+            let child_node = child.internal.compute_layout(taffy_tree, element_state, scale_factor);
+            self.element_data.layout_item.push_child(&child_node);
+        }
+
+        let style: taffy::Style = self.element_data.style.to_taffy_style();
+
+        self.element_data.layout_item.build_tree(taffy_tree, style)
+    }
+
+    fn finalize_layout(
+        &mut self,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        root_node: NodeId,
+        position: Point,
+        z_index: &mut u32,
+        transform: Affine,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+        clip_bounds: Option<Rectangle>,
+    ) {
+        let is_visible = self.get_state(element_state).is_visible;
+        let result = taffy_tree.layout(root_node).unwrap();
+        self.resolve_box(position, transform, result, z_index);
+        self.resolve_clip(clip_bounds);
+        self.finalize_borders(element_state);
+
+        let anchor = self.computed_box_transformed().border_rectangle();
+        let placement = self.placement;
+        let element_position = self.element_data.layout_item.computed_box.position;
+        let child_clip_bounds = self.element_data.layout_item.clip_bounds;
+
+        for (index, child) in self.element_data.children.iter_mut().enumerate() {
+            let taffy_child_node_id = child.internal.element_data().layout_item.taffy_node_id;
+            if taffy_child_node_id.is_none() {
+                continue;
+            }
+
+            if index == TOOLTIP_CONTENT_INDEX {
+                if is_visible {
+                    placement.finalize_popover(
+                        child.internal.as_mut(),
+                        taffy_tree,
+                        taffy_child_node_id.unwrap(),
+                        anchor,
+                        z_index,
+                        element_state,
+                        pointer,
+                        text_context,
+                    );
+                }
+                continue;
+            }
+
+            child.internal.finalize_layout(
+                taffy_tree,
+                taffy_child_node_id.unwrap(),
+                element_position,
+                z_index,
+                transform,
+                element_state,
+                pointer,
+                text_context,
+                child_clip_bounds,
+            );
+        }
+    }
+
+    fn resolve_clip(&mut self, clip_bounds: Option<Rectangle>) {
+        resolve_clip_for_scrollable(self, clip_bounds);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        should_style: bool,
+        event: &mut Event,
+    ) {
+        self.on_style_event(message, element_state, should_style, event);
+        self.maybe_unset_focus(message, event);
+
+        let base_state = self.get_base_state_mut(element_state);
+        let state = base_state.data.as_mut().downcast_mut::<TooltipState>().unwrap();
+
+        match message {
+            CraftMessage::PointerMovedEvent(_) => {
+                if state.hover_start.is_none() {
+                    state.hover_start = Some(Instant::now());
+                }
+            }
+            CraftMessage::PointerScroll(_) | CraftMessage::PointerButtonDown(_) => {
+                state.is_visible = false;
+                state.is_dismissed = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn initialize_state(&mut self, _scaling_factor: f64) -> ElementStateStoreItem {
+        ElementStateStoreItem {
+            base: Default::default(),
+            data: Box::new(TooltipState::default()),
+        }
+    }
+
+    #[cfg(feature = "accesskit")]
+    fn compute_accessibility_tree(
+        &mut self,
+        tree: &mut accesskit::TreeUpdate,
+        parent_index: Option<usize>,
+        element_state: &mut ElementStateStore,
+        scale_factor: f64,
+    ) {
+        let current_node_id = accesskit::NodeId(self.element_data().component_id);
+
+        let mut current_node = accesskit::Node::new(Role::GenericContainer);
+        if self.element_data().on_pointer_button_up.is_some() {
+            current_node.set_role(Role::Button);
+            current_node.add_action(Action::Click);
+        }
+        if let Some(description) = self.description(element_state) {
+            current_node.set_description(description);
+        }
+
+        let padding_box =
+            self.element_data().layout_item.computed_box_transformed.padding_rectangle().scale(scale_factor);
+
+        current_node.set_bounds(accesskit::Rect {
+            x0: padding_box.left() as f64,
+            y0: padding_box.top() as f64,
+            x1: padding_box.right() as f64,
+            y1: padding_box.bottom() as f64,
+        });
+
+        let current_index = tree.nodes.len();
+
+        if let Some(parent_index) = parent_index {
+            let parent_node = tree.nodes.get_mut(parent_index).unwrap();
+            parent_node.1.push_child(current_node_id);
+        }
+
+        tree.nodes.push((current_node_id, current_node));
+
+        // The tooltip content is exposed as a description instead of as a child.
+        for child in self.element_data_mut().children.iter_mut().skip(TOOLTIP_CONTENT_INDEX + 1) {
+            child.internal.compute_accessibility_tree(tree, Some(current_index), element_state, scale_factor);
+        }
+    }
+}
+
+impl Tooltip {
+    #[allow(dead_code)]
+    fn get_state<'a>(&self, element_state: &'a ElementStateStore) -> &'a TooltipState {
+        element_state.storage.get(&self.element_data.component_id).unwrap().data.as_ref().downcast_ref().unwrap()
+    }
+
+    /// Collects the text of the tooltip content, so that it can be used as an accessible description.
+    #[allow(dead_code)]
+    fn description(&self, element_state: &ElementStateStore) -> Option<String> {
+        let content = self.element_data.children.get(TOOLTIP_CONTENT_INDEX)?;
+
+        let description: Vec<&str> = content
+            .internal
+            .as_ref()
+            .pre_order_iter()
+            .filter_map(|element| {
+                element_state.storage.get(&element.component_id())?.data.downcast_ref::<TextState>()?.text()
+            })
+            .collect();
+
+        if description.is_empty() {
+            None
+        } else {
+            Some(description.join(" "))
+        }
+    }
+
+    /// Creates a tooltip that shows `content` when its children are hovered.
+    pub fn new(content: impl Into<ComponentSpecification>) -> Tooltip {
+        let mut tooltip = Tooltip {
+            element_data: Default::default(),
+            delay: Duration::from_millis(500),
+            placement: Placement::new(PlacementSide::Top, PlacementAlignment::Center).offset(6.0),
+        };
+        tooltip.element_data.child_specs.push(content.into());
+        tooltip
+    }
+
+    /// Sets how long the children need to be hovered before the tooltip is shown.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets where the tooltip is placed relative to the children.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    generate_component_methods_no_children!();
+
+    // The tooltip content is always the first child, so the push methods only touch the children after it.
+
+    #[allow(dead_code)]
+    pub fn push<T>(mut self, component_specification: T) -> Self
+    where
+        T: Into<ComponentSpecification>,
+    {
+        self.element_data.child_specs.push(component_specification.into());
+
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn push_children<T>(mut self, children: Vec<T>) -> Self
+    where
+        T: Into<ComponentSpecification>,
+    {
+        self.element_data.child_specs.truncate(TOOLTIP_CONTENT_INDEX + 1);
+        self.element_data.child_specs.extend(children.into_iter().map(|x| x.into()));
+
+        self
+    }
+
+    #[allow(dead_code)]
+    pub fn extend_children<T>(mut self, children: Vec<T>) -> Self
+    where
+        T: Into<ComponentSpecification>,
+    {
+        self.element_data.child_specs.extend(children.into_iter().map(|x| x.into()));
+
+        self
+    }
+    #[allow(dead_code)]
+    pub fn push_in_place(&mut self, component_specification: ComponentSpecification) {
+        self.element_data.child_specs.push(component_specification);
+    }
+}
+
+impl ElementStyles for Tooltip {
+    fn styles_mut(&mut self) -> &mut Style {
+        self.element_data.current_style_mut()
+    }
+}
@@ -11,13 +11,17 @@ use crate::resource_manager::ResourceManager;
 use crate::text::text_context::TextContext;
 use crate::window_context::WindowContext;
 use crate::{GlobalState, ReactiveTree};
-use craft_logging::{span, Level};
+use craft_logging::{span, warn, Level};
 use std::cell::RefCell;
 use std::collections::VecDeque;
 use std::rc::Rc;
 use std::sync::Arc;
 use winit::event::Ime;
 
+/// How many times elements can turn the messages of their children into new messages during a dispatch, which
+/// guards against elements that keep answering each other's messages.
+const MAX_ELEMENT_MESSAGE_DEPTH: usize = 16;
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn dispatch_event(
     message: &Message,
@@ -136,7 +140,9 @@ pub(crate) fn dispatch_event(
                 return;
             }
 
-            let mut element_events: VecDeque<(CraftMessage, &dyn Element)> = VecDeque::new();
+            // The messages that elements generated, the element that generated them, the index in `targets`
+            // of the first node that should receive them and how many elements turned them into new messages.
+            let mut element_events: VecDeque<(CraftMessage, &dyn Element, usize, usize)> = VecDeque::new();
 
             let target = targets[0].clone();
             let mut propagate = true;
@@ -169,10 +175,11 @@ pub(crate) fn dispatch_event(
                     event.target = Some(target.borrow().element.unwrap());
                     event.current_target = Some(current_target.borrow().element.unwrap());
                     (node.update)(state, global_state, node.props.clone(), &mut event, message);
+                    event.window.claim_toasts(node.id);
 
                     if !event.prevent_defaults && event.propagate {
                         if let Some(ref result_message) = event.result_message {
-                            element_events.push_back((result_message.clone(), *event.target.as_ref().unwrap()));
+                            element_events.push_back((result_message.clone(), *event.target.as_ref().unwrap(), 0, 0));
                         }
                     }
 
@@ -195,14 +202,12 @@ pub(crate) fn dispatch_event(
                         }
                     }
                     prevent_defaults = prevent_defaults || event.prevent_defaults;
-                    if event.future.is_some() {
-                        reactive_tree.update_queue.push_back(UpdateQueueEntry::new(
-                            node.id,
-                            node.update,
-                            event,
-                            node.props.clone(),
-                        ));
-                    }
+                    reactive_tree.update_queue.extend(UpdateQueueEntry::from_event(
+                        node.id,
+                        node.update,
+                        event,
+                        node.props.clone(),
+                    ));
                 }
             }
 
@@ -246,8 +251,12 @@ pub(crate) fn dispatch_event(
                             reactive_tree.element_state.update_element_focus(res.focus);
 
                             if let Some(result_message) = res.result_message {
-                                element_events.push_back((result_message, element));
+                                element_events.push_back((result_message, element, 0, 0));
                             }
+                            // Elements can send messages to components that are not their ancestors, like a toast
+                            // that sends its action to the component that pushed it. Direct dispatch already
+                            // handles these, so bubbling does too instead of dropping them.
+                            effects.append(&mut res.effects);
 
                             propagate = propagate && res.propagate;
                             prevent_defaults = prevent_defaults || res.prevent_defaults;
@@ -256,10 +265,11 @@ pub(crate) fn dispatch_event(
                 }
             }
 
-            for (message, target_element) in element_events.iter() {
+            while let Some((message, target_element, first_target, depth)) = element_events.pop_front() {
+                let message = &message;
                 let mut propagate = true;
                 let mut prevent_defaults = false;
-                for node in targets.iter() {
+                for (index, node) in targets.iter().enumerate().skip(first_target) {
                     let current_target = node.borrow();
 
                     if !propagate {
@@ -268,6 +278,7 @@ pub(crate) fn dispatch_event(
 
                     let mut event = Event::with_window_context(window_context.clone());
                     if let Some(element) = current_target.element {
+                        event.target = Some(target_element);
                         element.on_event(
                             message,
                             &mut reactive_tree.element_state,
@@ -278,13 +289,29 @@ pub(crate) fn dispatch_event(
                         );
                         focus = focus.merge(event.focus);
                         reactive_tree.element_state.update_element_focus(event.focus);
+
+                        // Elements may turn the messages of their children into their own messages, which are
+                        // sent to the ancestors of the element. The element doesn't receive its own message, so
+                        // an element that answers a message with the same message doesn't loop.
+                        if let Some(result_message) = event.result_message.take() {
+                            if depth < MAX_ELEMENT_MESSAGE_DEPTH {
+                                element_events.push_back((result_message, element, index + 1, depth + 1));
+                            } else {
+                                warn!(
+                                    "Dropped the message {:?} of a {} after {} nested element messages.",
+                                    result_message,
+                                    element.name(),
+                                    MAX_ELEMENT_MESSAGE_DEPTH
+                                );
+                            }
+                        }
                     } else {
                         let state =
                             reactive_tree.user_state.storage.get_mut(&current_target.component.id).unwrap().as_mut();
                         // For element events the target and current target
                         // are the element the event was dispatched from.
-                        event.target = Some(*target_element);
-                        event.current_target = Some(*target_element);
+                        event.target = Some(target_element);
+                        event.current_target = Some(target_element);
                         (current_target.component.update)(
                             state,
                             global_state,
@@ -292,19 +319,18 @@ pub(crate) fn dispatch_event(
                             &mut event,
                             &Message::CraftMessage(message.clone()),
                         );
+                        event.window.claim_toasts(current_target.component.id);
                     }
                     *window_context = event.window.clone();
                     effects.append(&mut event.effects);
                     propagate = propagate && event.propagate;
                     prevent_defaults = prevent_defaults || event.prevent_defaults;
-                    if event.future.is_some() {
-                        reactive_tree.update_queue.push_back(UpdateQueueEntry::new(
-                            current_target.component.id,
-                            current_target.component.update,
-                            event,
-                            current_target.component.props.clone(),
-                        ));
-                    }
+                    reactive_tree.update_queue.extend(UpdateQueueEntry::from_event(
+                        current_target.component.id,
+                        current_target.component.update,
+                        event,
+                        current_target.component.props.clone(),
+                    ));
                 }
             }
         }
@@ -335,16 +361,15 @@ pub(crate) fn dispatch_event(
                         event.current_target = None;
                         event.target = None;
                         (component.update)(state, global_state, component.props.clone(), &mut event, message);
+                        event.window.claim_toasts(component.id);
                         *window_context = event.window.clone();
                         effects.append(&mut event.effects);
-                        if event.future.is_some() {
-                            reactive_tree.update_queue.push_back(UpdateQueueEntry::new(
-                                component.id,
-                                component.update,
-                                event,
-                                component.props.clone(),
-                            ));
-                        }
+                        reactive_tree.update_queue.extend(UpdateQueueEntry::from_event(
+                            component.id,
+                            component.update,
+                            event,
+                            component.props.clone(),
+                        ));
 
                         break;
                     }
@@ -4,17 +4,19 @@ pub(crate) mod event_dispatch;
 pub(crate) mod internal;
 pub(crate) mod resource_event;
 pub mod update_queue_entry;
-//#[cfg(test)]
-//mod tests;
+#[cfg(test)]
+mod tests;
 
 pub use mouse_wheel::MouseWheel;
 pub use winit::event::ElementState;
 
 use crate::components::ComponentId;
 use crate::elements::Element;
+use crate::Color;
 use crate::events::CraftMessage::PointerButtonUp;
 use std::any::Any;
 use std::sync::Arc;
+pub use chrono;
 pub use ui_events;
 use ui_events::keyboard::KeyboardEvent;
 use ui_events::pointer::{PointerButtonUpdate, PointerScrollUpdate, PointerUpdate};
@@ -50,6 +52,29 @@ pub enum CraftMessage {
     /// Generated when a switch is toggled. The boolean is the status of toggled after the event has occurred.
     SwitchToggled(bool),
     SliderValueChanged(f64),
+    /// Generated when a dialog's close key is pressed or its backdrop is clicked.
+    /// The dialog stays open until it is removed from the view.
+    DialogCloseRequested,
+    /// Generated when a menu item is clicked or activated with the keyboard.
+    /// The string is the id of the item, or its label if the item has no id.
+    MenuItemActivated(String),
+    /// Generated when a split pane divider is dragged or reset.
+    /// The sizes are the size of every pane in pixels, in the order of the panes.
+    PaneResized(Vec<f32>),
+    /// Generated when the value of a number input is changed by typing or stepping.
+    /// The value is clamped to the input's range.
+    NumberChanged(f64),
+    /// Generated when a day is picked in a date picker.
+    DateChanged(chrono::NaiveDate),
+    /// Generated when an hour or a minute is picked in a time picker.
+    TimeChanged(chrono::NaiveTime),
+    /// Generated when a color is picked in a color picker.
+    ColorChanged(Color),
+    /// Generated when a link in a text is clicked. The string is the payload of the link.
+    LinkClicked(String),
+    /// Generated when a font loaded by a `Font` element is registered and can be used.
+    /// The strings are the families of the font, sent to the components that contain the element.
+    FontLoaded(Vec<String>),
     ElementMessage(Arc<UserMessage>),
 }
 
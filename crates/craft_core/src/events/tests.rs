@@ -0,0 +1,243 @@
+use crate::components::{Component, ComponentId, ComponentSpecification, Event, Props};
+use crate::craft_runtime::CraftRuntime;
+use crate::elements::element::ElementBoxed;
+use crate::elements::{Container, Element, ElementData};
+use crate::events::event_dispatch::dispatch_event;
+use crate::events::update_queue_entry::UpdateQueueEntry;
+use crate::events::{CraftMessage, EventDispatchType, Message};
+use crate::geometry::{Point, Rectangle};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_id::reset_unique_element_id;
+use crate::reactive::element_state_store::ElementStateStore;
+use crate::reactive::tree::diff_trees;
+use crate::renderer::renderer::RenderList;
+use crate::resource_manager::ResourceManager;
+use crate::text::text_context::TextContext;
+use crate::window_context::WindowContext;
+use crate::{GlobalState, ReactiveTree};
+use kurbo::Affine;
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use taffy::{NodeId, TaffyTree};
+use winit::window::Window;
+
+/// An element that passes every hit test and answers `Initialized` with an effect or a result message.
+#[derive(Clone, Default)]
+struct TestElement {
+    element_data: ElementData,
+    /// Adds a bubbling user message effect.
+    effect: bool,
+    /// Sends `SwitchToggled` to its ancestors, and sends it again whenever it receives it.
+    echo: bool,
+}
+
+impl Element for TestElement {
+    fn element_data(&self) -> &ElementData {
+        &self.element_data
+    }
+
+    fn element_data_mut(&mut self) -> &mut ElementData {
+        &mut self.element_data
+    }
+
+    fn in_bounds(&self, _point: Point) -> bool {
+        true
+    }
+
+    fn name(&self) -> &'static str {
+        "TestElement"
+    }
+
+    fn draw(
+        &mut self,
+        _renderer: &mut RenderList,
+        _text_context: &mut TextContext,
+        _element_state: &mut ElementStateStore,
+        _pointer: Option<Point>,
+        _window: Option<Arc<Window>>,
+        _scale_factor: f64,
+    ) {
+    }
+
+    fn compute_layout(
+        &mut self,
+        _taffy_tree: &mut TaffyTree<LayoutContext>,
+        _element_state: &mut ElementStateStore,
+        _scale_factor: f64,
+    ) -> Option<NodeId> {
+        None
+    }
+
+    fn finalize_layout(
+        &mut self,
+        _taffy_tree: &mut TaffyTree<LayoutContext>,
+        _root_node: NodeId,
+        _position: Point,
+        _z_index: &mut u32,
+        _transform: Affine,
+        _element_state: &mut ElementStateStore,
+        _pointer: Option<Point>,
+        _text_context: &mut TextContext,
+        _clip_bounds: Option<Rectangle>,
+    ) {
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn on_event(
+        &self,
+        message: &CraftMessage,
+        _element_state: &mut ElementStateStore,
+        _text_context: &mut TextContext,
+        _should_style: bool,
+        event: &mut Event,
+    ) {
+        match message {
+            CraftMessage::Initialized => {
+                if self.effect {
+                    event.add_effect(EventDispatchType::Bubbling, Message::UserMessage(Box::new(())));
+                }
+                if self.echo {
+                    event.result_message(CraftMessage::SwitchToggled(true));
+                }
+            }
+            CraftMessage::SwitchToggled(_) if self.echo => {
+                event.result_message(CraftMessage::SwitchToggled(true));
+            }
+            _ => {}
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct TestProps {
+    effect: bool,
+    echo: bool,
+}
+
+/// Counts the messages that it receives.
+#[derive(Default)]
+struct RecordingComponent {
+    user_messages: usize,
+    switch_toggles: usize,
+}
+
+impl Component for RecordingComponent {
+    type GlobalState = ();
+    type Props = TestProps;
+    type Message = ();
+
+    fn view(
+        &self,
+        _global_state: &Self::GlobalState,
+        props: &Self::Props,
+        _children: Vec<ComponentSpecification>,
+        _id: ComponentId,
+        _window: &WindowContext,
+    ) -> ComponentSpecification {
+        Container::new().component().push(TestElement {
+            effect: props.effect,
+            echo: props.echo,
+            ..Default::default()
+        })
+    }
+
+    fn update(
+        &mut self,
+        _global_state: &mut Self::GlobalState,
+        _props: &Self::Props,
+        _event: &mut Event,
+        message: &Message,
+    ) {
+        match message {
+            Message::CraftMessage(CraftMessage::SwitchToggled(_)) => self.switch_toggles += 1,
+            Message::UserMessage(_) => self.user_messages += 1,
+            _ => {}
+        }
+    }
+}
+
+/// Dispatches `Initialized` to the element of a `RecordingComponent` and returns the number of user messages and
+/// `SwitchToggled` messages it received.
+fn dispatch_initialized(props: TestProps) -> (usize, usize) {
+    let mut text_context = TextContext::new();
+    reset_unique_element_id();
+
+    let root_component = RecordingComponent::component().props(Props::new(props));
+    let root_element: ElementBoxed = Container::new().into();
+
+    let mut reactive_tree = ReactiveTree::default();
+    let mut global_state = GlobalState::from(Box::new(()) as Box<dyn Any + Send>);
+    let mut window_context = WindowContext::new();
+    let mut update_queue: VecDeque<UpdateQueueEntry> = VecDeque::new();
+
+    let tree = diff_trees(
+        root_component,
+        Vec::new(),
+        root_element,
+        None,
+        &mut reactive_tree.user_state,
+        &mut global_state,
+        &mut reactive_tree.element_state,
+        false,
+        &mut text_context,
+        1.0,
+        &mut window_context,
+        &mut update_queue,
+    );
+
+    reactive_tree.component_tree = Some(tree.component_tree);
+    reactive_tree.element_tree = Some(tree.element_tree.internal);
+    reactive_tree.element_ids = tree.element_ids;
+    reactive_tree.component_ids = tree.component_ids;
+
+    let runtime = CraftRuntime::new();
+    let (app_sender, _app_receiver) = tokio::sync::mpsc::channel(1);
+    let mut resource_manager = Arc::new(ResourceManager::new(app_sender, runtime.handle()));
+    let mut text_context = Some(text_context);
+
+    // The containers are not laid out, so only the test element passes the hit test away from the origin.
+    dispatch_event(
+        &Message::CraftMessage(CraftMessage::Initialized),
+        EventDispatchType::Bubbling,
+        &mut resource_manager,
+        Some(Point::new(10.0, 10.0)),
+        &mut reactive_tree,
+        &mut global_state,
+        &mut text_context,
+        &mut window_context,
+        false,
+    );
+
+    let state = reactive_tree
+        .user_state
+        .storage
+        .values()
+        .find_map(|state| state.as_ref().downcast_ref::<RecordingComponent>())
+        .unwrap();
+    (state.user_messages, state.switch_toggles)
+}
+
+#[test]
+fn bubbling_dispatch_element_effects_are_dispatched() {
+    let (user_messages, _) = dispatch_initialized(TestProps {
+        effect: true,
+        echo: false,
+    });
+
+    assert_ne!(user_messages, 0, "The effects of elements should be dispatched after bubbling.");
+}
+
+#[test]
+fn bubbling_dispatch_element_answering_its_own_message_does_not_loop() {
+    let (_, switch_toggles) = dispatch_initialized(TestProps {
+        effect: false,
+        echo: true,
+    });
+
+    // The message of the element and its answer to it, which the element doesn't receive again.
+    assert_eq!(switch_toggles, 2, "An element should not receive its own result message.");
+}
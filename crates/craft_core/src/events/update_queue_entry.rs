@@ -19,4 +19,25 @@ impl UpdateQueueEntry {
             props,
         }
     }
+
+    /// The entries for the future of `event` and for each of its clipboard reads.
+    pub(crate) fn from_event(
+        source_component: ComponentId,
+        update_function: UpdateFn,
+        mut event: Event,
+        props: Props,
+    ) -> Vec<Self> {
+        event
+            .future
+            .take()
+            .into_iter()
+            .chain(std::mem::take(&mut event.clipboard_reads))
+            .map(|future| UpdateQueueEntry {
+                source_component,
+                update_function,
+                update_result: Some(future),
+                props: props.clone(),
+            })
+            .collect()
+    }
 }
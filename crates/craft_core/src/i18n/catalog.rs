@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+use std::fmt;
+
+/// The messages of one locale.
+///
+/// Catalogs are written as one `key = message` pair per line, and lines starting with `#` are comments. A message
+/// continues on the next line when its line ends with `\`, and `\n` is a line break in the message.
+///
+/// `{name}` in a message is replaced by the argument `name`, and `{{` and `}}` are literal braces. Plural forms are
+/// separate keys ending in their [`PluralCategory`](super::PluralCategory), like `inbox.one` and `inbox.other`,
+/// which are chosen by the `count` argument.
+#[derive(Clone, Debug, Default)]
+pub struct Catalog {
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn parse(source: &str) -> Result<Self, CatalogError> {
+        let mut messages = HashMap::new();
+        let mut lines = source.lines().enumerate();
+
+        while let Some((index, line)) = lines.next() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((key, message)) = line.split_once('=') else {
+                return Err(CatalogError::new(index, format!("expected `key = message`, found `{line}`")));
+            };
+            let key = key.trim();
+            if key.is_empty() {
+                return Err(CatalogError::new(index, "missing key before `=`".to_string()));
+            }
+
+            let mut message = message.trim().to_string();
+            while message.ends_with('\\') && !message.ends_with("\\\\") {
+                message.pop();
+                match lines.next() {
+                    Some((_, next)) => message.push_str(next.trim()),
+                    None => break,
+                }
+            }
+
+            messages.insert(key.to_string(), unescape(&message));
+        }
+
+        Ok(Self { messages })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, key: &str, message: &str) {
+        self.messages.insert(key.to_string(), message.to_string());
+    }
+
+    /// Adds the messages of `other`, replacing the messages with the same keys.
+    pub fn extend(&mut self, other: Catalog) {
+        self.messages.extend(other.messages);
+    }
+}
+
+fn unescape(message: &str) -> String {
+    let mut unescaped = String::with_capacity(message.len());
+    let mut chars = message.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('\\') => unescaped.push('\\'),
+            Some(other) => {
+                unescaped.push('\\');
+                unescaped.push(other);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// A line of a catalog that could not be parsed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatalogError {
+    /// The line number, starting at 1.
+    pub line: usize,
+    pub message: String,
+}
+
+impl CatalogError {
+    fn new(index: usize, message: String) -> Self {
+        Self {
+            line: index + 1,
+            message,
+        }
+    }
+}
+
+impl fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for CatalogError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Catalog, CatalogError};
+
+    #[test]
+    fn parses_messages_and_skips_comments() {
+        let catalog = Catalog::parse("# Greetings\n\nhello = Hello, {name}!\n  inbox.one=One message  \n").unwrap();
+        assert_eq!(catalog.get("hello"), Some("Hello, {name}!"));
+        assert_eq!(catalog.get("inbox.one"), Some("One message"));
+        assert_eq!(catalog.get("# Greetings"), None);
+    }
+
+    #[test]
+    fn joins_continuation_lines() {
+        let catalog = Catalog::parse("long = first \\\n    second \\\n  third\nnext = after").unwrap();
+        assert_eq!(catalog.get("long"), Some("first second third"));
+        assert_eq!(catalog.get("next"), Some("after"));
+    }
+
+    #[test]
+    fn escaped_backslash_does_not_continue_the_message() {
+        let catalog = Catalog::parse("path = C:\\\\\nnext = after").unwrap();
+        assert_eq!(catalog.get("path"), Some("C:\\"));
+        assert_eq!(catalog.get("next"), Some("after"));
+    }
+
+    #[test]
+    fn unescapes_line_breaks() {
+        let catalog = Catalog::parse("lines = one\\ntwo\nother = a\\tb").unwrap();
+        assert_eq!(catalog.get("lines"), Some("one\ntwo"));
+        // Unknown escapes are kept as they are.
+        assert_eq!(catalog.get("other"), Some("a\\tb"));
+    }
+
+    #[test]
+    fn reports_the_line_of_an_error() {
+        let error = Catalog::parse("ok = fine\n\nbroken line").unwrap_err();
+        assert_eq!(error.line, 3);
+
+        let error = Catalog::parse(" = no key").unwrap_err();
+        assert_eq!(
+            error,
+            CatalogError {
+                line: 1,
+                message: "missing key before `=`".to_string(),
+            }
+        );
+    }
+}
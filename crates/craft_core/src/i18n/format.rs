@@ -0,0 +1,147 @@
+use chrono::{NaiveDate, NaiveTime};
+
+/// How long a date formatted by [`Localization::format_date`](super::Localization::format_date) is.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DateStyle {
+    /// Only digits, like `12/31/2025` in American English.
+    #[default]
+    Short,
+    /// With the name of the month, like `December 31, 2025` in American English.
+    Long,
+}
+
+/// The decimal separator and the separator between groups of thousands.
+fn separators(language: &str) -> (char, char) {
+    match language {
+        "de" | "nl" | "it" | "es" | "pt" | "id" | "da" | "tr" | "el" | "ro" | "hr" | "sl" | "sr" => (',', '.'),
+        "fr" => (',', '\u{202f}'),
+        "ru" | "uk" | "be" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "no" | "bg" | "hu" | "et" | "lv" | "lt" => {
+            (',', '\u{a0}')
+        }
+        _ => ('.', ','),
+    }
+}
+
+pub(crate) fn format_number(language: &str, value: f64, fraction_digits: usize) -> String {
+    let (decimal, group) = separators(language);
+    let formatted = format!("{:.*}", fraction_digits, value.abs());
+    let (integer, fraction) = match formatted.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (formatted.as_str(), None),
+    };
+
+    let mut result = String::with_capacity(formatted.len() + integer.len() / 3 + 1);
+    if value < 0.0 && formatted.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        result.push('-');
+    }
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            result.push(group);
+        }
+        result.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        result.push(decimal);
+        result.push_str(fraction);
+    }
+    result
+}
+
+/// Whether the locale writes the month before the day, like American English.
+fn is_us(language: &str, region: Option<&str>) -> bool {
+    language == "en" && matches!(region, None | Some("US"))
+}
+
+fn date_pattern(language: &str, region: Option<&str>, style: DateStyle) -> &'static str {
+    match style {
+        DateStyle::Short => match language {
+            _ if is_us(language, region) => "%m/%d/%Y",
+            "en" | "fr" | "es" | "it" | "pt" | "el" | "id" | "vi" | "he" | "ar" => "%d/%m/%Y",
+            "de" | "ru" | "uk" | "be" | "pl" | "cs" | "sk" | "fi" | "nb" | "no" | "da" | "tr" | "ro" | "bg" => {
+                "%d.%m.%Y"
+            }
+            "nl" => "%d-%m-%Y",
+            "ja" | "zh" => "%Y/%m/%d",
+            "ko" | "hu" => "%Y. %m. %d.",
+            _ => "%Y-%m-%d",
+        },
+        DateStyle::Long => match language {
+            _ if is_us(language, region) => "%B %-d, %Y",
+            "de" | "da" | "nb" | "no" => "%-d. %B %Y",
+            "es" | "pt" => "%-d de %B de %Y",
+            "ja" | "zh" => "%Y年%-m月%-d日",
+            "ko" => "%Y년 %-m월 %-d일",
+            _ => "%-d %B %Y",
+        },
+    }
+}
+
+/// Formats `date`, with `month_name` as the name of the month if it's translated.
+pub(crate) fn format_date(
+    language: &str,
+    region: Option<&str>,
+    date: NaiveDate,
+    style: DateStyle,
+    month_name: Option<&str>,
+) -> String {
+    let pattern = date_pattern(language, region, style);
+    match month_name {
+        Some(month_name) => date.format(&pattern.replace("%B", &month_name.replace('%', "%%"))).to_string(),
+        None => date.format(pattern).to_string(),
+    }
+}
+
+pub(crate) fn format_time(language: &str, region: Option<&str>, time: NaiveTime) -> String {
+    let pattern = if is_us(language, region) { "%-I:%M %p" } else { "%H:%M" };
+    time.format(pattern).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_date, format_number, format_time, DateStyle};
+    use chrono::{NaiveDate, NaiveTime};
+
+    #[test]
+    fn numbers_use_the_separators_of_the_language() {
+        assert_eq!(format_number("en", 1_234_567.891, 2), "1,234,567.89");
+        assert_eq!(format_number("de", 1_234_567.891, 2), "1.234.567,89");
+        assert_eq!(format_number("fr", 1_234.5, 1), "1\u{202f}234,5");
+        assert_eq!(format_number("ru", 1_234.0, 0), "1\u{a0}234");
+        assert_eq!(format_number("en", 999.0, 0), "999");
+    }
+
+    #[test]
+    fn negative_numbers() {
+        assert_eq!(format_number("en", -1_234.0, 0), "-1,234");
+        // A number that rounds to zero has no sign.
+        assert_eq!(format_number("en", -0.001, 2), "0.00");
+    }
+
+    #[test]
+    fn short_dates_follow_the_order_of_the_locale() {
+        let date = NaiveDate::from_ymd_opt(2025, 12, 31).unwrap();
+        assert_eq!(format_date("en", None, date, DateStyle::Short, None), "12/31/2025");
+        assert_eq!(format_date("en", Some("GB"), date, DateStyle::Short, None), "31/12/2025");
+        assert_eq!(format_date("de", Some("DE"), date, DateStyle::Short, None), "31.12.2025");
+        assert_eq!(format_date("ja", None, date, DateStyle::Short, None), "2025/12/31");
+        assert_eq!(format_date("xx", None, date, DateStyle::Short, None), "2025-12-31");
+    }
+
+    #[test]
+    fn long_dates_use_the_month_name() {
+        let date = NaiveDate::from_ymd_opt(2025, 3, 5).unwrap();
+        assert_eq!(format_date("en", Some("US"), date, DateStyle::Long, None), "March 5, 2025");
+        assert_eq!(format_date("de", None, date, DateStyle::Long, Some("März")), "5. März 2025");
+        assert_eq!(format_date("es", None, date, DateStyle::Long, Some("marzo")), "5 de marzo de 2025");
+        // The month name is not a format pattern.
+        assert_eq!(format_date("fr", None, date, DateStyle::Long, Some("%Y")), "5 %Y 2025");
+    }
+
+    #[test]
+    fn times_use_the_clock_of_the_locale() {
+        let time = NaiveTime::from_hms_opt(13, 5, 0).unwrap();
+        assert_eq!(format_time("en", Some("US"), time), "1:05 PM");
+        assert_eq!(format_time("en", Some("GB"), time), "13:05");
+        assert_eq!(format_time("de", None, time), "13:05");
+    }
+}
@@ -0,0 +1,331 @@
+//! Translated messages and locale-aware formatting.
+//!
+//! Messages are looked up by key in the [`Catalog`] of the current locale, through the
+//! [`Localization`] of the [`WindowContext`](crate::WindowContext). Catalogs are added directly or loaded from a
+//! [`ResourceIdentifier`](crate::resource_manager::ResourceIdentifier) with
+//! [`WindowContext::load_catalog`](crate::WindowContext::load_catalog). Switching the locale with
+//! [`WindowContext::set_locale`](crate::WindowContext::set_locale) renders the view again in the new locale.
+
+mod catalog;
+mod format;
+mod plural;
+
+pub use catalog::{Catalog, CatalogError};
+pub use format::DateStyle;
+pub use plural::PluralCategory;
+
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use std::collections::HashMap;
+
+/// The value of a `{name}` placeholder in a message.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MessageArg {
+    String(String),
+    /// A number, which is formatted for the locale and selects the plural form when it's the `count` argument.
+    Number(f64),
+}
+
+impl From<&str> for MessageArg {
+    fn from(value: &str) -> Self {
+        MessageArg::String(value.to_string())
+    }
+}
+
+impl From<String> for MessageArg {
+    fn from(value: String) -> Self {
+        MessageArg::String(value)
+    }
+}
+
+macro_rules! impl_number_arg {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for MessageArg {
+                fn from(value: $t) -> Self {
+                    MessageArg::Number(value as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_arg!(f32, i32, i64, u32, u64, usize);
+
+impl From<f64> for MessageArg {
+    fn from(value: f64) -> Self {
+        MessageArg::Number(value)
+    }
+}
+
+/// The messages of each locale, and the locale they are shown in.
+#[derive(Clone, Debug)]
+pub struct Localization {
+    locale: String,
+    fallback_locale: Option<String>,
+    catalogs: HashMap<String, Catalog>,
+}
+
+impl Default for Localization {
+    /// Uses the locale of the system, or `en-US` if it's unknown.
+    fn default() -> Self {
+        Self::new(&system_locale())
+    }
+}
+
+impl Localization {
+    pub fn new(locale: &str) -> Self {
+        Self {
+            locale: normalize_locale(locale),
+            fallback_locale: None,
+            catalogs: HashMap::new(),
+        }
+    }
+
+    /// The current locale, like `en-US`.
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    pub fn set_locale(&mut self, locale: &str) {
+        self.locale = normalize_locale(locale);
+    }
+
+    /// Set the locale whose messages are used when a message is missing from the current locale.
+    pub fn set_fallback_locale(&mut self, locale: Option<&str>) {
+        self.fallback_locale = locale.map(normalize_locale);
+    }
+
+    /// Adds the messages of `catalog` to `locale`, replacing the messages with the same keys.
+    ///
+    /// A catalog for a language, like `de`, is used for all regions of it, like `de-DE` and `de-CH`.
+    pub fn add_catalog(&mut self, locale: &str, catalog: Catalog) {
+        self.catalogs.entry(normalize_locale(locale)).or_default().extend(catalog);
+    }
+
+    /// The message of `key` in the current locale, or the key itself if there is no such message.
+    pub fn message(&self, key: &str) -> String {
+        self.format(key, &[])
+    }
+
+    /// The message of `key` with its `{name}` placeholders replaced by `args`.
+    ///
+    /// When there is a `count` argument, the plural form of the message for it is used.
+    pub fn format(&self, key: &str, args: &[(&str, MessageArg)]) -> String {
+        let count = args.iter().find_map(|(name, arg)| match arg {
+            MessageArg::Number(count) if *name == "count" => Some(*count),
+            _ => None,
+        });
+
+        match self.lookup(key, count) {
+            Some(message) => self.interpolate(message, args),
+            None => key.to_string(),
+        }
+    }
+
+    /// The plural category of `n` in the current locale.
+    pub fn plural_category(&self, n: f64) -> PluralCategory {
+        plural::category(language(&self.locale), n)
+    }
+
+    /// Formats `value` with the decimal and thousands separators of the current locale.
+    pub fn format_number(&self, value: f64, fraction_digits: usize) -> String {
+        format::format_number(language(&self.locale), value, fraction_digits)
+    }
+
+    /// Formats `date` in the order and with the separators of the current locale.
+    ///
+    /// [`DateStyle::Long`] uses the month names of the catalog, with the keys `month.1` to `month.12`, and the
+    /// English month names if they are missing.
+    pub fn format_date(&self, date: NaiveDate, style: DateStyle) -> String {
+        let month_name = self.lookup(&format!("month.{}", date.month()), None);
+        format::format_date(language(&self.locale), region(&self.locale), date, style, month_name)
+    }
+
+    /// Formats `time` with the 12 or 24 hour clock of the current locale.
+    pub fn format_time(&self, time: NaiveTime) -> String {
+        format::format_time(language(&self.locale), region(&self.locale), time)
+    }
+
+    /// Finds the message of `key` in the current locale, its language, or the fallback locale.
+    fn lookup(&self, key: &str, count: Option<f64>) -> Option<&str> {
+        let fallback = self.fallback_locale.as_deref();
+        let locales = [Some(self.locale.as_str()), Some(language(&self.locale)), fallback, fallback.map(language)];
+
+        for locale in locales.into_iter().flatten() {
+            let Some(catalog) = self.catalogs.get(locale) else {
+                continue;
+            };
+            if let Some(count) = count {
+                let category = plural::category(language(locale), count);
+                let plural = catalog
+                    .get(&format!("{key}.{}", category.as_str()))
+                    .or_else(|| catalog.get(&format!("{key}.other")));
+                if plural.is_some() {
+                    return plural;
+                }
+            }
+            if let Some(message) = catalog.get(key) {
+                return Some(message);
+            }
+        }
+        None
+    }
+
+    fn interpolate(&self, message: &str, args: &[(&str, MessageArg)]) -> String {
+        let mut result = String::with_capacity(message.len());
+        let mut rest = message;
+
+        while let Some(index) = rest.find(['{', '}']) {
+            result.push_str(&rest[..index]);
+            let brace = &rest[index..index + 1];
+            rest = &rest[index + 1..];
+
+            if let Some(after) = rest.strip_prefix(brace) {
+                result.push_str(brace);
+                rest = after;
+                continue;
+            }
+
+            let argument = rest.find('}').filter(|_| brace == "{").and_then(|end| {
+                let name = rest[..end].trim();
+                args.iter().find(|(arg_name, _)| *arg_name == name).map(|(_, arg)| (end, arg))
+            });
+            match argument {
+                Some((end, MessageArg::String(value))) => {
+                    result.push_str(value);
+                    rest = &rest[end + 1..];
+                }
+                Some((end, MessageArg::Number(value))) => {
+                    result.push_str(&self.format_number(*value, fraction_digits(*value)));
+                    rest = &rest[end + 1..];
+                }
+                // Unknown placeholders are kept as they are.
+                None => result.push_str(brace),
+            }
+        }
+
+        result.push_str(rest);
+        result
+    }
+}
+
+/// The number of fraction digits needed to show `value`, up to 6.
+fn fraction_digits(value: f64) -> usize {
+    let formatted = format!("{:.6}", value.fract().abs());
+    formatted.trim_end_matches('0').split_once('.').map_or(0, |(_, fraction)| fraction.len())
+}
+
+/// The language of a locale, like `de` for `de-CH`.
+fn language(locale: &str) -> &str {
+    locale.split('-').next().unwrap_or(locale)
+}
+
+/// The region of a locale, like `CH` for `de-CH`.
+fn region(locale: &str) -> Option<&str> {
+    locale.split('-').skip(1).find(|part| part.len() == 2 || part.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Turns a locale like `de_DE.UTF-8` into `de-DE`.
+fn normalize_locale(locale: &str) -> String {
+    let locale = locale.split(['.', '@']).next().unwrap_or_default().trim();
+    if locale.is_empty() || locale == "C" || locale == "POSIX" {
+        return "en-US".to_string();
+    }
+
+    locale
+        .split(['-', '_'])
+        .enumerate()
+        .map(|(index, part)| match (index, part.len()) {
+            (0, _) => part.to_ascii_lowercase(),
+            (_, 2) => part.to_ascii_uppercase(),
+            _ => part.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn system_locale() -> String {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .find_map(|variable| std::env::var(variable).ok().filter(|value| !value.is_empty()))
+            .map(|locale| normalize_locale(&locale))
+            .unwrap_or_else(|| "en-US".to_string())
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        "en-US".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Catalog, Localization, MessageArg};
+
+    fn localization(locale: &str, catalog: &str) -> Localization {
+        let mut localization = Localization::new(locale);
+        localization.add_catalog(locale, Catalog::parse(catalog).unwrap());
+        localization
+    }
+
+    #[test]
+    fn interpolates_arguments() {
+        let localization = localization("en-US", "greeting = Hello, { name }! You are {age}.");
+        let message = localization.format("greeting", &[("name", "Ada".into()), ("age", 36.into())]);
+        assert_eq!(message, "Hello, Ada! You are 36.");
+    }
+
+    #[test]
+    fn doubled_braces_are_literal() {
+        let localization = localization("en-US", "code = {{name}} is {name}, }} and {{");
+        assert_eq!(localization.format("code", &[("name", "Ada".into())]), "{name} is Ada, } and {");
+    }
+
+    #[test]
+    fn unknown_placeholders_are_kept() {
+        let localization = localization("en-US", "greeting = Hello, {name}! {unclosed");
+        assert_eq!(localization.format("greeting", &[]), "Hello, {name}! {unclosed");
+        assert_eq!(localization.format("greeting", &[("other", "Ada".into())]), "Hello, {name}! {unclosed");
+    }
+
+    #[test]
+    fn numbers_are_formatted_for_the_locale() {
+        let localization = localization("de-DE", "total = {amount} €");
+        let message = localization.format("total", &[("amount", MessageArg::Number(1234.5))]);
+        assert_eq!(message, "1.234,5 €");
+    }
+
+    #[test]
+    fn count_selects_the_plural_form() {
+        let localization = localization("en-US", "inbox.one = {count} message\ninbox.other = {count} messages");
+        assert_eq!(localization.format("inbox", &[("count", 1.into())]), "1 message");
+        assert_eq!(localization.format("inbox", &[("count", 3.into())]), "3 messages");
+    }
+
+    #[test]
+    fn missing_plural_forms_fall_back_to_other() {
+        let localization = localization("ru", "files.one = {count} файл\nfiles.other = {count} файлов");
+        // 5 is `many` in Russian, which the catalog doesn't have.
+        assert_eq!(localization.format("files", &[("count", 5.into())]), "5 файлов");
+        assert_eq!(localization.format("files", &[("count", 21.into())]), "21 файл");
+    }
+
+    #[test]
+    fn messages_without_plural_forms_ignore_the_count() {
+        let localization = localization("en-US", "unread = {count} unread");
+        assert_eq!(localization.format("unread", &[("count", 2.into())]), "2 unread");
+    }
+
+    #[test]
+    fn missing_messages_fall_back_to_the_language_then_the_fallback_locale() {
+        let mut localization = localization("de", "save = Speichern");
+        localization.add_catalog("en", Catalog::parse("save = Save\nquit = Quit").unwrap());
+        localization.set_locale("de-CH");
+        localization.set_fallback_locale(Some("en-US"));
+
+        assert_eq!(localization.message("save"), "Speichern");
+        assert_eq!(localization.message("quit"), "Quit");
+        assert_eq!(localization.message("missing"), "missing");
+    }
+}
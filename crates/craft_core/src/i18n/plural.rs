@@ -0,0 +1,232 @@
+/// The plural form of a message, following the CLDR plural rules of the locale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// The suffix of the catalog key of this plural form, like `one` in `inbox.one`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+/// The cardinal plural category of `n` in `language`.
+///
+/// Languages without rules here use the English ones.
+pub(crate) fn category(language: &str, n: f64) -> PluralCategory {
+    let n = n.abs();
+    // The integer digits, and whether there are visible fraction digits.
+    let i = n.trunc() as u64;
+    let integer = n.fract() == 0.0;
+    // The visible fraction digits as an integer, like 25 for 1.25.
+    let f = visible_fraction(n);
+
+    match language {
+        "ja" | "zh" | "ko" | "th" | "vi" | "id" | "ms" => PluralCategory::Other,
+        "fr" | "pt" => {
+            if i <= 1 {
+                PluralCategory::One
+            } else if integer && i != 0 && i % 1_000_000 == 0 {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        "ru" | "uk" | "be" => {
+            if !integer {
+                PluralCategory::Other
+            } else if i % 10 == 1 && i % 100 != 11 {
+                PluralCategory::One
+            } else if (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Many
+            }
+        }
+        "sr" | "hr" | "bs" => {
+            if (integer && i % 10 == 1 && i % 100 != 11) || (f % 10 == 1 && f % 100 != 11) {
+                PluralCategory::One
+            } else if (integer && (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)))
+                || ((2..=4).contains(&(f % 10)) && !(12..=14).contains(&(f % 100)))
+            {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        "pl" => {
+            if !integer {
+                PluralCategory::Other
+            } else if i == 1 {
+                PluralCategory::One
+            } else if (2..=4).contains(&(i % 10)) && !(12..=14).contains(&(i % 100)) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Many
+            }
+        }
+        "cs" | "sk" => {
+            if !integer {
+                PluralCategory::Many
+            } else if i == 1 {
+                PluralCategory::One
+            } else if (2..=4).contains(&i) {
+                PluralCategory::Few
+            } else {
+                PluralCategory::Other
+            }
+        }
+        "ar" => {
+            if !integer {
+                PluralCategory::Other
+            } else if i == 0 {
+                PluralCategory::Zero
+            } else if i == 1 {
+                PluralCategory::One
+            } else if i == 2 {
+                PluralCategory::Two
+            } else if (3..=10).contains(&(i % 100)) {
+                PluralCategory::Few
+            } else if (11..=99).contains(&(i % 100)) {
+                PluralCategory::Many
+            } else {
+                PluralCategory::Other
+            }
+        }
+        "he" => {
+            if integer && i == 1 {
+                PluralCategory::One
+            } else if integer && i == 2 {
+                PluralCategory::Two
+            } else {
+                PluralCategory::Other
+            }
+        }
+        _ => {
+            if integer && i == 1 {
+                PluralCategory::One
+            } else {
+                PluralCategory::Other
+            }
+        }
+    }
+}
+
+/// The fraction digits of `n` as an integer, up to 6 digits and without trailing zeros.
+fn visible_fraction(n: f64) -> u64 {
+    let formatted = format!("{:.6}", n.fract());
+    let fraction = formatted.split_once('.').map_or("", |(_, fraction)| fraction).trim_end_matches('0');
+    fraction.parse().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::category;
+    use super::PluralCategory::{Few, Many, One, Other, Two, Zero};
+
+    #[test]
+    fn english() {
+        assert_eq!(category("en", 1.0), One);
+        assert_eq!(category("en", 0.0), Other);
+        assert_eq!(category("en", 2.0), Other);
+        assert_eq!(category("en", 1.5), Other);
+        assert_eq!(category("en", -1.0), One);
+    }
+
+    #[test]
+    fn turkish() {
+        assert_eq!(category("tr", 1.0), One);
+        assert_eq!(category("tr", 0.0), Other);
+        assert_eq!(category("tr", 5.0), Other);
+    }
+
+    #[test]
+    fn japanese() {
+        assert_eq!(category("ja", 1.0), Other);
+        assert_eq!(category("ja", 2.0), Other);
+    }
+
+    #[test]
+    fn french() {
+        assert_eq!(category("fr", 0.0), One);
+        assert_eq!(category("fr", 1.5), One);
+        assert_eq!(category("fr", 2.0), Other);
+        assert_eq!(category("fr", 1_000_000.0), Many);
+    }
+
+    #[test]
+    fn russian() {
+        assert_eq!(category("ru", 1.0), One);
+        assert_eq!(category("ru", 21.0), One);
+        assert_eq!(category("ru", 11.0), Many);
+        assert_eq!(category("ru", 3.0), Few);
+        assert_eq!(category("ru", 13.0), Many);
+        assert_eq!(category("ru", 5.0), Many);
+        assert_eq!(category("ru", 1.5), Other);
+    }
+
+    #[test]
+    fn serbian_croatian_and_bosnian() {
+        for language in ["sr", "hr", "bs"] {
+            assert_eq!(category(language, 1.0), One, "{language}");
+            assert_eq!(category(language, 21.0), One, "{language}");
+            assert_eq!(category(language, 11.0), Other, "{language}");
+            assert_eq!(category(language, 2.0), Few, "{language}");
+            assert_eq!(category(language, 24.0), Few, "{language}");
+            assert_eq!(category(language, 12.0), Other, "{language}");
+            assert_eq!(category(language, 5.0), Other, "{language}");
+            assert_eq!(category(language, 0.1), One, "{language}");
+            assert_eq!(category(language, 1.21), One, "{language}");
+            assert_eq!(category(language, 0.2), Few, "{language}");
+            assert_eq!(category(language, 0.5), Other, "{language}");
+            assert_eq!(category(language, 0.11), Other, "{language}");
+        }
+    }
+
+    #[test]
+    fn polish() {
+        assert_eq!(category("pl", 1.0), One);
+        assert_eq!(category("pl", 21.0), Many);
+        assert_eq!(category("pl", 22.0), Few);
+        assert_eq!(category("pl", 12.0), Many);
+        assert_eq!(category("pl", 1.5), Other);
+    }
+
+    #[test]
+    fn czech() {
+        assert_eq!(category("cs", 1.0), One);
+        assert_eq!(category("cs", 3.0), Few);
+        assert_eq!(category("cs", 5.0), Other);
+        assert_eq!(category("cs", 1.5), Many);
+    }
+
+    #[test]
+    fn arabic() {
+        assert_eq!(category("ar", 0.0), Zero);
+        assert_eq!(category("ar", 1.0), One);
+        assert_eq!(category("ar", 2.0), Two);
+        assert_eq!(category("ar", 103.0), Few);
+        assert_eq!(category("ar", 111.0), Many);
+        assert_eq!(category("ar", 100.0), Other);
+    }
+
+    #[test]
+    fn hebrew() {
+        assert_eq!(category("he", 1.0), One);
+        assert_eq!(category("he", 2.0), Two);
+        assert_eq!(category("he", 3.0), Other);
+    }
+}
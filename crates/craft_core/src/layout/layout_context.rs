@@ -53,7 +53,7 @@ impl MetricsRaw {
     pub(crate) fn from(style: &Style, scaling_factor: f64) -> Self {
         Self {
             font_size: (style.font_size() * scaling_factor as f32).to_bits(),
-            line_height: (style.font_size() * 1.2 * scaling_factor as f32).to_bits(),
+            line_height: (style.font_size() * style.line_height() * scaling_factor as f32).to_bits(),
             scaling_factor: scaling_factor.to_bits(),
         }
     }
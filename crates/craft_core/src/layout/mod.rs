@@ -1,2 +1,3 @@
 pub mod layout_context;
 pub mod layout_item;
+pub mod popover;
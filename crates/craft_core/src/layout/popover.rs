@@ -0,0 +1,333 @@
+use crate::elements::Element;
+use crate::geometry::{Point, PointConverter, Rectangle, Size};
+use crate::layout::layout_context::LayoutContext;
+use crate::reactive::element_state_store::ElementStateStore;
+use crate::style::{Style, Unit};
+use crate::text::text_context::TextContext;
+use kurbo::Affine;
+use taffy::{NodeId, Position, TaffyTree};
+
+/// The side of the anchor that a popover is placed on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PlacementSide {
+    Top,
+    Right,
+    #[default]
+    Bottom,
+    Left,
+}
+
+impl PlacementSide {
+    fn opposite(&self) -> PlacementSide {
+        match self {
+            PlacementSide::Top => PlacementSide::Bottom,
+            PlacementSide::Right => PlacementSide::Left,
+            PlacementSide::Bottom => PlacementSide::Top,
+            PlacementSide::Left => PlacementSide::Right,
+        }
+    }
+
+    fn is_vertical(&self) -> bool {
+        matches!(self, PlacementSide::Top | PlacementSide::Bottom)
+    }
+}
+
+/// How a popover is aligned with the anchor along the side it is placed on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PlacementAlignment {
+    /// Align the left edges (or top edges for the left and right sides).
+    #[default]
+    Start,
+    Center,
+    /// Align the right edges (or bottom edges for the left and right sides).
+    End,
+}
+
+/// Describes where an overlay should be placed relative to the element it is anchored to.
+///
+/// Tooltips, dropdowns and menus share this, so that they all flip and shift the same way
+/// when there is not enough room in the window.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Placement {
+    pub side: PlacementSide,
+    pub alignment: PlacementAlignment,
+    /// The gap between the anchor and the popover in logical pixels.
+    pub offset: f32,
+    /// Place the popover on the opposite side when it does not fit on the preferred side.
+    pub flip: bool,
+    /// Move the popover along the anchor's side, so that it stays inside the window.
+    pub shift: bool,
+    /// Make the popover at least as wide as the anchor. Only applies to the top and bottom sides.
+    pub match_anchor_width: bool,
+}
+
+impl Default for Placement {
+    fn default() -> Self {
+        Self {
+            side: PlacementSide::Bottom,
+            alignment: PlacementAlignment::Start,
+            offset: 0.0,
+            flip: true,
+            shift: true,
+            match_anchor_width: false,
+        }
+    }
+}
+
+impl Placement {
+    pub fn new(side: PlacementSide, alignment: PlacementAlignment) -> Self {
+        Self {
+            side,
+            alignment,
+            ..Default::default()
+        }
+    }
+
+    pub fn offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn flip(mut self, flip: bool) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    pub fn shift(mut self, shift: bool) -> Self {
+        self.shift = shift;
+        self
+    }
+
+    pub fn match_anchor_width(mut self, match_anchor_width: bool) -> Self {
+        self.match_anchor_width = match_anchor_width;
+        self
+    }
+
+    /// Takes the popover out of the normal flow, so that it can be placed with [`Placement::finalize_popover`].
+    ///
+    /// When matching the anchor width, the popover's node must be a child of the anchor's node in the layout tree.
+    pub(crate) fn apply_to_style(&self, style: &mut Style) {
+        *style.position_mut() = Position::Absolute;
+        if self.match_anchor_width && self.side.is_vertical() {
+            *style.min_width_mut() = Unit::Percentage(100.0);
+        }
+    }
+
+    /// Computes the border rectangle of a popover of the given size.
+    ///
+    /// All rectangles are in logical window coordinates.
+    pub fn resolve(&self, anchor: Rectangle, size: Size<f32>, viewport: Rectangle) -> Rectangle {
+        let mut width = size.width;
+        if self.match_anchor_width && self.side.is_vertical() {
+            width = width.max(anchor.width);
+        }
+        let size = Size::new(width, size.height);
+
+        let mut side = self.side;
+        if self.flip && !self.fits(side, anchor, size, viewport) {
+            let opposite = side.opposite();
+            if self.fits(opposite, anchor, size, viewport)
+                || self.available_space(opposite, anchor, viewport) > self.available_space(side, anchor, viewport)
+            {
+                side = opposite;
+            }
+        }
+
+        let (mut x, mut y) = match side {
+            PlacementSide::Top => (self.align(anchor.x, anchor.width, width), anchor.top() - self.offset - size.height),
+            PlacementSide::Bottom => (self.align(anchor.x, anchor.width, width), anchor.bottom() + self.offset),
+            PlacementSide::Left => (anchor.left() - self.offset - width, self.align(anchor.y, anchor.height, size.height)),
+            PlacementSide::Right => (anchor.right() + self.offset, self.align(anchor.y, anchor.height, size.height)),
+        };
+
+        if self.shift {
+            if side.is_vertical() {
+                x = x.min(viewport.right() - width).max(viewport.left());
+            } else {
+                y = y.min(viewport.bottom() - size.height).max(viewport.top());
+            }
+        }
+
+        Rectangle::new(x, y, width, size.height)
+    }
+
+    /// Lays out a popover element next to the anchor rectangle and returns where it was placed.
+    ///
+    /// The placement is computed in window space, so the popover is finalized without its parent's scroll transform.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn finalize_popover(
+        &self,
+        popover: &mut dyn Element,
+        taffy_tree: &mut TaffyTree<LayoutContext>,
+        popover_node: NodeId,
+        anchor: Rectangle,
+        z_index: &mut u32,
+        element_state: &mut ElementStateStore,
+        pointer: Option<Point>,
+        text_context: &mut TextContext,
+    ) -> Rectangle {
+        let layout = taffy_tree.layout(popover_node).unwrap();
+        // Absolute elements are sized by their content, see `LayoutItem::resolve_box`.
+        let size = Size::new(
+            f32::max(layout.size.width, layout.content_size.width),
+            f32::max(layout.size.height, layout.content_size.height),
+        );
+        let location = Point::from_taffy_point(layout.location);
+
+        let placed = self.resolve(anchor, size, element_state.viewport);
+        let position = Point::new(placed.x as f64 - location.x, placed.y as f64 - location.y);
+
+        popover.finalize_layout(
+            taffy_tree,
+            popover_node,
+            position,
+            z_index,
+            Affine::IDENTITY,
+            element_state,
+            pointer,
+            text_context,
+            None,
+        );
+
+        placed
+    }
+
+    fn align(&self, anchor_start: f32, anchor_length: f32, length: f32) -> f32 {
+        match self.alignment {
+            PlacementAlignment::Start => anchor_start,
+            PlacementAlignment::Center => anchor_start + (anchor_length - length) / 2.0,
+            PlacementAlignment::End => anchor_start + anchor_length - length,
+        }
+    }
+
+    fn available_space(&self, side: PlacementSide, anchor: Rectangle, viewport: Rectangle) -> f32 {
+        match side {
+            PlacementSide::Top => anchor.top() - viewport.top(),
+            PlacementSide::Right => viewport.right() - anchor.right(),
+            PlacementSide::Bottom => viewport.bottom() - anchor.bottom(),
+            PlacementSide::Left => anchor.left() - viewport.left(),
+        }
+    }
+
+    fn fits(&self, side: PlacementSide, anchor: Rectangle, size: Size<f32>, viewport: Rectangle) -> bool {
+        let needed = if side.is_vertical() { size.height } else { size.width };
+        self.available_space(side, anchor, viewport) >= needed + self.offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Placement, PlacementAlignment, PlacementSide};
+    use crate::geometry::{Rectangle, Size};
+
+    const VIEWPORT: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 400.0,
+        height: 300.0,
+    };
+
+    /// Places a 100x50 popover with a gap of 4 and returns its `(x, y, width, height)`.
+    fn resolve(placement: Placement, anchor: Rectangle) -> (f32, f32, f32, f32) {
+        resolve_size(placement, anchor, Size::new(100.0, 50.0))
+    }
+
+    fn resolve_size(placement: Placement, anchor: Rectangle, size: Size<f32>) -> (f32, f32, f32, f32) {
+        let placed = placement.offset(4.0).resolve(anchor, size, VIEWPORT);
+        (placed.x, placed.y, placed.width, placed.height)
+    }
+
+    fn placement(side: PlacementSide) -> Placement {
+        Placement::new(side, PlacementAlignment::Start)
+    }
+
+    #[test]
+    fn placed_on_the_preferred_side_when_it_fits() {
+        let anchor = Rectangle::new(150.0, 100.0, 80.0, 20.0);
+        assert_eq!(resolve(placement(PlacementSide::Bottom), anchor), (150.0, 124.0, 100.0, 50.0));
+        assert_eq!(resolve(placement(PlacementSide::Top), anchor), (150.0, 46.0, 100.0, 50.0));
+        assert_eq!(resolve(placement(PlacementSide::Right), anchor), (234.0, 100.0, 100.0, 50.0));
+        assert_eq!(resolve(placement(PlacementSide::Left), anchor), (46.0, 100.0, 100.0, 50.0));
+    }
+
+    #[test]
+    fn flips_at_the_bottom_edge() {
+        let anchor = Rectangle::new(10.0, 270.0, 80.0, 20.0);
+        assert_eq!(resolve(placement(PlacementSide::Bottom), anchor), (10.0, 216.0, 100.0, 50.0));
+    }
+
+    #[test]
+    fn flips_at_the_top_edge() {
+        let anchor = Rectangle::new(10.0, 5.0, 80.0, 20.0);
+        assert_eq!(resolve(placement(PlacementSide::Top), anchor), (10.0, 29.0, 100.0, 50.0));
+    }
+
+    #[test]
+    fn flips_at_the_right_edge() {
+        let anchor = Rectangle::new(350.0, 100.0, 40.0, 20.0);
+        assert_eq!(resolve(placement(PlacementSide::Right), anchor), (246.0, 100.0, 100.0, 50.0));
+    }
+
+    #[test]
+    fn flips_at_the_left_edge() {
+        let anchor = Rectangle::new(5.0, 100.0, 40.0, 20.0);
+        assert_eq!(resolve(placement(PlacementSide::Left), anchor), (49.0, 100.0, 100.0, 50.0));
+    }
+
+    #[test]
+    fn stays_on_the_preferred_side_without_flip() {
+        let anchor = Rectangle::new(10.0, 270.0, 80.0, 20.0);
+        let placement = placement(PlacementSide::Bottom).flip(false);
+        assert_eq!(resolve(placement, anchor), (10.0, 294.0, 100.0, 50.0));
+    }
+
+    #[test]
+    fn flips_to_the_side_with_more_space_when_neither_fits() {
+        let size = Size::new(100.0, 200.0);
+
+        // 130 above and 150 below.
+        let anchor = Rectangle::new(10.0, 130.0, 80.0, 20.0);
+        assert_eq!(resolve_size(placement(PlacementSide::Bottom), anchor, size), (10.0, 154.0, 100.0, 200.0));
+
+        // 170 above and 110 below, so it overflows the top, as it is only shifted along the anchor's side.
+        let anchor = Rectangle::new(10.0, 170.0, 80.0, 20.0);
+        assert_eq!(resolve_size(placement(PlacementSide::Bottom), anchor, size), (10.0, -34.0, 100.0, 200.0));
+    }
+
+    #[test]
+    fn shifts_inside_the_viewport() {
+        let anchor = Rectangle::new(350.0, 100.0, 40.0, 20.0);
+        assert_eq!(resolve(placement(PlacementSide::Bottom), anchor), (300.0, 124.0, 100.0, 50.0));
+
+        let anchor = Rectangle::new(10.0, 100.0, 20.0, 20.0);
+        let end = Placement::new(PlacementSide::Bottom, PlacementAlignment::End);
+        assert_eq!(resolve(end, anchor), (0.0, 124.0, 100.0, 50.0));
+
+        let anchor = Rectangle::new(100.0, 280.0, 40.0, 20.0);
+        assert_eq!(resolve(placement(PlacementSide::Right), anchor), (144.0, 250.0, 100.0, 50.0));
+    }
+
+    #[test]
+    fn shifts_to_the_start_when_both_sides_overflow() {
+        let anchor = Rectangle::new(150.0, 100.0, 40.0, 20.0);
+        let centered = Placement::new(PlacementSide::Bottom, PlacementAlignment::Center);
+        assert_eq!(resolve_size(centered, anchor, Size::new(500.0, 50.0)), (0.0, 124.0, 500.0, 50.0));
+
+        let centered = Placement::new(PlacementSide::Right, PlacementAlignment::Center);
+        assert_eq!(resolve_size(centered, anchor, Size::new(100.0, 400.0)), (194.0, 0.0, 100.0, 400.0));
+    }
+
+    #[test]
+    fn does_not_shift_without_shift() {
+        let anchor = Rectangle::new(350.0, 100.0, 40.0, 20.0);
+        let placement = placement(PlacementSide::Bottom).shift(false);
+        assert_eq!(resolve(placement, anchor), (350.0, 124.0, 100.0, 50.0));
+    }
+
+    #[test]
+    fn matches_the_anchor_width() {
+        let anchor = Rectangle::new(10.0, 100.0, 160.0, 20.0);
+        let placement = placement(PlacementSide::Bottom).match_anchor_width(true);
+        assert_eq!(resolve(placement, anchor), (10.0, 124.0, 160.0, 50.0));
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(feature = "accesskit")]
 pub mod accessibility;
 pub mod components;
+pub mod clipboard;
 pub mod craft_runtime;
 mod craft_winit_state;
 pub mod elements;
@@ -17,13 +18,14 @@ mod app;
 #[cfg(feature = "dev_tools")]
 pub(crate) mod devtools;
 pub mod geometry;
+pub mod i18n;
 pub mod layout;
 pub mod resource_manager;
 mod view_introspection;
 mod window_context;
 
 pub use craft_runtime::CraftRuntime;
-pub use options::CraftOptions;
+pub use options::{CraftOptions, FallbackScript, FontOptions, GenericFamily};
 pub use renderer::color::palette;
 pub use renderer::color::Color;
 
@@ -219,11 +221,15 @@ fn craft_main_with_options_2(
         global_state,
         window: None,
         text_context: None,
+        font_options: craft_options.fonts.clone(),
         renderer: None,
         window_context: WindowContext::new(),
+        toasts: Default::default(),
+        catalog_requests: Default::default(),
         resource_manager,
         resources_collected: Default::default(),
         reload_fonts: false,
+        animation_frame_requested: false,
         user_tree: ReactiveTree {
             element_tree: None,
             component_tree: None,
@@ -234,6 +240,8 @@ fn craft_main_with_options_2(
             user_state,
             element_state: Default::default(),
             focus: None,
+            modal_focus_stack: Vec::new(),
+            text_selection: Default::default(),
         },
 
         #[cfg(feature = "dev_tools")]
@@ -250,6 +258,8 @@ fn craft_main_with_options_2(
             component_ids: Default::default(),
             pointer_captures: Default::default(),
             focus: None,
+            modal_focus_stack: Vec::new(),
+            text_selection: Default::default(),
         },
         runtime: runtime_copy,
         modifiers: Default::default(),
@@ -1,4 +1,5 @@
 use crate::geometry::Size;
+use std::borrow::Cow;
 use std::fmt::{Display, Formatter};
 
 /// Configuration options for the Craft application.
@@ -18,6 +19,8 @@ pub struct CraftOptions {
     pub window_title: String,
     /// The initial size of the window.
     pub window_size: Option<Size<f32>>,
+    /// The fonts that text is rendered with.
+    pub fonts: FontOptions,
 }
 
 impl Default for CraftOptions {
@@ -26,6 +29,84 @@ impl Default for CraftOptions {
             renderer: RendererType::default(),
             window_title: "craft".to_string(),
             window_size: None,
+            fonts: FontOptions::default(),
+        }
+    }
+}
+
+/// Configures the fonts that are available to text.
+///
+/// Bundling fonts and turning off the system fonts makes text render the same on every machine.
+#[derive(Clone, Debug)]
+pub struct FontOptions {
+    /// Whether the fonts installed on the system are used. They are only available with the `system_fonts` feature.
+    ///
+    /// Without system fonts, the embedded Roboto font is used for the system UI family.
+    ///
+    /// Defaults to `true`.
+    pub system_fonts: bool,
+    /// The font files that are registered when the application starts.
+    pub bundled_fonts: Vec<Cow<'static, [u8]>>,
+    /// The family of text without a font family, which is used before the system UI fonts.
+    pub default_family: Option<String>,
+    /// The families that a generic family is mapped to, in order of preference.
+    pub generic_families: Vec<(GenericFamily, Vec<String>)>,
+    /// The families that are used for the characters of a script that are missing from the font of the text,
+    /// in order of preference.
+    pub fallback_families: Vec<(FallbackScript, Vec<String>)>,
+}
+
+impl Default for FontOptions {
+    fn default() -> Self {
+        Self {
+            system_fonts: true,
+            bundled_fonts: Vec::new(),
+            default_family: None,
+            generic_families: Vec::new(),
+            fallback_families: Vec::new(),
+        }
+    }
+}
+
+/// A family that stands for the fonts of a kind, like the monospace fonts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GenericFamily {
+    SansSerif,
+    Serif,
+    Monospace,
+    SystemUi,
+    Emoji,
+}
+
+impl GenericFamily {
+    pub(crate) fn to_parley(self) -> parley::GenericFamily {
+        match self {
+            GenericFamily::SansSerif => parley::GenericFamily::SansSerif,
+            GenericFamily::Serif => parley::GenericFamily::Serif,
+            GenericFamily::Monospace => parley::GenericFamily::Monospace,
+            GenericFamily::SystemUi => parley::GenericFamily::SystemUi,
+            GenericFamily::Emoji => parley::GenericFamily::Emoji,
+        }
+    }
+}
+
+/// The scripts that fallback families can be set for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FallbackScript {
+    /// Chinese, Japanese and Korean.
+    Cjk,
+    Emoji,
+    /// A script by its ISO 15924 tag, like `*b"Arab"`.
+    Script([u8; 4]),
+}
+
+impl FallbackScript {
+    /// The ISO 15924 tags of the scripts.
+    pub(crate) fn tags(self) -> Vec<[u8; 4]> {
+        match self {
+            FallbackScript::Cjk => vec![*b"Hani", *b"Hira", *b"Kana", *b"Hang", *b"Bopo"],
+            FallbackScript::Emoji => vec![*b"Zsye"],
+            FallbackScript::Script(tag) => vec![tag],
         }
     }
 }
@@ -1,7 +1,9 @@
 use crate::components::{ComponentId, FocusAction};
 use crate::elements::base_element_state::BaseElementState;
+use crate::geometry::Rectangle;
 use std::any::Any;
 use std::collections::{HashMap, HashSet};
+use winit::window::Cursor;
 
 #[derive(Debug)]
 pub struct ElementStateStoreItem {
@@ -12,6 +14,13 @@ pub struct ElementStateStoreItem {
 #[derive(Default)]
 pub struct ElementStateStore {
     pub storage: HashMap<ComponentId, ElementStateStoreItem>,
+    /// The area of the window that the tree is laid out in. Popovers are kept inside of it.
+    pub(crate) viewport: Rectangle,
+    /// Set while drawing by elements that animate, so that the window is drawn again without running the view.
+    pub(crate) animation_frame_requested: bool,
+    /// Set while drawing by elements that change the cursor, it is shown unless another cursor is requested through
+    /// the window context.
+    pub(crate) requested_cursor: Option<Cursor>,
 }
 
 impl ElementStateStore {
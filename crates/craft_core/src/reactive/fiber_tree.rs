@@ -1,5 +1,5 @@
 use crate::elements::element::Element;
-use crate::elements::{Dropdown, Overlay};
+use crate::elements::{DatePicker, Dialog, Dropdown, Menu, Overlay, TimePicker};
 use crate::reactive::tree::ComponentTreeNode;
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -42,7 +42,13 @@ pub fn new<'a>(root_component: &'a ComponentTreeNode, root_element: &'a dyn Elem
         // push its children so the two stacks stay aligned.
         let element = if component.is_element {
             let element = element_stack.pop().expect("component / element stacks out of sync");
-            if element.as_any().is::<Overlay>() || element.as_any().is::<Dropdown>() {
+            if element.as_any().is::<Overlay>()
+                || element.as_any().is::<Dropdown>()
+                || element.as_any().is::<Dialog>()
+                || element.as_any().is::<Menu>()
+                || element.as_any().is::<DatePicker>()
+                || element.as_any().is::<TimePicker>()
+            {
                 overlay_order += 1;
             }
             for &child_element in element.children().iter().rev() {
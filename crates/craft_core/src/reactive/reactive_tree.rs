@@ -1,9 +1,13 @@
 use crate::components::{ComponentId, Event, FocusAction};
-use crate::elements::Element;
+use crate::elements::date_picker::DatePickerState;
+use crate::elements::menu::MenuState;
+use crate::elements::time_picker::TimePickerState;
+use crate::elements::{Dialog, Element};
 use crate::events::update_queue_entry::UpdateQueueEntry;
 use crate::reactive::element_state_store::ElementStateStore;
 use crate::reactive::state_store::StateStore;
 use crate::reactive::tree::ComponentTreeNode;
+use crate::text::text_selection::TextSelection;
 use std::collections::{HashMap, HashSet, VecDeque};
 
 #[derive(Default)]
@@ -18,6 +22,10 @@ pub(crate) struct ReactiveTree {
     pub(crate) user_state: StateStore,
     pub(crate) element_state: ElementStateStore,
     pub(crate) focus: Option<ComponentId>,
+    /// The mounted dialogs from bottom to top, along with the element that was focused before each dialog opened.
+    pub(crate) modal_focus_stack: Vec<(ComponentId, Option<ComponentId>)>,
+    /// The selection that is dragged across the texts of the tree.
+    pub(crate) text_selection: TextSelection,
 }
 
 impl ReactiveTree {
@@ -32,4 +40,74 @@ impl ReactiveTree {
             }
         }
     }
+
+    /// Whether Escape is handled by the view, because a dialog is mounted or the focused menu or picker is open.
+    pub(crate) fn captures_escape(&self) -> bool {
+        let is_open = |id: &ComponentId| {
+            self.element_state.storage.get(id).is_some_and(|item| {
+                item.data.downcast_ref::<MenuState>().is_some_and(|state| state.is_open())
+                    || item.data.downcast_ref::<DatePickerState>().is_some_and(|state| state.is_open())
+                    || item.data.downcast_ref::<TimePickerState>().is_some_and(|state| state.is_open())
+            })
+        };
+        // The text field of a picker is focused instead of the picker itself.
+        let is_popup_open = self.focus.is_some_and(|focus| {
+            is_open(&focus)
+                || self.element_tree.as_ref().is_some_and(|element_tree| {
+                    element_tree.pre_order_iter().any(|element| {
+                        element.children().first().is_some_and(|field| field.component_id() == focus)
+                            && is_open(&element.component_id())
+                    })
+                })
+        });
+
+        !self.modal_focus_stack.is_empty() || is_popup_open
+    }
+
+    /// Moves the focus into newly mounted dialogs and restores the previous focus when a dialog is unmounted.
+    pub(crate) fn update_modal_focus(&mut self) {
+        let dialogs: Vec<ComponentId> = if let Some(element_tree) = self.element_tree.as_ref() {
+            element_tree
+                .pre_order_iter()
+                .filter(|element| element.as_any().is::<Dialog>())
+                .map(|element| element.component_id())
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut index = self.modal_focus_stack.len();
+        while index > 0 {
+            index -= 1;
+            let (dialog_id, previous_focus) = self.modal_focus_stack[index];
+            if dialogs.contains(&dialog_id) {
+                continue;
+            }
+
+            self.modal_focus_stack.remove(index);
+            if index < self.modal_focus_stack.len() {
+                // A dialog underneath the top was closed, so the dialog above it inherits its previous focus.
+                self.modal_focus_stack[index].1 = previous_focus;
+                continue;
+            }
+
+            let focus = match previous_focus {
+                Some(id) if self.element_ids.contains(&id) => FocusAction::Set(id),
+                _ => FocusAction::Unset,
+            };
+            self.element_state.update_element_focus(focus);
+            self.update_focus(focus);
+        }
+
+        for dialog_id in dialogs {
+            if self.modal_focus_stack.iter().any(|(id, _)| *id == dialog_id) {
+                continue;
+            }
+
+            self.modal_focus_stack.push((dialog_id, self.focus));
+            let focus = FocusAction::Set(dialog_id);
+            self.element_state.update_element_focus(focus);
+            self.update_focus(focus);
+        }
+    }
 }
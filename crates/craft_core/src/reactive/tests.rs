@@ -32,6 +32,7 @@ fn diff_trees_same_tag_same_id_are_equal() {
 
     let initial_tree = diff_trees(
         initial_view,
+        Vec::new(),
         root_element.clone(),
         None,
         &mut user_state,
@@ -46,6 +47,7 @@ fn diff_trees_same_tag_same_id_are_equal() {
 
     let updated_tree = diff_trees(
         updated_view,
+        Vec::new(),
         root_element.clone(),
         Some(&initial_tree.component_tree),
         &mut user_state,
@@ -82,6 +84,7 @@ fn diff_trees_after_one_iteration_adjacent_nodes_different_ids() {
     let mut window_context = WindowContext::new();
     let tree_1 = diff_trees(
         root_node_1,
+        Vec::new(),
         root_element.clone(),
         None,
         &mut user_state,
@@ -96,6 +99,7 @@ fn diff_trees_after_one_iteration_adjacent_nodes_different_ids() {
 
     let tree_2 = diff_trees(
         root_node_2,
+        Vec::new(),
         root_element.clone(),
         Some(&tree_1.component_tree),
         &mut user_state,
@@ -130,6 +134,7 @@ fn remove_unused_element_state_after_removal_is_state_deleted() {
 
     let tree_1 = diff_trees(
         root_component_1,
+        Vec::new(),
         root_element.clone(),
         None,
         &mut reactive_tree.user_state,
@@ -153,6 +158,7 @@ fn remove_unused_element_state_after_removal_is_state_deleted() {
 
     let tree_2 = diff_trees(
         root_component_2,
+        Vec::new(),
         root_element.clone(),
         Some(reactive_tree.component_tree.as_ref().unwrap()),
         &mut reactive_tree.user_state,
@@ -215,6 +221,7 @@ fn remove_unused_component_state_after_removal_is_state_deleted() {
 
     let tree_1 = diff_trees(
         root_component_1,
+        Vec::new(),
         root_element.clone(),
         None,
         &mut reactive_tree.user_state,
@@ -238,6 +245,7 @@ fn remove_unused_component_state_after_removal_is_state_deleted() {
 
     let tree_2 = diff_trees(
         root_component_2,
+        Vec::new(),
         root_element.clone(),
         Some(reactive_tree.component_tree.as_ref().unwrap()),
         &mut reactive_tree.user_state,
@@ -281,6 +289,7 @@ fn diff_trees_after_one_iteration_same_key_different_position_same_id() {
 
     let tree_1 = diff_trees(
         root_node_1,
+        Vec::new(),
         root_element.clone(),
         None,
         &mut user_state,
@@ -295,6 +304,7 @@ fn diff_trees_after_one_iteration_same_key_different_position_same_id() {
 
     let tree_2 = diff_trees(
         root_node_2,
+        Vec::new(),
         root_element.clone(),
         Some(&tree_1.component_tree),
         &mut user_state,
@@ -329,6 +339,7 @@ fn diff_trees_after_one_iteration_same_position_different_component_keys_differe
     let mut update_queue: VecDeque<UpdateQueueEntry> = VecDeque::new();
     let tree_1 = diff_trees(
         root_node_1,
+        Vec::new(),
         root_element.clone(),
         None,
         &mut user_state,
@@ -343,6 +354,7 @@ fn diff_trees_after_one_iteration_same_position_different_component_keys_differe
 
     let tree_2 = diff_trees(
         root_node_2,
+        Vec::new(),
         root_element.clone(),
         Some(&tree_1.component_tree),
         &mut user_state,
@@ -381,6 +393,7 @@ fn diff_trees_after_one_iteration_same_position_different_components_different_c
 
     let tree_1 = diff_trees(
         root_node_1,
+        Vec::new(),
         root_element.clone(),
         None,
         &mut user_state,
@@ -395,6 +408,7 @@ fn diff_trees_after_one_iteration_same_position_different_components_different_c
 
     let tree_2 = diff_trees(
         root_node_2,
+        Vec::new(),
         root_element.clone(),
         Some(&tree_1.component_tree),
         &mut user_state,
@@ -81,8 +81,12 @@ pub struct DiffTreesResult {
 #[allow(clippy::too_many_arguments)]
 /// Creates a new Component tree and Element tree from a ComponentSpecification.
 /// The ids of the Component tree are stable across renders.
+///
+/// The `overlays` are mounted in the root element after the tree of `component_specification`. They can come and
+/// go without moving the tree, so it keeps its ids and state.
 pub(crate) fn diff_trees(
     component_specification: ComponentSpecification,
+    overlays: Vec<ComponentSpecification>,
     mut root_element: ElementBoxed,
     old_component_tree: Option<&ComponentTreeNode>,
     user_state: &mut StateStore,
@@ -116,14 +120,11 @@ pub(crate) fn diff_trees(
             },
         );
 
-        let mut old_component_tree_as_ptr = old_component_tree.map(|old_root| old_root as *const ComponentTreeNode);
-
-        // HACK: This is a workaround to get the first child of the old component tree because we start at the first level on the new tree.
-        // This is because the root of the component tree is not a component, but a dummy node.
-        if old_component_tree_as_ptr.is_some() {
-            old_component_tree_as_ptr =
-                Some((*old_component_tree_as_ptr.unwrap()).children.first().unwrap() as *const ComponentTreeNode);
-        }
+        // The root of the component tree is not a component, but a dummy node, so the new tree starts with the
+        // children of the old root.
+        let old_roots: Vec<*const ComponentTreeNode> = old_component_tree
+            .map(|old_root| old_root.children.iter().map(|child| child as *const ComponentTreeNode).collect())
+            .unwrap_or_default();
 
         let component_root: *mut ComponentTreeNode = &mut component_tree as *mut ComponentTreeNode;
 
@@ -131,12 +132,19 @@ pub(crate) fn diff_trees(
         let mut new_element_ids: HashSet<ComponentId> = HashSet::new();
         let mut pointer_captures: HashMap<i64, ComponentId> = HashMap::new();
 
-        let mut to_visit: Vec<TreeVisitorNode> = vec![TreeVisitorNode {
-            component_specification,
-            parent_element_ptr: root_element.internal.as_mut() as *mut dyn Element,
-            parent_component_node: component_root,
-            old_component_node: old_component_tree_as_ptr,
-        }];
+        let root_element_ptr = root_element.internal.as_mut() as *mut dyn Element;
+        let mut to_visit: Vec<TreeVisitorNode> = std::iter::once(component_specification)
+            .chain(overlays)
+            .enumerate()
+            .map(|(index, component_specification)| TreeVisitorNode {
+                component_specification,
+                parent_element_ptr: root_element_ptr,
+                parent_component_node: component_root,
+                old_component_node: old_roots.get(index).copied(),
+            })
+            .collect();
+        // The tree is visited depth first, so the overlays are mounted after the whole tree.
+        to_visit.reverse();
 
         while let Some(tree_node) = to_visit.pop() {
             let old_tag = tree_node.old_component_node.map(|old_node| (*old_node).tag.as_str());
@@ -290,16 +298,15 @@ pub(crate) fn diff_trees(
                             &mut event,
                             &Message::CraftMessage(CraftMessage::Initialized),
                         );
+                        event.window.claim_toasts(id);
                         *window_context = event.window.clone();
                         // TODO: Should we handle effects here?
-                        if event.future.is_some() {
-                            update_queue.push_back(UpdateQueueEntry::new(
-                                id,
-                                component_data.update_fn,
-                                event,
-                                props.clone(),
-                            ));
-                        }
+                        update_queue.extend(UpdateQueueEntry::from_event(
+                            id,
+                            component_data.update_fn,
+                            event,
+                            props.clone(),
+                        ));
                     }
 
                     let state = user_state.storage.get(&id);
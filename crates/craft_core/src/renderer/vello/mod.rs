@@ -237,17 +237,16 @@ impl Renderer for VelloRenderer<'_> {
 
                     cull_and_process(&mut |line: &TextRenderLine| {
                         for item in &line.items {
-                            
-                            if let Some(underline) = &item.underline {
+                            for decoration in item.underline.iter().chain(&item.overline) {
                                 scene.stroke(
-                                    &Stroke::new(underline.width.into()),
+                                    &Stroke::new(decoration.width.into()),
                                     text_transform,
-                                    underline.brush.color,
+                                    decoration.brush.color,
                                     None,
-                                    &underline.line,
+                                    &decoration.to_path(),
                                 );
                             }
-                            
+
                             scene
                                 .draw_glyphs(&item.font)
                                 .font_size(item.font_size)
@@ -264,6 +263,16 @@ impl Renderer for VelloRenderer<'_> {
                                         y: glyph.y,
                                     }),
                                 );
+
+                            if let Some(strikethrough) = &item.strikethrough {
+                                scene.stroke(
+                                    &Stroke::new(strikethrough.width.into()),
+                                    text_transform,
+                                    strikethrough.brush.color,
+                                    None,
+                                    &strikethrough.to_path(),
+                                );
+                            }
                         }
                     });
                     
@@ -232,13 +232,13 @@ impl Renderer for VelloCpuRenderer {
 
                     cull_and_process(&mut |line: &TextRenderLine| {
                         for item in &line.items {
-                            if let Some(underline) = &item.underline {
+                            for decoration in item.underline.iter().chain(&item.overline) {
                                 self.render_context.set_transform(text_transform);
-                                self.render_context.set_stroke(Stroke::new(underline.width.into()));
-                                self.render_context.set_paint(PaintType::from(underline.brush.color));
-                                self.render_context.stroke_path(&underline.line.to_path(0.1));
+                                self.render_context.set_stroke(Stroke::new(decoration.width.into()));
+                                self.render_context.set_paint(PaintType::from(decoration.brush.color));
+                                self.render_context.stroke_path(&decoration.to_path());
                             }
-                            
+
                             self.render_context.set_paint(PaintType::from(
                                 text_render.override_brush.map(|b| b.color).unwrap_or_else(|| item.brush.color),
                             ));
@@ -254,6 +254,14 @@ impl Renderer for VelloCpuRenderer {
                                 x: glyph.x,
                                 y: glyph.y,
                             }));
+
+                            if let Some(strikethrough) = &item.strikethrough {
+                                self.render_context.set_transform(text_transform);
+                                self.render_context.set_stroke(Stroke::new(strikethrough.width.into()));
+                                self.render_context.set_paint(PaintType::from(strikethrough.brush.color));
+                                self.render_context.stroke_path(&strikethrough.to_path());
+                                self.render_context.reset_transform();
+                            }
                         }
                     });
                     
@@ -235,11 +235,11 @@ impl CraftRenderer for VelloHybridRenderer<'_> {
 
                     cull_and_process(&mut |line: &TextRenderLine| {
                         for item in &line.items {
-                            if let Some(underline) = &item.underline {
+                            for decoration in item.underline.iter().chain(&item.overline) {
                                 scene.set_transform(text_transform);
-                                scene.set_stroke(Stroke::new(underline.width.into()));
-                                scene.set_paint(Paint::from(underline.brush.color));
-                                scene.stroke_path(&underline.line.to_path(0.1));
+                                scene.set_stroke(Stroke::new(decoration.width.into()));
+                                scene.set_paint(Paint::from(decoration.brush.color));
+                                scene.stroke_path(&decoration.to_path());
                             }
 
                             scene.set_paint(Paint::from(
@@ -254,6 +254,14 @@ impl CraftRenderer for VelloHybridRenderer<'_> {
                                 x: glyph.x,
                                 y: glyph.y,
                             }));
+
+                            if let Some(strikethrough) = &item.strikethrough {
+                                scene.set_transform(text_transform);
+                                scene.set_stroke(Stroke::new(strikethrough.width.into()));
+                                scene.set_paint(Paint::from(strikethrough.brush.color));
+                                scene.stroke_path(&strikethrough.to_path());
+                                scene.reset_transform();
+                            }
                         }
                     });
 
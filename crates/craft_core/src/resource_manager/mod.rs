@@ -16,7 +16,7 @@ use crate::resource_manager::image::ImageResource;
 use crate::resource_manager::resource::Resource;
 use crate::resource_manager::resource_data::ResourceData;
 use crate::resource_manager::resource_type::ResourceType;
-use craft_logging::info;
+use craft_logging::{info, warn};
 
 use ::image::ImageReader;
 use tokio::sync::mpsc::Sender;
@@ -141,6 +141,26 @@ impl ResourceManager {
                     };
                     self.runtime.spawn(f);
                 }
+                ResourceType::MessageCatalog => {
+                    let app_sender_copy = self.app_sender.clone();
+                    let f = async move {
+                        let bytes = resource_identifier.fetch_data_from_resource_identifier().await;
+
+                        if let Some(bytes) = bytes {
+                            app_sender_copy
+                                .send(InternalMessage::ResourceEvent(ResourceEvent::Loaded(
+                                    resource_identifier_copy,
+                                    ResourceType::MessageCatalog,
+                                    Resource::MessageCatalog(bytes),
+                                )))
+                                .await
+                                .expect("Failed to send added resource event");
+                        } else {
+                            warn!("Failed to load the message catalog {:?}", resource_identifier_copy);
+                        }
+                    };
+                    self.runtime.spawn(f);
+                }
             }
         }
     }
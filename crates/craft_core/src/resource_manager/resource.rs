@@ -7,6 +7,7 @@ pub enum Resource {
     Image(Arc<ImageResource>),
     Font(Vec<u8>),
     TinyVg(TinyVgResource),
+    MessageCatalog(Vec<u8>),
 }
 
 impl Resource {
@@ -15,6 +16,7 @@ impl Resource {
             Resource::Image(data) => data.common_data.data.as_deref(),
             Resource::Font(data) => Some(data),
             Resource::TinyVg(data) => data.common_data.data.as_deref(),
+            Resource::MessageCatalog(data) => Some(data),
         }
     }
 }
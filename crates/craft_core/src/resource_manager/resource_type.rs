@@ -3,4 +3,5 @@ pub enum ResourceType {
     Image,
     Font,
     TinyVg,
+    MessageCatalog,
 }
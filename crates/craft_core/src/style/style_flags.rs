@@ -42,5 +42,16 @@ bitflags! {
         const SCROLLBAR_THUMB_MARGIN = 1 << 36;
         const VISIBLE = 1 << 37;
         const UNDERLINE = 1 << 38;
+        const LINE_HEIGHT = 1 << 39;
+        const LETTER_SPACING = 1 << 40;
+        const WORD_SPACING = 1 << 41;
+        const TEXT_ALIGN = 1 << 42;
+        const WHITE_SPACE = 1 << 43;
+        const STRIKETHROUGH = 1 << 44;
+        const OVERLINE = 1 << 45;
+        const FONT_FEATURES = 1 << 46;
+        const FONT_VARIATIONS = 1 << 47;
+        const DIRECTION = 1 << 48;
+        const FONT_OBLIQUE_ANGLE = 1 << 49;
     }
 }
@@ -1,6 +1,7 @@
 use crate::renderer::color::Color;
 use crate::style::style_flags::StyleFlags;
 use std::borrow::Cow;
+use std::ops::Range;
 
 pub use taffy::BoxSizing;
 pub use taffy::Overflow;
@@ -8,6 +9,7 @@ pub use taffy::Position;
 
 use crate::geometry::TrblRectangle;
 use crate::text::text_context::ColorBrush;
+use craft_logging::warn;
 use std::fmt;
 use std::fmt::Debug;
 use parley::Brush;
@@ -137,17 +139,36 @@ impl Default for Weight {
     }
 }
 
+/// How a text decoration line is drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextDecorationStyle {
+    #[default]
+    Solid,
+    Double,
+    Dotted,
+    Dashed,
+    Wavy,
+}
+
+/// A line drawn under, over or through text.
+///
+/// The `offset` is the distance from the baseline to the top of the line, and the font's default is used when the
+/// `thickness` or `offset` is `None`.
 #[derive(Clone, Copy, Debug, PartialEq)]
-pub struct Underline {
+pub struct TextDecoration {
     pub thickness: Option<f32>,
     pub color: Color,
     pub offset: Option<f32>,
+    pub style: TextDecorationStyle,
 }
 
+pub type Underline = TextDecoration;
+
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
 pub enum FontStyle {
     Normal,
     Italic,
+    /// Slanted by the oblique angle of the style, which defaults to 14 degrees.
     Oblique,
 }
 
@@ -158,6 +179,234 @@ impl Default for FontStyle {
     }
 }
 
+impl FontStyle {
+    /// Converts the style, slanting an oblique style by `oblique_angle` in degrees if there is one.
+    pub(crate) fn to_parley(self, oblique_angle: Option<f32>) -> parley::FontStyle {
+        match self {
+            FontStyle::Normal => parley::FontStyle::Normal,
+            FontStyle::Italic => parley::FontStyle::Italic,
+            FontStyle::Oblique => parley::FontStyle::Oblique(oblique_angle),
+        }
+    }
+}
+
+/// An OpenType feature and its value, like `FontFeature::new(b"tnum", 1)` for tabular numbers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FontFeature {
+    pub tag: [u8; 4],
+    pub value: u16,
+}
+
+impl FontFeature {
+    pub fn new(tag: &[u8; 4], value: u16) -> Self {
+        Self { tag: *tag, value }
+    }
+
+    /// Digits that all have the same width, so that numbers line up in columns.
+    pub fn tabular_numbers() -> Self {
+        Self::new(b"tnum", 1)
+    }
+
+    /// Turns the standard ligatures, like `fi`, on or off.
+    pub fn ligatures(enabled: bool) -> Self {
+        Self::new(b"liga", enabled as u16)
+    }
+
+    /// Lowercase letters drawn as small capitals.
+    pub fn small_caps() -> Self {
+        Self::new(b"smcp", 1)
+    }
+
+    fn to_parley(self) -> parley::swash::Setting<u16> {
+        parley::swash::Setting {
+            tag: parley::swash::tag_from_bytes(&self.tag),
+            value: self.value,
+        }
+    }
+}
+
+impl fmt::Display for FontFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", String::from_utf8_lossy(&self.tag), self.value)
+    }
+}
+
+/// A value of a variable font axis, like `FontVariation::new(b"wght", 650.0)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FontVariation {
+    pub tag: [u8; 4],
+    pub value: f32,
+}
+
+impl FontVariation {
+    pub fn new(tag: &[u8; 4], value: f32) -> Self {
+        Self { tag: *tag, value }
+    }
+
+    pub fn weight(weight: f32) -> Self {
+        Self::new(b"wght", weight)
+    }
+
+    /// The width as a percentage of the normal width.
+    pub fn width(width: f32) -> Self {
+        Self::new(b"wdth", width)
+    }
+
+    /// The font size that the glyphs are designed for.
+    pub fn optical_size(optical_size: f32) -> Self {
+        Self::new(b"opsz", optical_size)
+    }
+
+    fn to_parley(self) -> parley::swash::Setting<f32> {
+        parley::swash::Setting {
+            tag: parley::swash::tag_from_bytes(&self.tag),
+            value: self.value,
+        }
+    }
+}
+
+impl fmt::Display for FontVariation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", String::from_utf8_lossy(&self.tag), self.value)
+    }
+}
+
+/// The maximum number of font features or variations of a style.
+const MAX_FONT_SETTINGS: usize = 8;
+
+/// A list of font features or variations with a fixed capacity, so that styles stay `Copy`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FontSettingList<T> {
+    len: u8,
+    settings: [T; MAX_FONT_SETTINGS],
+}
+
+impl<T: Copy + Default> Default for FontSettingList<T> {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            settings: [T::default(); MAX_FONT_SETTINGS],
+        }
+    }
+}
+
+impl<T: Copy> FontSettingList<T> {
+    pub fn as_slice(&self) -> &[T] {
+        &self.settings[..self.len as usize]
+    }
+
+    /// Adds a setting, replacing the setting it has the same tag as according to `same_tag`.
+    ///
+    /// Settings over the capacity are ignored with a warning.
+    pub(crate) fn set(&mut self, setting: T, same_tag: impl Fn(&T, &T) -> bool)
+    where
+        T: fmt::Display,
+    {
+        let len = self.len as usize;
+        if let Some(existing) = self.settings[..len].iter_mut().find(|existing| same_tag(existing, &setting)) {
+            *existing = setting;
+        } else if len < MAX_FONT_SETTINGS {
+            self.settings[len] = setting;
+            self.len += 1;
+        } else {
+            warn!(
+                "Ignoring the font setting {}, a style has at most {} font features or variations",
+                setting, MAX_FONT_SETTINGS
+            );
+        }
+    }
+}
+
+/// How the lines of a text are aligned horizontally.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextAlign {
+    #[default]
+    Start,
+    Center,
+    End,
+    Justify,
+}
+
+impl TextAlign {
+    /// The alignment of a text in `direction`, where start and end follow the text itself for `Direction::Auto`.
+    pub(crate) fn to_parley_alignment(self, direction: Direction) -> parley::Alignment {
+        match (self, direction) {
+            (TextAlign::Start, Direction::Auto) => parley::Alignment::Start,
+            (TextAlign::End, Direction::Auto) => parley::Alignment::End,
+            (TextAlign::Start, Direction::Ltr) | (TextAlign::End, Direction::Rtl) => parley::Alignment::Left,
+            (TextAlign::Start, Direction::Rtl) | (TextAlign::End, Direction::Ltr) => parley::Alignment::Right,
+            (TextAlign::Center, _) => parley::Alignment::Center,
+            (TextAlign::Justify, _) => parley::Alignment::Justify,
+        }
+    }
+}
+
+/// The direction that the content of an element flows in.
+///
+/// Right-to-left reverses the rows of a flex container, puts the vertical scrollbar on the left, and aligns text
+/// to the right at its start. Mixed-direction text is always laid out by its bidirectional runs.
+///
+/// The scrollbar is only moved to the left when the left padding is in pixels, as its space is reserved by
+/// widening that padding. A percentage left padding keeps it on the right.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Direction {
+    /// Left-to-right for layout, and the direction of the first strong character for text.
+    #[default]
+    Auto,
+    Ltr,
+    Rtl,
+}
+
+impl Direction {
+    /// The invisible character that text is laid out after to give it this base direction.
+    ///
+    /// Parley takes the base direction of a layout from the first strong character of its text, and doesn't have
+    /// an option to set it, so the byte indices of the layout are offset by the length of the mark.
+    pub(crate) fn mark(self) -> &'static str {
+        match self {
+            Direction::Auto => "",
+            Direction::Ltr => "\u{200E}",
+            Direction::Rtl => "\u{200F}",
+        }
+    }
+}
+
+/// How a text that doesn't fit is cut off.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TextOverflow {
+    /// The text is cut off at the end of the last line that fits.
+    #[default]
+    Clip,
+    /// The text is cut off at the last glyph that fits, followed by an ellipsis.
+    Ellipsis,
+}
+
+/// How white space and line wrapping are handled in a text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WhiteSpace {
+    /// Runs of white space, including line breaks, are collapsed into a single space and lines wrap.
+    Normal,
+    /// Runs of white space are collapsed like with `Normal`, but lines don't wrap.
+    NoWrap,
+    /// White space is kept and lines only break at line breaks.
+    Pre,
+    /// White space is kept and lines wrap.
+    #[default]
+    PreWrap,
+}
+
+impl WhiteSpace {
+    /// Whether lines wrap at the available width.
+    pub(crate) fn wraps(self) -> bool {
+        matches!(self, WhiteSpace::Normal | WhiteSpace::PreWrap)
+    }
+
+    /// Whether runs of white space are collapsed into a single space.
+    pub(crate) fn collapses(self) -> bool {
+        matches!(self, WhiteSpace::Normal | WhiteSpace::NoWrap)
+    }
+}
+
 #[derive(Clone, PartialEq)]
 pub enum TextStyleProperty {
     Color(Color),
@@ -165,13 +414,60 @@ pub enum TextStyleProperty {
     FontSize(f32),
     FontWeight(Weight),
     FontStyle(FontStyle),
+    /// An oblique font style slanted by an angle in degrees.
+    FontObliqueAngle(f32),
+    /// Replaces the font features of the text.
+    FontFeatures(Vec<FontFeature>),
+    /// Replaces the font variations of the text.
+    FontVariations(Vec<FontVariation>),
+    Underline(bool),
     UnderlineOffset(f32),
     UnderlineSize(f32),
     UnderlineBrush(Color),
+    UnderlineStyle(TextDecorationStyle),
+    Strikethrough(bool),
+    StrikethroughOffset(f32),
+    StrikethroughSize(f32),
+    StrikethroughBrush(Color),
+    StrikethroughStyle(TextDecorationStyle),
+    Overline(Option<TextDecoration>),
 }
 
 impl TextStyleProperty {
-    pub(crate) fn to_parley_style_property(&self) -> parley::StyleProperty<'static, ColorBrush> {
+    /// Pushes `styles` to a layout builder for the byte `range` of the text.
+    ///
+    /// Parley doesn't support overlines and decoration styles, so they are carried by the brush of the text along with
+    /// its color. The properties that change the brush are applied to `brush`, the brush of the text around the range.
+    pub(crate) fn push_to_builder(
+        styles: &[TextStyleProperty],
+        brush: ColorBrush,
+        range: Range<usize>,
+        builder: &mut parley::RangedBuilder<ColorBrush>,
+    ) {
+        let mut span_brush = brush;
+        for style in styles {
+            if !style.apply_to_brush(&mut span_brush) {
+                builder.push(style.to_parley_style_property(), range.clone());
+            }
+        }
+        if span_brush != brush {
+            builder.push(parley::StyleProperty::Brush(span_brush), range);
+        }
+    }
+
+    /// Applies the property to the brush of the text, returning false if it isn't carried by the brush.
+    fn apply_to_brush(&self, brush: &mut ColorBrush) -> bool {
+        match self {
+            TextStyleProperty::Color(color) => brush.color = *color,
+            TextStyleProperty::UnderlineStyle(style) => brush.underline_style = *style,
+            TextStyleProperty::StrikethroughStyle(style) => brush.strikethrough_style = *style,
+            TextStyleProperty::Overline(overline) => brush.overline = *overline,
+            _ => return false,
+        }
+        true
+    }
+
+    fn to_parley_style_property(&self) -> parley::StyleProperty<'static, ColorBrush> {
         match self {
             TextStyleProperty::FontFamily(font_family, font_family_length) => {
                 
@@ -202,28 +498,35 @@ impl TextStyleProperty {
             }
             
             TextStyleProperty::Color(color) => {
-                let brush = ColorBrush {
-                    color: *color,
-                };
-
-                parley::StyleProperty::Brush(brush)
+                parley::StyleProperty::Brush(ColorBrush::new(*color))
             }
             
             TextStyleProperty::FontStyle(font_style) => {
-                let font_style = match font_style {
-                    FontStyle::Normal => parley::FontStyle::Normal,
-                    FontStyle::Italic => parley::FontStyle::Italic,
-                    // FIXME: Allow an angle when setting the obliqueness.
-                    FontStyle::Oblique => parley::FontStyle::Oblique(None),
-                };
+                parley::StyleProperty::FontStyle(font_style.to_parley(None))
+            }
+
+            TextStyleProperty::FontObliqueAngle(angle) => {
+                parley::StyleProperty::FontStyle(FontStyle::Oblique.to_parley(Some(*angle)))
+            }
+
+            TextStyleProperty::FontFeatures(features) => {
+                let features = features.iter().map(|feature| feature.to_parley()).collect();
+                parley::StyleProperty::FontFeatures(parley::FontSettings::List(Cow::Owned(features)))
+            }
 
-                parley::StyleProperty::FontStyle(font_style)
+            TextStyleProperty::FontVariations(variations) => {
+                let variations = variations.iter().map(|variation| variation.to_parley()).collect();
+                parley::StyleProperty::FontVariations(parley::FontSettings::List(Cow::Owned(variations)))
             }
 
             TextStyleProperty::FontWeight(font_weight) => {
                 parley::StyleProperty::FontWeight(parley::FontWeight::new(font_weight.0 as f32))
             }
 
+            TextStyleProperty::Underline(underline) => {
+                parley::StyleProperty::Underline(*underline)
+            }
+
             TextStyleProperty::UnderlineOffset(offset) => {
                 parley::StyleProperty::UnderlineOffset(Some(*offset))
             }
@@ -233,11 +536,29 @@ impl TextStyleProperty {
             }
 
             TextStyleProperty::UnderlineBrush(color) => {
-                let brush = ColorBrush {
-                    color: *color,
-                };
+                parley::StyleProperty::UnderlineBrush(Some(ColorBrush::new(*color)))
+            }
+
+            TextStyleProperty::Strikethrough(strikethrough) => {
+                parley::StyleProperty::Strikethrough(*strikethrough)
+            }
+
+            TextStyleProperty::StrikethroughOffset(offset) => {
+                parley::StyleProperty::StrikethroughOffset(Some(*offset))
+            }
+
+            TextStyleProperty::StrikethroughSize(size) => {
+                parley::StyleProperty::StrikethroughSize(Some(*size))
+            }
+
+            TextStyleProperty::StrikethroughBrush(color) => {
+                parley::StyleProperty::StrikethroughBrush(Some(ColorBrush::new(*color)))
+            }
 
-                parley::StyleProperty::UnderlineBrush(Some(brush))
+            TextStyleProperty::UnderlineStyle(_)
+            | TextStyleProperty::StrikethroughStyle(_)
+            | TextStyleProperty::Overline(_) => {
+                unreachable!("carried by the brush of the text")
             }
         }
     }
@@ -275,7 +596,20 @@ pub struct Style {
     font_size: f32,
     font_weight: Weight,
     font_style: FontStyle,
+    /// The angle in degrees that an oblique font style slants by, or `None` for the default angle.
+    font_oblique_angle: Option<f32>,
+    font_features: FontSettingList<FontFeature>,
+    font_variations: FontSettingList<FontVariation>,
     underline: Option<Underline>,
+    strikethrough: Option<TextDecoration>,
+    overline: Option<TextDecoration>,
+    /// The height of a line relative to the font size.
+    line_height: f32,
+    letter_spacing: f32,
+    word_spacing: f32,
+    text_align: TextAlign,
+    direction: Direction,
+    white_space: WhiteSpace,
     overflow: [Overflow; 2],
 
     border_color: TrblRectangle<Color>,
@@ -327,7 +661,18 @@ impl Default for Style {
             font_size: 16.0,
             font_weight: Default::default(),
             font_style: Default::default(),
+            font_oblique_angle: None,
+            font_features: Default::default(),
+            font_variations: Default::default(),
             underline: None,
+            strikethrough: None,
+            overline: None,
+            line_height: 1.2,
+            letter_spacing: 0.0,
+            word_spacing: 0.0,
+            text_align: TextAlign::default(),
+            direction: Direction::default(),
+            white_space: WhiteSpace::default(),
             overflow: [Overflow::default(), Overflow::default()],
             border_radius: [(0.0, 0.0); 4],
             scrollbar_color: ScrollbarColor {
@@ -630,6 +975,33 @@ impl Style {
         &mut self.font_style
     }
 
+    pub fn font_oblique_angle(&self) -> Option<f32> {
+        self.font_oblique_angle
+    }
+
+    pub fn font_oblique_angle_mut(&mut self) -> &mut Option<f32> {
+        self.dirty_flags.insert(StyleFlags::FONT_OBLIQUE_ANGLE);
+        &mut self.font_oblique_angle
+    }
+
+    pub fn font_features(&self) -> &[FontFeature] {
+        self.font_features.as_slice()
+    }
+
+    pub fn font_features_mut(&mut self) -> &mut FontSettingList<FontFeature> {
+        self.dirty_flags.insert(StyleFlags::FONT_FEATURES);
+        &mut self.font_features
+    }
+
+    pub fn font_variations(&self) -> &[FontVariation] {
+        self.font_variations.as_slice()
+    }
+
+    pub fn font_variations_mut(&mut self) -> &mut FontSettingList<FontVariation> {
+        self.dirty_flags.insert(StyleFlags::FONT_VARIATIONS);
+        &mut self.font_variations
+    }
+
     pub fn underline(&self) -> Option<Underline> {
         self.underline
     }
@@ -639,6 +1011,90 @@ impl Style {
         &mut self.underline
     }
 
+    pub fn strikethrough(&self) -> Option<TextDecoration> {
+        self.strikethrough
+    }
+
+    pub fn strikethrough_mut(&mut self) -> &mut Option<TextDecoration> {
+        self.dirty_flags.insert(StyleFlags::STRIKETHROUGH);
+        &mut self.strikethrough
+    }
+
+    pub fn overline(&self) -> Option<TextDecoration> {
+        self.overline
+    }
+
+    pub fn overline_mut(&mut self) -> &mut Option<TextDecoration> {
+        self.dirty_flags.insert(StyleFlags::OVERLINE);
+        &mut self.overline
+    }
+
+    pub fn line_height(&self) -> f32 {
+        self.line_height
+    }
+
+    pub fn line_height_mut(&mut self) -> &mut f32 {
+        self.dirty_flags.insert(StyleFlags::LINE_HEIGHT);
+        &mut self.line_height
+    }
+
+    pub fn letter_spacing(&self) -> f32 {
+        self.letter_spacing
+    }
+
+    pub fn letter_spacing_mut(&mut self) -> &mut f32 {
+        self.dirty_flags.insert(StyleFlags::LETTER_SPACING);
+        &mut self.letter_spacing
+    }
+
+    pub fn word_spacing(&self) -> f32 {
+        self.word_spacing
+    }
+
+    pub fn word_spacing_mut(&mut self) -> &mut f32 {
+        self.dirty_flags.insert(StyleFlags::WORD_SPACING);
+        &mut self.word_spacing
+    }
+
+    pub fn text_align(&self) -> TextAlign {
+        self.text_align
+    }
+
+    pub fn text_align_mut(&mut self) -> &mut TextAlign {
+        self.dirty_flags.insert(StyleFlags::TEXT_ALIGN);
+        &mut self.text_align
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    pub fn direction_mut(&mut self) -> &mut Direction {
+        self.dirty_flags.insert(StyleFlags::DIRECTION);
+        &mut self.direction
+    }
+
+    /// Whether the vertical scrollbar is on the left.
+    ///
+    /// Taffy always reserves the space of the scrollbar on the right, so a left scrollbar reserves it by widening
+    /// the left padding instead. Taffy can't add a length to a percentage without calc support, so this is only
+    /// done for a left padding in pixels, and the scrollbar stays on the right for a percentage. Only
+    /// `Overflow::Scroll` shows a vertical scrollbar.
+    pub(crate) fn has_left_scrollbar(&self) -> bool {
+        self.direction == Direction::Rtl
+            && self.overflow[1] == Overflow::Scroll
+            && matches!(self.padding.left, Unit::Px(_))
+    }
+
+    pub fn white_space(&self) -> WhiteSpace {
+        self.white_space
+    }
+
+    pub fn white_space_mut(&mut self) -> &mut WhiteSpace {
+        self.dirty_flags.insert(StyleFlags::WHITE_SPACE);
+        &mut self.white_space
+    }
+
     pub fn overflow(&self) -> [Overflow; 2] {
         self.overflow
     }
@@ -806,6 +1262,15 @@ impl Style {
             if new_dirty_flags.contains(StyleFlags::FONT_WEIGHT) { new.font_weight } else { old.font_weight };
 
         let font_style = if new_dirty_flags.contains(StyleFlags::FONT_STYLE) { new.font_style } else { old.font_style };
+        let font_oblique_angle = if new_dirty_flags.contains(StyleFlags::FONT_OBLIQUE_ANGLE) {
+            new.font_oblique_angle
+        } else {
+            old.font_oblique_angle
+        };
+        let font_features =
+            if new_dirty_flags.contains(StyleFlags::FONT_FEATURES) { new.font_features } else { old.font_features };
+        let font_variations =
+            if new_dirty_flags.contains(StyleFlags::FONT_VARIATIONS) { new.font_variations } else { old.font_variations };
 
         let overflow = if new_dirty_flags.contains(StyleFlags::OVERFLOW) { new.overflow } else { old.overflow };
 
@@ -839,6 +1304,24 @@ impl Style {
         let visible = if new_dirty_flags.contains(StyleFlags::VISIBLE) { new.visible } else { old.visible };
 
         let underline = if new_dirty_flags.contains(StyleFlags::UNDERLINE) { new.underline } else { old.underline };
+        let strikethrough =
+            if new_dirty_flags.contains(StyleFlags::STRIKETHROUGH) { new.strikethrough } else { old.strikethrough };
+        let overline = if new_dirty_flags.contains(StyleFlags::OVERLINE) { new.overline } else { old.overline };
+
+        let line_height =
+            if new_dirty_flags.contains(StyleFlags::LINE_HEIGHT) { new.line_height } else { old.line_height };
+
+        let letter_spacing =
+            if new_dirty_flags.contains(StyleFlags::LETTER_SPACING) { new.letter_spacing } else { old.letter_spacing };
+
+        let word_spacing =
+            if new_dirty_flags.contains(StyleFlags::WORD_SPACING) { new.word_spacing } else { old.word_spacing };
+
+        let text_align = if new_dirty_flags.contains(StyleFlags::TEXT_ALIGN) { new.text_align } else { old.text_align };
+        let direction = if new_dirty_flags.contains(StyleFlags::DIRECTION) { new.direction } else { old.direction };
+
+        let white_space =
+            if new_dirty_flags.contains(StyleFlags::WHITE_SPACE) { new.white_space } else { old.white_space };
         
         let dirty_flags = old_dirty_flags | new_dirty_flags;
 
@@ -872,7 +1355,18 @@ impl Style {
             font_size,
             font_weight,
             font_style,
+            font_oblique_angle,
+            font_features,
+            font_variations,
             underline,
+            strikethrough,
+            overline,
+            line_height,
+            letter_spacing,
+            word_spacing,
+            text_align,
+            direction,
+            white_space,
             overflow,
             border_color,
             border_width,
@@ -890,15 +1384,8 @@ impl Style {
     pub fn to_text_style(&self) -> parley::TextStyle<ColorBrush> {
         let font_size = self.font_size();
         let font_weight = parley::FontWeight::new(self.font_weight().0 as f32);
-        let font_style = match self.font_style() {
-            FontStyle::Normal => parley::FontStyle::Normal,
-            FontStyle::Italic => parley::FontStyle::Italic,
-            // FIXME: Allow an angle when setting the obliqueness.
-            FontStyle::Oblique => parley::FontStyle::Oblique(None),
-        };
-        let brush = ColorBrush {
-            color: self.color(),
-        };
+        let font_style = self.font_style().to_parley(self.font_oblique_angle);
+        let brush = self.text_brush();
 
         let font_stack_cow_list = if let Some(font_family) = self.font_family() {
             // Use the user-provided font and fallback to system UI fonts as needed.
@@ -912,17 +1399,7 @@ impl Style {
         };
         
         let has_underline = self.underline.is_some();
-        let mut underline_offset = None;
-        let mut underline_size = None;
-        let mut underline_brush = None;
-        
-        if let Some(underline) = self.underline {
-            underline_offset = underline.offset;
-            underline_size = underline.thickness;
-            underline_brush = Some(ColorBrush {
-                color: underline.color,
-            });
-        }
+        let has_strikethrough = self.strikethrough.is_some();
 
         let font_stack = parley::FontStack::List(font_stack_cow_list);
         parley::TextStyle {
@@ -931,21 +1408,21 @@ impl Style {
             font_width: Default::default(),
             font_style,
             font_weight,
-            font_variations: parley::FontSettings::List(Cow::Borrowed(&[])),
-            font_features: parley::FontSettings::List(Cow::Borrowed(&[])),
+            font_variations: parley::FontSettings::List(Cow::Owned(self.parley_font_variations())),
+            font_features: parley::FontSettings::List(Cow::Owned(self.parley_font_features())),
             locale: Default::default(),
             brush,
             has_underline,
-            underline_offset,
-            underline_size,
-            underline_brush,
-            has_strikethrough: Default::default(),
-            strikethrough_offset: Default::default(),
-            strikethrough_size: Default::default(),
-            strikethrough_brush: Default::default(),
-            line_height: parley::LineHeight::FontSizeRelative(1.2),
-            word_spacing: Default::default(),
-            letter_spacing: Default::default(),
+            underline_offset: self.underline.and_then(|underline| underline.offset),
+            underline_size: self.underline.and_then(|underline| underline.thickness),
+            underline_brush: self.underline.map(|underline| ColorBrush::new(underline.color)),
+            has_strikethrough,
+            strikethrough_offset: self.strikethrough.and_then(|strikethrough| strikethrough.offset),
+            strikethrough_size: self.strikethrough.and_then(|strikethrough| strikethrough.thickness),
+            strikethrough_brush: self.strikethrough.map(|strikethrough| ColorBrush::new(strikethrough.color)),
+            line_height: parley::LineHeight::FontSizeRelative(self.line_height),
+            word_spacing: self.word_spacing,
+            letter_spacing: self.letter_spacing,
             word_break: Default::default(),
             overflow_wrap: Default::default(),
         }
@@ -954,28 +1431,8 @@ impl Style {
     pub fn add_styles_to_style_set(&self, style_set: &mut parley::StyleSet<ColorBrush>) {
         let font_size = self.font_size();
         let font_weight = parley::FontWeight::new(self.font_weight().0 as f32);
-        let font_style = match self.font_style() {
-            FontStyle::Normal => parley::FontStyle::Normal,
-            FontStyle::Italic => parley::FontStyle::Italic,
-            // FIXME: Allow an angle when setting the obliqueness.
-            FontStyle::Oblique => parley::FontStyle::Oblique(None),
-        };
-        let brush = ColorBrush {
-            color: self.color(),
-        };
-
-        let has_underline = self.underline.is_some();
-        let mut underline_offset = None;
-        let mut underline_size = None;
-        let mut underline_brush = None;
-
-        if let Some(underline) = self.underline {
-            underline_offset = underline.offset;
-            underline_size = underline.thickness;
-            underline_brush = Some(ColorBrush {
-                color: underline.color,
-            });
-        }
+        let font_style = self.font_style().to_parley(self.font_oblique_angle);
+        let brush = self.text_brush();
 
         let font_stack_cow_list = if let Some(font_family) = self.font_family() {
             // Use the user-provided font and fallback to system UI fonts as needed.
@@ -992,12 +1449,50 @@ impl Style {
         style_set.insert(parley::StyleProperty::FontSize(font_size));
         style_set.insert(parley::StyleProperty::FontStyle(font_style));
         style_set.insert(parley::StyleProperty::FontWeight(font_weight));
+        style_set.insert(parley::StyleProperty::FontFeatures(parley::FontSettings::List(Cow::Owned(
+            self.parley_font_features(),
+        ))));
+        style_set.insert(parley::StyleProperty::FontVariations(parley::FontSettings::List(Cow::Owned(
+            self.parley_font_variations(),
+        ))));
         style_set.insert(parley::StyleProperty::Brush(brush));
-        style_set.insert(parley::StyleProperty::LineHeight(parley::LineHeight::FontSizeRelative(1.2)));
-        style_set.insert(parley::StyleProperty::Underline(has_underline));
-        style_set.insert(parley::StyleProperty::UnderlineBrush(underline_brush));
-        style_set.insert(parley::StyleProperty::UnderlineOffset(underline_offset));
-        style_set.insert(parley::StyleProperty::UnderlineSize(underline_size));
+        style_set.insert(parley::StyleProperty::LineHeight(parley::LineHeight::FontSizeRelative(self.line_height)));
+        style_set.insert(parley::StyleProperty::LetterSpacing(self.letter_spacing));
+        style_set.insert(parley::StyleProperty::WordSpacing(self.word_spacing));
+        style_set.insert(parley::StyleProperty::Underline(self.underline.is_some()));
+        style_set.insert(parley::StyleProperty::UnderlineBrush(
+            self.underline.map(|underline| ColorBrush::new(underline.color)),
+        ));
+        style_set.insert(parley::StyleProperty::UnderlineOffset(self.underline.and_then(|underline| underline.offset)));
+        style_set.insert(parley::StyleProperty::UnderlineSize(self.underline.and_then(|underline| underline.thickness)));
+        style_set.insert(parley::StyleProperty::Strikethrough(self.strikethrough.is_some()));
+        style_set.insert(parley::StyleProperty::StrikethroughBrush(
+            self.strikethrough.map(|strikethrough| ColorBrush::new(strikethrough.color)),
+        ));
+        style_set.insert(parley::StyleProperty::StrikethroughOffset(
+            self.strikethrough.and_then(|strikethrough| strikethrough.offset),
+        ));
+        style_set.insert(parley::StyleProperty::StrikethroughSize(
+            self.strikethrough.and_then(|strikethrough| strikethrough.thickness),
+        ));
+    }
+
+    pub(crate) fn parley_font_features(&self) -> Vec<parley::swash::Setting<u16>> {
+        self.font_features().iter().map(|feature| feature.to_parley()).collect()
+    }
+
+    pub(crate) fn parley_font_variations(&self) -> Vec<parley::swash::Setting<f32>> {
+        self.font_variations().iter().map(|variation| variation.to_parley()).collect()
+    }
+
+    /// The brush of the text, which also carries the decoration styles and the overline.
+    pub(crate) fn text_brush(&self) -> ColorBrush {
+        ColorBrush {
+            color: self.color(),
+            underline_style: self.underline.map(|underline| underline.style).unwrap_or_default(),
+            strikethrough_style: self.strikethrough.map(|strikethrough| strikethrough.style).unwrap_or_default(),
+            overline: self.overline,
+        }
     }
     
 }
@@ -1,4 +1,4 @@
-use crate::style::{AlignItems, Display, FlexDirection, JustifyContent, Style, Unit, Wrap};
+use crate::style::{AlignItems, Direction, Display, FlexDirection, JustifyContent, Style, Unit, Wrap};
 use taffy::{FlexWrap, Overflow};
 
 fn unit_to_taffy_dimension(unit: Unit) -> taffy::Dimension {
@@ -107,7 +107,11 @@ impl Style {
             Some(JustifyContent::SpaceAround) => Some(taffy::JustifyContent::SpaceAround),
         };
 
+        // Taffy has no direction, so the rows of right-to-left containers are reversed instead.
+        let rtl = style.direction() == Direction::Rtl;
         let flex_direction = match style.flex_direction() {
+            FlexDirection::Row if rtl => taffy::FlexDirection::RowReverse,
+            FlexDirection::RowReverse if rtl => taffy::FlexDirection::Row,
             FlexDirection::Row => taffy::FlexDirection::Row,
             FlexDirection::Column => taffy::FlexDirection::Column,
             FlexDirection::RowReverse => taffy::FlexDirection::RowReverse,
@@ -131,7 +135,15 @@ impl Style {
         let overflow_x = overflow_to_taffy_overflow(style.overflow()[0]);
         let overflow_y = overflow_to_taffy_overflow(style.overflow()[1]);
 
-        let scrollbar_width = style.scrollbar_width();
+        let mut scrollbar_width = style.scrollbar_width();
+        let mut padding = padding;
+
+        // Taffy reserves the space of the scrollbar on the right, so it is reserved as left padding instead when
+        // the scrollbar is on the left.
+        if let (true, Unit::Px(left)) = (style.has_left_scrollbar(), style.padding().left) {
+            padding.left = taffy::LengthPercentage::length(left + scrollbar_width);
+            scrollbar_width = 0.0;
+        }
 
         let box_sizing = taffy::BoxSizing::BorderBox;
 
@@ -0,0 +1,226 @@
+//! The undo and redo history of a [`PlainEditor`](super::parley_editor::PlainEditor).
+
+/// The maximum number of edits that can be undone.
+const MAX_EDITS: usize = 100;
+
+/// A replacement of `deleted` with `inserted` at the byte index `start` of the text buffer.
+#[derive(Clone, Debug)]
+pub(crate) struct Edit {
+    pub(crate) start: usize,
+    pub(crate) deleted: String,
+    pub(crate) inserted: String,
+    /// The byte indices of the selection anchor and focus before the edit.
+    pub(crate) selection: (usize, usize),
+    /// Whether the edit is a typed character, which consecutive typed characters are merged into.
+    pub(crate) typing: bool,
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct EditHistory {
+    undo: Vec<Edit>,
+    redo: Vec<Edit>,
+}
+
+impl EditHistory {
+    /// Adds an edit to the history and forgets the edits that were undone.
+    pub(crate) fn record(&mut self, edit: Edit) {
+        self.redo.clear();
+
+        if edit.typing {
+            if let Some(last) = self.undo.last_mut() {
+                if last.typing && last.start + last.inserted.len() == edit.start {
+                    last.inserted.push_str(&edit.inserted);
+                    return;
+                }
+            }
+        }
+
+        if self.undo.len() == MAX_EDITS {
+            self.undo.remove(0);
+        }
+        self.undo.push(edit);
+    }
+
+    /// Takes the last edit, which can be redone afterwards.
+    pub(crate) fn undo(&mut self) -> Option<Edit> {
+        let mut edit = self.undo.pop()?;
+        edit.typing = false;
+        self.redo.push(edit.clone());
+        Some(edit)
+    }
+
+    /// Takes the last undone edit, which can be undone again afterwards.
+    pub(crate) fn redo(&mut self) -> Option<Edit> {
+        let edit = self.redo.pop()?;
+        self.undo.push(edit.clone());
+        Some(edit)
+    }
+
+    /// Makes replacing the whole `buffer` with `new_buffer` a part of the last edit, so that both are undone together.
+    ///
+    /// `buffer` is the text right after the last edit.
+    pub(crate) fn amend(&mut self, buffer: &str, new_buffer: &str) {
+        if let Some(last) = self.undo.last_mut() {
+            let old_buffer =
+                format!("{}{}{}", &buffer[..last.start], last.deleted, &buffer[last.start + last.inserted.len()..]);
+            last.start = 0;
+            last.deleted = old_buffer;
+            last.inserted = new_buffer.to_owned();
+            last.typing = false;
+        }
+    }
+
+    pub(crate) fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub(crate) fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Edit, EditHistory, MAX_EDITS};
+    use crate::text::parley_editor::PlainEditor;
+    use crate::text::text_context::TextContext;
+
+    fn edit(start: usize, deleted: &str, inserted: &str, typing: bool) -> Edit {
+        Edit {
+            start,
+            deleted: deleted.to_string(),
+            inserted: inserted.to_string(),
+            selection: (start, start + deleted.len()),
+            typing,
+        }
+    }
+
+    #[test]
+    fn consecutive_typing_is_undone_together() {
+        let mut history = EditHistory::default();
+        history.record(edit(0, "", "a", true));
+        history.record(edit(1, "", "b", true));
+        history.record(edit(2, "", "c", true));
+
+        let undone = history.undo().unwrap();
+        assert_eq!((undone.start, undone.inserted.as_str()), (0, "abc"));
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn typing_elsewhere_or_other_edits_are_not_merged() {
+        let mut history = EditHistory::default();
+        history.record(edit(0, "", "a", true));
+        history.record(edit(0, "", "b", true));
+        history.record(edit(1, "", "pasted", false));
+
+        assert_eq!(history.undo().unwrap().inserted, "pasted");
+        assert_eq!(history.undo().unwrap().inserted, "b");
+        assert_eq!(history.undo().unwrap().inserted, "a");
+    }
+
+    #[test]
+    fn typing_after_a_redo_is_a_new_edit() {
+        let mut history = EditHistory::default();
+        history.record(edit(0, "", "a", true));
+        history.undo();
+        history.redo();
+        history.record(edit(1, "", "b", true));
+
+        assert_eq!(history.undo().unwrap().inserted, "b");
+        assert_eq!(history.undo().unwrap().inserted, "a");
+    }
+
+    #[test]
+    fn recording_forgets_the_undone_edits() {
+        let mut history = EditHistory::default();
+        history.record(edit(0, "", "a", false));
+        history.undo();
+        assert!(history.can_redo());
+
+        history.record(edit(0, "", "b", false));
+        assert!(!history.can_redo());
+        assert!(history.redo().is_none());
+    }
+
+    #[test]
+    fn oldest_edits_are_evicted() {
+        let mut history = EditHistory::default();
+        for start in 0..=MAX_EDITS {
+            history.record(edit(start, "", "x", false));
+        }
+
+        for start in (1..=MAX_EDITS).rev() {
+            assert_eq!(history.undo().unwrap().start, start);
+        }
+        assert!(history.undo().is_none());
+    }
+
+    #[test]
+    fn amend_replaces_the_whole_text_in_the_last_edit() {
+        let mut history = EditHistory::default();
+        // Typing `1` at the end of `abc`, which is reformatted to `ab-c1`.
+        history.record(edit(3, "", "1", true));
+        history.amend("abc1", "ab-c1");
+
+        let amended = history.undo().unwrap();
+        assert_eq!((amended.start, amended.deleted.as_str(), amended.inserted.as_str()), (0, "abc", "ab-c1"));
+        assert_eq!(amended.selection, (3, 3));
+        assert!(!amended.typing);
+    }
+
+    #[test]
+    fn undo_and_redo_restore_the_selection() {
+        let mut text_context = TextContext::with_test_font();
+        let mut editor = PlainEditor::new(16.0);
+        editor.set_text("hello world");
+        let mut driver = editor.driver(&mut text_context.font_context, &mut text_context.layout_context);
+
+        driver.select_byte_range(0, 5);
+        driver.insert_or_replace_selection("bye");
+        assert_eq!(driver.editor.raw_text(), "bye world");
+
+        driver.undo();
+        assert_eq!(driver.editor.raw_text(), "hello world");
+        assert_eq!(driver.editor.selection_byte_range(), 0..5);
+
+        driver.redo();
+        assert_eq!(driver.editor.raw_text(), "bye world");
+        assert_eq!(driver.editor.selection_byte_range(), 3..3);
+    }
+
+    #[test]
+    fn edits_that_change_nothing_are_not_recorded() {
+        let mut text_context = TextContext::with_test_font();
+        let mut editor = PlainEditor::new(16.0);
+        editor.set_text("abc");
+        let mut driver = editor.driver(&mut text_context.font_context, &mut text_context.layout_context);
+
+        driver.insert_or_replace_selection("");
+        driver.select_byte_range(1, 2);
+        driver.insert_or_replace_selection("b");
+        assert!(!driver.editor.can_undo());
+    }
+
+    #[test]
+    fn setting_another_text_clears_the_history() {
+        let mut text_context = TextContext::with_test_font();
+        let mut editor = PlainEditor::new(16.0);
+        editor
+            .driver(&mut text_context.font_context, &mut text_context.layout_context)
+            .insert_or_replace_selection("a");
+        assert!(editor.can_undo());
+
+        editor.set_text("a");
+        assert!(editor.can_undo());
+
+        editor.set_text("b");
+        assert!(!editor.can_undo());
+        assert!(!editor.can_redo());
+    }
+}
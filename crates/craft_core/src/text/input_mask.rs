@@ -0,0 +1,148 @@
+//! Formatting masks for text inputs, like `(999) 999-9999` for phone numbers.
+
+/// A part of an [`InputMask`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MaskItem {
+    /// A digit, written as `9`.
+    Digit,
+    /// A letter, written as `a`.
+    Letter,
+    /// A letter or digit, written as `*`.
+    Alphanumeric,
+    /// A character that is inserted by the mask.
+    Literal(char),
+}
+
+impl MaskItem {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            MaskItem::Digit => c.is_ascii_digit(),
+            MaskItem::Letter => c.is_alphabetic(),
+            MaskItem::Alphanumeric => c.is_alphanumeric(),
+            MaskItem::Literal(_) => false,
+        }
+    }
+}
+
+/// A pattern that the text of a text input is formatted with.
+///
+/// `9` accepts a digit, `a` a letter and `*` a letter or digit. Any other character is a literal, which is
+/// inserted automatically, and `\` makes the next character a literal.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct InputMask {
+    items: Vec<MaskItem>,
+}
+
+impl InputMask {
+    pub(crate) fn new(pattern: &str) -> Self {
+        let mut items = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            items.push(match c {
+                '9' => MaskItem::Digit,
+                'a' => MaskItem::Letter,
+                '*' => MaskItem::Alphanumeric,
+                '\\' => MaskItem::Literal(chars.next().unwrap_or('\\')),
+                c => MaskItem::Literal(c),
+            });
+        }
+
+        Self { items }
+    }
+
+    /// The characters of `text` that were entered by the user, without the literals of the mask.
+    pub(crate) fn unmask(&self, text: &str) -> Vec<char> {
+        let mut items = self.items.iter().peekable();
+        let mut entered = Vec::new();
+        for c in text.chars() {
+            if items.peek() == Some(&&MaskItem::Literal(c)) {
+                items.next();
+                continue;
+            }
+            // Skip the literals that are missing from the text, e.g. after one was deleted.
+            while matches!(items.peek(), Some(MaskItem::Literal(_))) {
+                items.next();
+            }
+            items.next();
+            entered.push(c);
+        }
+        entered
+    }
+
+    /// Formats the entered characters, dropping the ones that the mask doesn't accept.
+    ///
+    /// Literals are only inserted before an accepted character, so formatting a prefix of the entered
+    /// characters gives a prefix of the formatted text.
+    pub(crate) fn format(&self, entered: &[char]) -> String {
+        let mut text = String::new();
+        let mut literals = String::new();
+        let mut entered = entered.iter().copied();
+        for item in &self.items {
+            if let MaskItem::Literal(c) = item {
+                literals.push(*c);
+                continue;
+            }
+            let Some(c) = entered.find(|c| item.accepts(*c)) else {
+                break;
+            };
+            text.push_str(&literals);
+            literals.clear();
+            text.push(c);
+        }
+        text
+    }
+
+    /// Formats the characters of `text` that were entered by the user.
+    pub(crate) fn apply(&self, text: &str) -> String {
+        self.format(&self.unmask(text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InputMask;
+
+    #[test]
+    fn formats_entered_characters() {
+        let mask = InputMask::new("(999) 999-9999");
+        assert_eq!(mask.apply("5551234567"), "(555) 123-4567");
+        // Literals are only inserted before an accepted character.
+        assert_eq!(mask.apply("555"), "(555");
+        assert_eq!(mask.apply("5551"), "(555) 1");
+    }
+
+    #[test]
+    fn drops_rejected_characters() {
+        let mask = InputMask::new("aa-99");
+        assert_eq!(mask.apply("a1b2c3"), "ab-12");
+        assert_eq!(mask.apply("12"), "");
+    }
+
+    #[test]
+    fn backslash_escapes_a_literal() {
+        let mask = InputMask::new("\\9-99");
+        assert_eq!(mask.apply("12"), "9-12");
+    }
+
+    #[test]
+    fn unmask_skips_missing_literals() {
+        let mask = InputMask::new("(999) 999");
+        assert_eq!(mask.unmask("(123) 456"), ['1', '2', '3', '4', '5', '6']);
+        // The space was deleted.
+        assert_eq!(mask.unmask("(123)456"), ['1', '2', '3', '4', '5', '6']);
+        assert_eq!(mask.apply("(123)456"), "(123) 456");
+    }
+
+    #[test]
+    fn unmask_drops_a_typed_character_equal_to_the_next_literal() {
+        let mask = InputMask::new("99/99");
+        assert_eq!(mask.unmask("12/"), ['1', '2']);
+        assert_eq!(mask.apply("12/"), "12");
+        assert_eq!(mask.apply("12/3"), "12/3");
+
+        // Even when the mask would accept it.
+        let mask = InputMask::new("*x*");
+        assert_eq!(mask.apply("ax"), "a");
+        assert_eq!(mask.apply("axb"), "axb");
+    }
+}
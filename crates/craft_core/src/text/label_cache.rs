@@ -0,0 +1,83 @@
+use crate::geometry::{Rectangle, Size};
+use crate::renderer::renderer::RenderList;
+use crate::style::Style;
+use crate::text::same_font;
+use crate::text::text_context::{ColorBrush, TextContext};
+use crate::text::text_render_data;
+use crate::text::text_render_data::TextRender;
+use parley::{Alignment, AlignmentOptions};
+use peniko::Color;
+use std::collections::HashMap;
+
+/// Lays out and caches short strings that are drawn without a [`Text`](crate::elements::Text) element,
+/// like the days of a calendar.
+#[derive(Default)]
+pub(crate) struct LabelCache {
+    scale_factor: f64,
+    style: Option<Style>,
+    labels: HashMap<String, (TextRender, Size<f32>)>,
+}
+
+impl LabelCache {
+    /// Draws `text` in `bounds`, which are in logical window coordinates.
+    ///
+    /// The text is vertically centered, and horizontally centered as well when `centered` is set.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn draw(
+        &mut self,
+        renderer: &mut RenderList,
+        text_context: &mut TextContext,
+        style: &Style,
+        text: &str,
+        color: Color,
+        bounds: Rectangle,
+        centered: bool,
+        scale_factor: f64,
+    ) {
+        let (text_render, size) = self.label(text_context, style, text, scale_factor);
+        let size = *size;
+
+        let x = if centered { bounds.x + (bounds.width - size.width) / 2.0 } else { bounds.x };
+        let y = bounds.y + (bounds.height - size.height) / 2.0;
+
+        let mut text_render = text_render.clone();
+        text_render.override_brush = Some(ColorBrush::new(color));
+        renderer.draw_text(text_render, Rectangle::new(x, y, size.width, size.height).scale(scale_factor), None, false);
+    }
+
+    /// The size of `text` in logical pixels.
+    pub(crate) fn measure(
+        &mut self,
+        text_context: &mut TextContext,
+        style: &Style,
+        text: &str,
+        scale_factor: f64,
+    ) -> Size<f32> {
+        self.label(text_context, style, text, scale_factor).1
+    }
+
+    fn label(
+        &mut self,
+        text_context: &mut TextContext,
+        style: &Style,
+        text: &str,
+        scale_factor: f64,
+    ) -> &(TextRender, Size<f32>) {
+        if self.scale_factor != scale_factor || !self.style.is_some_and(|last_style| same_font(&last_style, style)) {
+            self.labels.clear();
+            self.scale_factor = scale_factor;
+            self.style = Some(*style);
+        }
+
+        self.labels.entry(text.to_string()).or_insert_with(|| {
+            let mut builder = text_context.tree_builder(scale_factor as f32, &style.to_text_style());
+            builder.push_text(text);
+            let (mut layout, _) = builder.build();
+            layout.break_all_lines(None);
+            layout.align(None, Alignment::Start, AlignmentOptions::default());
+
+            let size = Size::new(layout.width() / scale_factor as f32, layout.height() / scale_factor as f32);
+            (text_render_data::from_editor(&layout), size)
+        })
+    }
+}
@@ -1,27 +1,63 @@
 pub mod text_context;
 pub(crate) mod text_render_data;
 pub(crate) mod parley_editor;
+pub(crate) mod edit_history;
+pub(crate) mod input_mask;
+pub(crate) mod tokenizer;
+pub(crate) mod text_selection;
+pub(crate) mod label_cache;
 
 use std::ops::Range;
 pub use parley;
 
-use crate::style::{Style, TextStyleProperty};
+use crate::style::{Direction, FontFeature, FontVariation, Style, TextAlign, TextStyleProperty, WhiteSpace};
 pub use text_render_data::from_editor;
 pub use text_render_data::TextRender;
+pub use tokenizer::Tokenizer;
 
 #[derive(PartialEq)]
 pub(crate) struct TextStyle {
     pub(crate) font_size: f32,
+    pub(crate) line_height: f32,
+    pub(crate) letter_spacing: f32,
+    pub(crate) word_spacing: f32,
+    pub(crate) text_align: TextAlign,
+    pub(crate) direction: Direction,
+    pub(crate) white_space: WhiteSpace,
+    pub(crate) font_features: Vec<FontFeature>,
+    pub(crate) font_variations: Vec<FontVariation>,
 }
 
 impl From<&Style> for TextStyle {
     fn from(style: &Style) -> Self {
         TextStyle {
             font_size: style.font_size(),
+            line_height: style.line_height(),
+            letter_spacing: style.letter_spacing(),
+            word_spacing: style.word_spacing(),
+            text_align: style.text_align(),
+            direction: style.direction(),
+            white_space: style.white_space(),
+            font_features: style.font_features().to_vec(),
+            font_variations: style.font_variations().to_vec(),
         }
     }
 }
 
+/// Whether two styles lay out text the same way.
+pub(crate) fn same_font(a: &Style, b: &Style) -> bool {
+    a.font_size() == b.font_size()
+        && a.font_weight() == b.font_weight()
+        && a.font_style() == b.font_style()
+        && a.font_oblique_angle() == b.font_oblique_angle()
+        && a.font_features() == b.font_features()
+        && a.font_variations() == b.font_variations()
+        && a.font_family() == b.font_family()
+        && a.line_height() == b.line_height()
+        && a.letter_spacing() == b.letter_spacing()
+        && a.word_spacing() == b.word_spacing()
+}
+
 #[derive(Clone)]
 #[derive(Default)]
 #[derive(PartialEq)]
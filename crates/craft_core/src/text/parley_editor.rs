@@ -9,7 +9,11 @@ use parley::{
 };
 
 extern crate alloc;
-use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::String,
+    vec::Vec,
+};
 use core::{
     cmp::PartialEq,
     default::Default,
@@ -22,7 +26,9 @@ use core::{
 use parley::layout::LayoutAccessibility;
 #[cfg(feature = "accesskit")]
 use accesskit::{Node, NodeId, TreeUpdate};
+use crate::style::{Direction, TextStyleProperty};
 use crate::text::RangedStyles;
+use crate::text::edit_history::{Edit, EditHistory};
 use crate::text::text_context::ColorBrush;
 
 /// Opaque representation of a generation.
@@ -111,7 +117,15 @@ pub struct PlainEditor
     compose: Option<Range<usize>>,
     /// Whether the cursor should be shown. The IME can request to hide the cursor.
     show_cursor: bool,
+    /// The character every character of the buffer is displayed as, e.g. for passwords.
+    /// The layout and the selection use the masked text, while the buffer keeps the real text.
+    mask: Option<char>,
+    /// The mark that gives the layout its base direction, which comes before the text in the layout.
+    direction_mark: &'static str,
+    history: EditHistory,
     width: Option<f32>,
+    /// Whether lines wrap at the width. The width is still used to align the lines otherwise.
+    wrap: bool,
     scale: f32,
     quantize: bool,
     // Simple tracking of when the layout needs to be updated
@@ -141,7 +155,11 @@ impl PlainEditor
             selection: Default::default(),
             compose: None,
             show_cursor: true,
+            mask: None,
+            direction_mark: Direction::Auto.mark(),
+            history: EditHistory::default(),
             width: None,
+            wrap: true,
             scale: 1.0,
             quantize: true,
             layout_dirty: true,
@@ -188,17 +206,17 @@ impl PlainEditorDriver<'_>
     /// No-op if the start of the range is not a char boundary.
     pub fn delete_bytes_before_selection(&mut self, len: NonZeroUsize) {
         let old_selection = self.editor.selection;
-        let selection_range = old_selection.text_range();
+        let selection_range = self.editor.buffer_range(old_selection.text_range());
         let range = selection_range.start.saturating_sub(len.get())..selection_range.start;
         if range.is_empty() || !self.editor.buffer.is_char_boundary(range.start) {
             return;
         }
-        self.editor.buffer.replace_range(range.clone(), "");
-        self.editor
-            .update_compose_for_replaced_range(range.clone(), 0);
-        self.update_layout();
         let old_anchor = old_selection.anchor();
         let old_focus = old_selection.focus();
+        let old_anchor_index = self.editor.buffer_index(old_anchor.index());
+        let old_focus_index = self.editor.buffer_index(old_focus.index());
+        self.editor.edit_buffer(range.clone(), "");
+        self.update_layout();
         // When doing the equivalent of a backspace on a collapsed selection,
         // always use downstream affinity, as `backdelete` does.
         let (anchor_affinity, focus_affinity) = if old_selection.is_collapsed() {
@@ -209,12 +227,12 @@ impl PlainEditorDriver<'_>
         self.editor.set_selection(Selection::new(
             Cursor::from_byte_index(
                 &self.editor.layout,
-                old_anchor.index() - range.len(),
+                self.editor.layout_index(old_anchor_index - range.len()),
                 anchor_affinity,
             ),
             Cursor::from_byte_index(
                 &self.editor.layout,
-                old_focus.index() - range.len(),
+                self.editor.layout_index(old_focus_index - range.len()),
                 focus_affinity,
             ),
         ));
@@ -226,7 +244,7 @@ impl PlainEditorDriver<'_>
     /// The deleted range is clamped to the end of the buffer.
     /// No-op if the end of the range is not a char boundary.
     pub fn delete_bytes_after_selection(&mut self, len: NonZeroUsize) {
-        let selection_range = self.editor.selection.text_range();
+        let selection_range = self.editor.buffer_range(self.editor.selection.text_range());
         let range = selection_range.end
             ..selection_range
             .end
@@ -235,8 +253,7 @@ impl PlainEditorDriver<'_>
         if range.is_empty() || !self.editor.buffer.is_char_boundary(range.end) {
             return;
         }
-        self.editor.buffer.replace_range(range.clone(), "");
-        self.editor.update_compose_for_replaced_range(range, 0);
+        self.editor.edit_buffer(range, "");
         self.update_layout();
     }
 
@@ -250,11 +267,10 @@ impl PlainEditorDriver<'_>
                 .focus()
                 .logical_clusters(&self.editor.layout)[1]
                 .as_ref()
-                .map(|cluster| cluster.text_range())
+                .map(|cluster| self.editor.buffer_range(cluster.text_range()))
                 .and_then(|range| (!range.is_empty()).then_some(range))
             {
-                self.editor.buffer.replace_range(range.clone(), "");
-                self.editor.update_compose_for_replaced_range(range, 0);
+                self.editor.edit_buffer(range, "");
                 self.update_layout();
             }
         } else {
@@ -266,15 +282,20 @@ impl PlainEditorDriver<'_>
     pub fn delete_word(&mut self) {
         if self.editor.selection.is_collapsed() {
             let focus = self.editor.selection.focus();
-            let start = focus.index();
-            let end = focus.next_logical_word(&self.editor.layout).index();
+            let start = self.editor.buffer_index(focus.index());
+            let end = self
+                .editor
+                .buffer_index(focus.next_logical_word(&self.editor.layout).index());
             if self.editor.buffer.get(start..end).is_some() {
-                self.editor.buffer.replace_range(start..end, "");
-                self.editor.update_compose_for_replaced_range(start..end, 0);
+                self.editor.edit_buffer(start..end, "");
                 self.update_layout();
                 self.editor.set_selection(
-                    Cursor::from_byte_index(&self.editor.layout, start, Affinity::Downstream)
-                        .into(),
+                    Cursor::from_byte_index(
+                        &self.editor.layout,
+                        self.editor.layout_index(start),
+                        Affinity::Downstream,
+                    )
+                    .into(),
                 );
             }
         } else {
@@ -293,7 +314,7 @@ impl PlainEditorDriver<'_>
                 .logical_clusters(&self.editor.layout)[0]
                 .clone()
             {
-                let range = cluster.text_range();
+                let range = self.editor.buffer_range(cluster.text_range());
                 let end = range.end;
                 let start = if cluster.is_hard_line_break() || cluster.is_emoji() {
                     // For newline sequences and emoji, delete the previous cluster
@@ -310,12 +331,15 @@ impl PlainEditorDriver<'_>
                     };
                     start
                 };
-                self.editor.buffer.replace_range(start..end, "");
-                self.editor.update_compose_for_replaced_range(start..end, 0);
+                self.editor.edit_buffer(start..end, "");
                 self.update_layout();
                 self.editor.set_selection(
-                    Cursor::from_byte_index(&self.editor.layout, start, Affinity::Downstream)
-                        .into(),
+                    Cursor::from_byte_index(
+                        &self.editor.layout,
+                        self.editor.layout_index(start),
+                        Affinity::Downstream,
+                    )
+                    .into(),
                 );
             }
         } else {
@@ -327,15 +351,20 @@ impl PlainEditorDriver<'_>
     pub fn backdelete_word(&mut self) {
         if self.editor.selection.is_collapsed() {
             let focus = self.editor.selection.focus();
-            let end = focus.index();
-            let start = focus.previous_logical_word(&self.editor.layout).index();
+            let end = self.editor.buffer_index(focus.index());
+            let start = self
+                .editor
+                .buffer_index(focus.previous_logical_word(&self.editor.layout).index());
             if self.editor.buffer.get(start..end).is_some() {
-                self.editor.buffer.replace_range(start..end, "");
-                self.editor.update_compose_for_replaced_range(start..end, 0);
+                self.editor.edit_buffer(start..end, "");
                 self.update_layout();
                 self.editor.set_selection(
-                    Cursor::from_byte_index(&self.editor.layout, start, Affinity::Downstream)
-                        .into(),
+                    Cursor::from_byte_index(
+                        &self.editor.layout,
+                        self.editor.layout_index(start),
+                        Affinity::Downstream,
+                    )
+                    .into(),
                 );
             }
         } else {
@@ -366,16 +395,14 @@ impl PlainEditorDriver<'_>
                 .replace_range(preedit_range.clone(), text);
             preedit_range.start
         } else {
-            if self.editor.selection.is_collapsed() {
-                self.editor
-                    .buffer
-                    .insert_str(self.editor.selection.text_range().start, text);
-            } else {
-                self.editor
-                    .buffer
-                    .replace_range(self.editor.selection.text_range(), text);
+            let selection_range = self.editor.buffer_range(self.editor.selection.text_range());
+            if !self.editor.selection.is_collapsed() {
+                self.editor.edit_buffer(selection_range.clone(), "");
             }
-            self.editor.selection.text_range().start
+            self.editor
+                .buffer
+                .insert_str(selection_range.start, text);
+            selection_range.start
         };
         self.editor.compose = Some(start..start + text.len());
         self.editor.show_cursor = cursor.is_some();
@@ -422,12 +449,83 @@ impl PlainEditorDriver<'_>
     /// This doesn't change the selection, but shows the cursor if
     /// it was hidden.
     pub fn finish_compose(&mut self) {
-        if self.editor.compose.take().is_some() {
+        if let Some(preedit_range) = self.editor.compose.take() {
+            self.editor.history.record(Edit {
+                start: preedit_range.start,
+                deleted: String::new(),
+                inserted: self.editor.buffer[preedit_range.clone()].to_owned(),
+                selection: (preedit_range.start, preedit_range.start),
+                typing: false,
+            });
             self.editor.show_cursor = true;
             self.update_layout();
         }
     }
 
+    // --- MARK: History ---
+    /// Undo the last edit, restoring the selection from before it.
+    ///
+    /// No-op while composing.
+    pub fn undo(&mut self) {
+        if self.editor.is_composing() {
+            return;
+        }
+        if let Some(edit) = self.editor.history.undo() {
+            let range = edit.start..edit.start + edit.inserted.len();
+            self.editor.buffer.replace_range(range, &edit.deleted);
+            self.update_layout();
+            let (anchor, focus) = edit.selection;
+            self.editor.set_selection(Selection::new(
+                self.editor.cursor_at(anchor),
+                self.editor.cursor_at(focus),
+            ));
+        }
+    }
+
+    /// Redo the last undone edit, placing the cursor after the inserted text.
+    ///
+    /// No-op while composing.
+    pub fn redo(&mut self) {
+        if self.editor.is_composing() {
+            return;
+        }
+        if let Some(edit) = self.editor.history.redo() {
+            let range = edit.start..edit.start + edit.deleted.len();
+            self.editor.buffer.replace_range(range, &edit.inserted);
+            self.update_layout();
+            self.editor
+                .set_selection(self.editor.cursor_at(edit.start + edit.inserted.len()).into());
+        }
+    }
+
+    /// Replace a range of byte indices with `s`, then select the byte range `selection` of the new text.
+    ///
+    /// No-op if either end of the range is not a char boundary.
+    pub fn replace_byte_range(&mut self, range: Range<usize>, s: &str, selection: Range<usize>) {
+        if !self.editor.buffer.is_char_boundary(range.start) || !self.editor.buffer.is_char_boundary(range.end) {
+            return;
+        }
+        self.editor.edit_buffer(range, s);
+        self.update_layout();
+        self.editor.set_selection(Selection::new(
+            self.editor.cursor_at(selection.start),
+            self.editor.cursor_at(selection.end),
+        ));
+    }
+
+    /// Replace the whole buffer with `s` as a part of the last edit, so that both are undone together,
+    /// and move the cursor to the byte index `cursor`.
+    ///
+    /// This is used to reformat the text after an edit.
+    pub fn amend_last_edit(&mut self, s: &str, cursor: usize) {
+        self.editor.history.amend(&self.editor.buffer, s);
+        self.editor.buffer.clear();
+        self.editor.buffer.push_str(s);
+        self.editor.compose = None;
+        self.update_layout();
+        self.editor.set_selection(self.editor.cursor_at(cursor).into());
+    }
+
     // --- MARK: Cursor Movement ---
     /// Move the cursor to the cluster boundary nearest this point in the layout.
     pub fn move_to_point(&mut self, x: f32, y: f32) {
@@ -755,13 +853,23 @@ impl PlainEditor
     }
 
     /// Borrow the current selection. The indices returned by functions
-    /// such as [`Selection::text_range`] refer to the raw text buffer,
-    /// including the IME preedit region, which can be accessed via
-    /// [`PlainEditor::raw_text`].
+    /// such as [`Selection::text_range`] refer to the laid out text, which
+    /// starts with the direction mark of the editor, and are masked if a mask is set.
+    /// Use [`PlainEditor::selection_byte_range`] for indices into the raw text buffer.
     pub fn raw_selection(&self) -> &Selection {
         &self.selection
     }
 
+    /// The byte index of the selection focus, which is where the caret is, in the raw text buffer.
+    pub fn focus_byte_index(&self) -> usize {
+        self.buffer_index(self.selection.focus().index())
+    }
+
+    /// The byte range of the selection in the raw text buffer.
+    pub fn selection_byte_range(&self) -> Range<usize> {
+        self.buffer_range(self.selection.text_range())
+    }
+
     /// Borrow the current IME preedit range, if any. These indices refer
     /// to the raw text buffer, which can be accessed via [`PlainEditor::raw_text`].
     pub fn raw_compose(&self) -> &Option<Range<usize>> {
@@ -775,7 +883,7 @@ impl PlainEditor
             return None;
         }
         if !self.selection.is_collapsed() {
-            self.buffer.get(self.selection.text_range())
+            self.buffer.get(self.buffer_range(self.selection.text_range()))
         } else {
             None
         }
@@ -895,19 +1003,56 @@ impl PlainEditor
     }
 
     /// Replace the whole text buffer.
+    ///
+    /// The edit history is cleared if the text changes.
     pub fn set_text(&mut self, is: &str) {
+        if self.buffer != is {
+            self.history.clear();
+        }
         self.buffer.clear();
         self.buffer.push_str(is);
         self.layout_dirty = true;
         self.compose = None;
     }
 
+    /// Display every character of the text as `mask`, e.g. for passwords.
+    ///
+    /// The buffer and [`text`](Self::text) keep the real text.
+    pub fn set_mask(&mut self, mask: Option<char>) {
+        if self.mask != mask {
+            self.mask = mask;
+            self.layout_dirty = true;
+        }
+    }
+
+    /// The character the text is displayed as, if any.
+    pub fn mask(&self) -> Option<char> {
+        self.mask
+    }
+
+    /// Set the base direction of the text, which decides the side that the text starts on and the order of
+    /// the runs of mixed-direction text.
+    pub fn set_direction(&mut self, direction: Direction) {
+        if self.direction_mark != direction.mark() {
+            self.direction_mark = direction.mark();
+            // The indices of the selection are offset by the mark, so the selection starts over.
+            self.selection = Selection::default();
+            self.layout_dirty = true;
+        }
+    }
+
     /// Set the width of the layout.
     pub fn set_width(&mut self, width: Option<f32>) {
         self.width = width;
         self.layout_dirty = true;
     }
 
+    /// Set whether the lines wrap at the width of the layout.
+    pub fn set_wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+        self.layout_dirty = true;
+    }
+
     /// Set the alignment of the layout.
     pub fn set_alignment(&mut self, alignment: Alignment) {
         self.alignment = alignment;
@@ -957,6 +1102,16 @@ impl PlainEditor
     }
 
 
+    /// Whether there is an edit to undo.
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+
+    /// Whether there is an undone edit to redo.
+    pub fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+
     /// Whether the editor is currently in IME composing mode.
     pub fn is_composing(&self) -> bool {
         self.compose.is_some()
@@ -1032,13 +1187,84 @@ impl PlainEditor
     fn cursor_at(&self, index: usize) -> Cursor {
         // TODO: Do we need to be non-dirty?
         // FIXME: `Selection` should make this easier
-        if index >= self.buffer.len() {
-            Cursor::from_byte_index(&self.layout, self.buffer.len(), Affinity::Upstream)
+        let end = self.layout_index(self.buffer.len());
+        let index = self.layout_index(index);
+        if index >= end {
+            Cursor::from_byte_index(&self.layout, end, Affinity::Upstream)
         } else {
             Cursor::from_byte_index(&self.layout, index, Affinity::Downstream)
         }
     }
 
+    /// The text the layout is built from, which is the direction mark followed by the buffer with every character
+    /// masked if a mask is set.
+    fn display_text<'a>(buffer: &'a str, mask: Option<char>, direction_mark: &str) -> Cow<'a, str> {
+        match mask {
+            Some(mask) => {
+                Cow::Owned(direction_mark.chars().chain(core::iter::repeat_n(mask, buffer.chars().count())).collect())
+            }
+            None if direction_mark.is_empty() => Cow::Borrowed(buffer),
+            None => Cow::Owned(format!("{direction_mark}{buffer}")),
+        }
+    }
+
+    /// Convert a byte index into the buffer to a byte index into the layout text.
+    fn layout_index(&self, index: usize) -> usize {
+        let index = match self.mask {
+            Some(mask) => {
+                self.buffer
+                    .char_indices()
+                    .take_while(|(i, _)| *i < index)
+                    .count()
+                    * mask.len_utf8()
+            }
+            None => index,
+        };
+        self.direction_mark.len() + index
+    }
+
+    /// Convert a byte index into the layout text to a byte index into the buffer.
+    pub(crate) fn buffer_index(&self, index: usize) -> usize {
+        let index = index.saturating_sub(self.direction_mark.len());
+        match self.mask {
+            Some(mask) => self
+                .buffer
+                .char_indices()
+                .nth(index / mask.len_utf8())
+                .map_or(self.buffer.len(), |(i, _)| i),
+            None => index,
+        }
+    }
+
+    fn buffer_range(&self, range: Range<usize>) -> Range<usize> {
+        self.buffer_index(range.start)..self.buffer_index(range.end)
+    }
+
+    fn layout_range(&self, range: Range<usize>) -> Range<usize> {
+        self.layout_index(range.start)..self.layout_index(range.end)
+    }
+
+    /// Replace a byte range of the buffer with `text`, recording the edit in the history.
+    fn edit_buffer(&mut self, range: Range<usize>, text: &str) {
+        // Edits that change nothing are not recorded, so they don't take an undo of their own.
+        if self.buffer[range.clone()] == *text {
+            return;
+        }
+        let typing = range.is_empty() && text.chars().count() == 1 && text != "\n";
+        self.history.record(Edit {
+            start: range.start,
+            deleted: self.buffer[range.clone()].to_owned(),
+            inserted: text.to_owned(),
+            selection: (
+                self.buffer_index(self.selection.anchor().index()),
+                self.buffer_index(self.selection.focus().index()),
+            ),
+            typing,
+        });
+        self.buffer.replace_range(range.clone(), text);
+        self.update_compose_for_replaced_range(range, text.len());
+    }
+
     fn update_compose_for_replaced_range(&mut self, old_range: Range<usize>, new_len: usize) {
         if new_len == old_range.len() {
             return;
@@ -1074,17 +1300,12 @@ impl PlainEditor
         layout_cx: &mut LayoutContext<ColorBrush>,
         s: &str,
     ) {
-        let range = self.selection.text_range();
+        let range = self.buffer_range(self.selection.text_range());
         let start = range.start;
-        if self.selection.is_collapsed() {
-            self.buffer.insert_str(start, s);
-        } else {
-            self.buffer.replace_range(range.clone(), s);
-        }
-        self.update_compose_for_replaced_range(range, s.len());
+        self.edit_buffer(range, s);
 
         self.update_layout(font_cx, layout_cx);
-        let new_index = start.saturating_add(s.len());
+        let new_index = self.layout_index(start.saturating_add(s.len()));
         let affinity = if s.ends_with("\n") {
             Affinity::Downstream
         } else {
@@ -1095,6 +1316,7 @@ impl PlainEditor
 
     /// Update the selection, and nudge the `Generation` if something other than `h_pos` changed.
     fn set_selection(&mut self, new_sel: Selection) {
+        let new_sel = self.skip_direction_mark(new_sel);
         if new_sel.focus() != self.selection.focus() || new_sel.anchor() != self.selection.anchor()
         {
             self.generation.nudge();
@@ -1132,26 +1354,59 @@ impl PlainEditor
         }
         self.selection = new_sel;
     }
+    /// Moves the cursors that are before the direction mark after it, so that the mark can't be selected or take
+    /// an extra key press to move over.
+    fn skip_direction_mark(&self, selection: Selection) -> Selection {
+        let start = self.direction_mark.len();
+        if selection.anchor().index() >= start && selection.focus().index() >= start {
+            return selection;
+        }
+
+        let skip = |cursor: Cursor| {
+            if cursor.index() < start {
+                Cursor::from_byte_index(&self.layout, start, Affinity::Downstream)
+            } else {
+                cursor
+            }
+        };
+        Selection::new(skip(selection.anchor()), skip(selection.focus()))
+    }
+
     /// Update the layout.
     fn update_layout(&mut self, font_cx: &mut FontContext, layout_cx: &mut LayoutContext<ColorBrush>) {
+        let text = Self::display_text(&self.buffer, self.mask, self.direction_mark);
         let mut builder =
-            layout_cx.ranged_builder(font_cx, &self.buffer, self.scale, self.quantize);
+            layout_cx.ranged_builder(font_cx, &text, self.scale, self.quantize);
         for prop in self.default_style.inner().values() {
             builder.push_default(prop.to_owned());
         }
 
+        let brush = self
+            .default_style
+            .inner()
+            .values()
+            .find_map(|prop| match prop {
+                StyleProperty::Brush(brush) => Some(*brush),
+                _ => None,
+            })
+            .unwrap_or_default();
         for (range, style) in &self.ranged_styles.styles {
-            builder.push(style.to_parley_style_property(), range.clone())
+            TextStyleProperty::push_to_builder(
+                core::slice::from_ref(style),
+                brush,
+                self.layout_range(range.clone()),
+                &mut builder,
+            );
         }
 
         if let Some(preedit_range) = &self.compose {
-            builder.push(StyleProperty::Underline(true), preedit_range.clone());
+            builder.push(StyleProperty::Underline(true), self.layout_range(preedit_range.clone()));
         }
-        self.layout = builder.build(&self.buffer);
-        self.layout.break_all_lines(self.width);
+        self.layout = builder.build(&text);
+        self.layout.break_all_lines(if self.wrap { self.width } else { None });
         self.layout
             .align(self.width, self.alignment, AlignmentOptions::default());
-        self.selection = self.selection.refresh(&self.layout);
+        self.selection = self.skip_direction_mark(self.selection.refresh(&self.layout));
         self.layout_dirty = false;
         self.generation.nudge();
     }
@@ -1172,8 +1427,9 @@ impl PlainEditor
         x_offset: f64,
         y_offset: f64,
     ) {
+        let text = Self::display_text(&self.buffer, self.mask, self.direction_mark);
         self.layout_access.build_nodes(
-            &self.buffer,
+            &text,
             &self.layout,
             update,
             node,
@@ -1194,3 +1450,102 @@ impl PlainEditor
         node.add_action(accesskit::Action::SetTextSelection);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::PlainEditor;
+    use crate::style::{Direction, TextAlign};
+    use crate::text::text_context::TextContext;
+
+    fn editor(text: &str, direction: Direction) -> PlainEditor {
+        let mut editor = PlainEditor::new(16.0);
+        editor.set_text(text);
+        editor.set_width(Some(400.0));
+        editor.set_direction(direction);
+        editor.set_alignment(TextAlign::Start.to_parley_alignment(direction));
+        editor
+    }
+
+    fn caret_x(editor: &mut PlainEditor, text_context: &mut TextContext) -> f64 {
+        editor.refresh_layout(&mut text_context.font_context, &mut text_context.layout_context);
+        editor.cursor_geometry(1.0).unwrap().x0
+    }
+
+    #[test]
+    fn indices_skip_the_direction_mark() {
+        let mut text_context = TextContext::with_test_font();
+        let mut editor = editor("abc", Direction::Rtl);
+        let mut driver = editor.driver(&mut text_context.font_context, &mut text_context.layout_context);
+
+        driver.move_to_text_start();
+        assert_eq!(driver.editor.focus_byte_index(), 0);
+        driver.move_left();
+        driver.move_left();
+        driver.backdelete();
+        assert_eq!(driver.editor.raw_text(), "abc");
+
+        driver.insert_or_replace_selection("x");
+        assert_eq!(driver.editor.raw_text(), "xabc");
+        assert_eq!(driver.editor.selection_byte_range(), 1..1);
+
+        driver.move_to_text_end();
+        assert_eq!(driver.editor.focus_byte_index(), 4);
+        driver.select_to_text_start();
+        assert_eq!(driver.editor.selected_text(), Some("xabc"));
+    }
+
+    #[test]
+    fn rtl_caret_starts_on_the_right() {
+        let mut text_context = TextContext::with_test_font();
+
+        let mut rtl_editor = editor("", Direction::Rtl);
+        assert!(caret_x(&mut rtl_editor, &mut text_context) > 200.0);
+
+        let mut ltr_editor = editor("", Direction::Ltr);
+        assert!(caret_x(&mut ltr_editor, &mut text_context) < 200.0);
+    }
+
+    #[test]
+    fn base_direction_orders_the_runs() {
+        let mut text_context = TextContext::with_test_font();
+        // "abc" followed by three Hebrew letters, which are two bytes each.
+        let text = "abc \u{5D0}\u{5D1}\u{5D2}";
+
+        for (direction, latin_first) in [(Direction::Ltr, true), (Direction::Rtl, false), (Direction::Auto, true)] {
+            let mut editor = editor(text, direction);
+            let mut caret_x_at = |index: usize| {
+                editor.driver(&mut text_context.font_context, &mut text_context.layout_context).move_to_byte(index);
+                caret_x(&mut editor, &mut text_context)
+            };
+            let latin_x = caret_x_at(0);
+            let hebrew_x = caret_x_at(6);
+
+            assert_eq!(latin_x < hebrew_x, latin_first, "{direction:?}");
+        }
+    }
+
+    #[test]
+    fn moving_right_across_an_rtl_run_moves_back_in_the_text() {
+        let mut text_context = TextContext::with_test_font();
+        let text = "abc \u{5D0}\u{5D1}\u{5D2}";
+
+        for direction in [Direction::Ltr, Direction::Rtl] {
+            let mut editor = editor(text, direction);
+            let mut driver = editor.driver(&mut text_context.font_context, &mut text_context.layout_context);
+            driver.move_to_text_start();
+
+            let mut visited = vec![driver.editor.focus_byte_index()];
+            for _ in 0..text.chars().count() * 2 {
+                driver.move_right();
+                let index = driver.editor.focus_byte_index();
+                assert!(text.is_char_boundary(index), "{direction:?}: {index}");
+                visited.push(index);
+            }
+
+            // The boundaries between the Hebrew letters are visited from the last letter to the first.
+            let position = |index: usize| visited.iter().position(|visited| *visited == index);
+            assert!(position(8).is_some() && position(6).is_some(), "{direction:?}: {visited:?}");
+            assert!(position(8) < position(6), "{direction:?}: {visited:?}");
+        }
+    }
+}
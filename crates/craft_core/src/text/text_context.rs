@@ -1,4 +1,8 @@
+use crate::style::{TextDecoration, TextDecorationStyle};
+use crate::options::FontOptions;
+use parley::fontique::{Collection, CollectionOptions, FamilyId, FontInfoOverride, Script};
 use parley::{FontContext, TextStyle, TreeBuilder};
+use std::sync::Arc;
 
 pub struct TextContext {
     pub font_context: FontContext,
@@ -8,19 +12,26 @@ pub struct TextContext {
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ColorBrush {
     pub(crate) color: peniko::Color,
+    // Parley doesn't support these, so they are carried by the brush of the text.
+    pub(crate) underline_style: TextDecorationStyle,
+    pub(crate) strikethrough_style: TextDecorationStyle,
+    pub(crate) overline: Option<TextDecoration>,
 }
 
 impl ColorBrush {
     pub fn new(color: peniko::Color) -> Self {
-        Self { color }
+        Self {
+            color,
+            underline_style: TextDecorationStyle::default(),
+            strikethrough_style: TextDecorationStyle::default(),
+            overline: None,
+        }
     }
 }
 
 impl Default for ColorBrush {
     fn default() -> Self {
-        Self {
-            color: peniko::Color::BLACK,
-        }
+        Self::new(peniko::Color::BLACK)
     }
 }
 
@@ -38,6 +49,31 @@ impl TextContext {
         }
     }
 
+    /// Creates a text context without the fonts installed on the system.
+    pub fn without_system_fonts() -> Self {
+        let mut text_context = Self::new();
+        text_context.font_context.collection = Collection::new(CollectionOptions {
+            shared: false,
+            system_fonts: false,
+        });
+        text_context
+    }
+
+    /// Creates a text context with only the bundled Roboto font, which is both the sans-serif and the system UI
+    /// family, so that tests lay text out the same on every machine.
+    #[cfg(test)]
+    pub(crate) fn with_test_font() -> Self {
+        let mut text_context = Self::without_system_fonts();
+        let data = include_bytes!("../../../../fonts/Roboto-VariableFont_wdth,wght.ttf");
+        let families = text_context.register_font(data.to_vec(), None);
+        let collection = &mut text_context.font_context.collection;
+        let family_ids: Vec<FamilyId> = families.iter().filter_map(|family| collection.family_id(family)).collect();
+        for generic_family in [parley::GenericFamily::SansSerif, parley::GenericFamily::SystemUi] {
+            collection.set_generic_families(generic_family, family_ids.iter().copied());
+        }
+        text_context
+    }
+
     pub fn tree_builder<'a>(
         &'a mut self,
         scale: f32,
@@ -45,4 +81,62 @@ impl TextContext {
     ) -> TreeBuilder<'a, ColorBrush> {
         self.layout_context.tree_builder(&mut self.font_context, scale, true, raw_style)
     }
+
+    /// Registers the fonts in `data`, under the `family` alias if there is one, and returns their family names.
+    pub fn register_font(&mut self, data: Vec<u8>, family: Option<&str>) -> Vec<String> {
+        let info_override = family.map(|family| FontInfoOverride {
+            family_name: Some(family),
+            ..Default::default()
+        });
+        let fonts = self.font_context.collection.register_fonts(peniko::Blob::new(Arc::new(data)), info_override);
+
+        fonts
+            .iter()
+            .filter_map(|(family_id, _)| self.font_context.collection.family_name(*family_id))
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Registers the bundled fonts and maps the default, generic and fallback families of `options`.
+    ///
+    /// Families that are not found are skipped.
+    pub(crate) fn configure_fonts(&mut self, options: &FontOptions) {
+        for font in &options.bundled_fonts {
+            self.register_font(font.to_vec(), None);
+        }
+
+        let collection = &mut self.font_context.collection;
+        let mut family_ids = |families: &[String]| -> Vec<FamilyId> {
+            families.iter().filter_map(|family| collection.family_id(family)).collect()
+        };
+
+        let generic_families: Vec<_> = options
+            .generic_families
+            .iter()
+            .map(|(generic_family, families)| (generic_family.to_parley(), family_ids(families)))
+            .collect();
+        let default_family = options.default_family.as_ref().map(|family| family_ids(std::slice::from_ref(family)));
+        let fallback_families: Vec<_> = options
+            .fallback_families
+            .iter()
+            .map(|(script, families)| (*script, family_ids(families)))
+            .collect();
+
+        for (generic_family, families) in generic_families {
+            collection.set_generic_families(generic_family, families.into_iter());
+        }
+
+        if let Some(default_family) = default_family {
+            let system_ui = parley::GenericFamily::SystemUi;
+            let families: Vec<FamilyId> =
+                default_family.into_iter().chain(collection.generic_families(system_ui)).collect();
+            collection.set_generic_families(system_ui, families.into_iter());
+        }
+
+        for (script, families) in fallback_families {
+            for tag in script.tags() {
+                collection.set_fallbacks(Script(tag), families.iter().copied());
+            }
+        }
+    }
 }
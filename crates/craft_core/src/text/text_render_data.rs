@@ -1,7 +1,8 @@
 use crate::geometry::Rectangle;
+use crate::style::TextDecorationStyle;
 use crate::text::text_context::ColorBrush;
 use parley::{Layout, PositionedLayoutItem};
-use peniko::kurbo::{Affine, Line};
+use peniko::kurbo::{Affine, BezPath, Line};
 
 #[derive(Clone, Debug)]
 pub struct TextRender {
@@ -19,10 +20,9 @@ pub struct TextRenderLine {
 #[derive(Clone, Debug)]
 pub struct TextRenderItem {
     pub brush: ColorBrush,
-    #[allow(dead_code)]
     pub underline: Option<TextRenderItemLine>,
-    #[allow(dead_code)]
     pub strikethrough: Option<TextRenderItemLine>,
+    pub overline: Option<TextRenderItemLine>,
     #[allow(dead_code)]
     pub glyph_transform: Option<Affine>,
     pub font_size: f32,
@@ -33,10 +33,58 @@ pub struct TextRenderItem {
 #[derive(Clone, Copy, Debug)]
 pub struct TextRenderItemLine {
     pub brush: ColorBrush,
-    #[allow(dead_code)]
     pub line: Line,
-    #[allow(dead_code)]
     pub width: f32,
+    pub style: TextDecorationStyle,
+}
+
+impl TextRenderItemLine {
+    /// The path of the decoration, which is stroked with its width by every renderer.
+    pub fn to_path(&self) -> BezPath {
+        let (start, end, y) = (self.line.p0.x, self.line.p1.x, self.line.p0.y);
+        // Keep the patterns visible for hairlines.
+        let width = (self.width as f64).max(1.0);
+        let mut path = BezPath::new();
+
+        match self.style {
+            TextDecorationStyle::Solid => {
+                path.move_to((start, y));
+                path.line_to((end, y));
+            }
+            TextDecorationStyle::Double => {
+                for y in [y - width, y + width] {
+                    path.move_to((start, y));
+                    path.line_to((end, y));
+                }
+            }
+            TextDecorationStyle::Dotted | TextDecorationStyle::Dashed => {
+                let (dash, gap) =
+                    if self.style == TextDecorationStyle::Dotted { (width, width) } else { (width * 3.0, width * 2.0) };
+                let mut x = start;
+                while x < end {
+                    path.move_to((x, y));
+                    path.line_to(((x + dash).min(end), y));
+                    x += dash + gap;
+                }
+            }
+            TextDecorationStyle::Wavy => {
+                let half_period = width * 2.0;
+                let amplitude = width * 1.5;
+                let mut x = start;
+                let mut up = true;
+                path.move_to((x, y));
+                while x < end {
+                    let next = (x + half_period).min(end);
+                    let peak = if up { y - amplitude } else { y + amplitude };
+                    path.quad_to(((x + next) / 2.0, peak), (next, y));
+                    x = next;
+                    up = !up;
+                }
+            }
+        }
+
+        path
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -65,7 +113,7 @@ pub fn from_editor(layout: &Layout<ColorBrush>) -> TextRender {
             };
 
             let style = glyph_run.style();
-            // We draw underlines under the text, then the strikethrough on top, following:
+            // We draw underlines and overlines under the text, then the strikethrough on top, following:
             // https://drafts.csswg.org/css-text-decor/#painting-order
             let underline: Option<TextRenderItemLine> = if let Some(underline) = &style.underline {
                 let underline_brush = underline.brush;
@@ -89,7 +137,7 @@ pub fn from_editor(layout: &Layout<ColorBrush>) -> TextRender {
                     (glyph_run.offset() as f64, y as f64),
                     ((glyph_run.offset() + glyph_run.advance()) as f64, y as f64),
                 );
-                Some(TextRenderItemLine { line, width, brush: underline_brush })
+                Some(TextRenderItemLine { line, width, brush: underline_brush, style: style.brush.underline_style })
             } else {
                 None
             };
@@ -134,15 +182,36 @@ pub fn from_editor(layout: &Layout<ColorBrush>) -> TextRender {
                     (glyph_run.offset() as f64, y as f64),
                     ((glyph_run.offset() + glyph_run.advance()) as f64, y as f64),
                 );
-                Some(TextRenderItemLine { line, width, brush: strikethrough_brush })
+                Some(TextRenderItemLine {
+                    line,
+                    width,
+                    brush: strikethrough_brush,
+                    style: style.brush.strikethrough_style,
+                })
             } else {
                 None
             };
 
+            // Parley doesn't lay out overlines, so they are carried by the brush and placed at the ascent by default.
+            let overline = style.brush.overline.map(|overline| {
+                let run_metrics = glyph_run.run().metrics();
+                let offset = overline.offset.unwrap_or(run_metrics.ascent);
+                let width = overline.thickness.unwrap_or(run_metrics.underline_size);
+                // The `offset` is the distance from the baseline to the top of the overline.
+                let y = glyph_run.baseline() - offset + width / 2.;
+
+                let line = Line::new(
+                    (glyph_run.offset() as f64, y as f64),
+                    ((glyph_run.offset() + glyph_run.advance()) as f64, y as f64),
+                );
+                TextRenderItemLine { line, width, brush: ColorBrush::new(overline.color), style: overline.style }
+            });
+
             let text_render_item = TextRenderItem {
                 brush: style.brush,
                 underline,
                 strikethrough,
+                overline,
                 glyph_transform: glyph_xform,
                 font_size,
                 glyphs: glyphs.collect(),
@@ -0,0 +1,336 @@
+//! Selection of text across the [`Text`] elements of a tree.
+
+use crate::components::ComponentId;
+use crate::elements::text::TextState;
+use crate::elements::{Element, Text};
+use crate::geometry::Point;
+use crate::reactive::element_state_store::ElementStateStore;
+
+/// A selection that is dragged from one `Text` over the texts that follow or precede it in tree order.
+///
+/// Each text keeps the part of the selection that it contains, so it is highlighted like a selection within
+/// a single text.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct TextSelection {
+    /// The text that the selection was started in, and the byte index in it.
+    anchor: Option<(ComponentId, usize)>,
+}
+
+impl TextSelection {
+    /// Starts a selection in the text that the pointer was pressed on, if any, and clears the selections of the
+    /// other texts.
+    pub(crate) fn start(&mut self, root: &dyn Element, element_state: &mut ElementStateStore) {
+        self.anchor = None;
+        for text in selectable_texts(root) {
+            let id = text.element_data().component_id;
+            let Some(state) = text_state_mut(element_state, id) else {
+                continue;
+            };
+            if state.is_dragging_selection() {
+                self.anchor = Some((id, state.selection_anchor()));
+            } else if !state.pointer_down {
+                state.clear_selection();
+            }
+        }
+    }
+
+    /// Stops extending the selection when the pointer is released, the texts keep their selections.
+    pub(crate) fn end(&mut self) {
+        self.anchor = None;
+    }
+
+    /// Extends the selection that is being dragged to the text at `pointer`.
+    ///
+    /// The texts between the anchor and the text at `pointer` in tree order are selected entirely, which is the
+    /// reading order unless the texts are reversed or positioned out of order.
+    pub(crate) fn extend(&mut self, root: &dyn Element, element_state: &mut ElementStateStore, pointer: Point) {
+        let Some((anchor_id, anchor_index)) = self.anchor else {
+            return;
+        };
+        let texts = selectable_texts(root);
+        let Some(anchor) = texts.iter().position(|text| text.element_data().component_id == anchor_id) else {
+            self.anchor = None;
+            return;
+        };
+        if !text_state_mut(element_state, anchor_id).is_some_and(|state| state.is_dragging_selection()) {
+            return;
+        }
+
+        // The text under the pointer, or else the last text that starts above it.
+        let focus = texts
+            .iter()
+            .position(|text| text.in_bounds(pointer))
+            .or_else(|| {
+                texts.iter().rposition(|text| text.computed_box_transformed().content_rectangle().y as f64 <= pointer.y)
+            })
+            .unwrap_or(0);
+        let focus_rectangle = texts[focus].computed_box_transformed().content_rectangle();
+        let focus_point = Point::new(pointer.x - focus_rectangle.x as f64, pointer.y - focus_rectangle.y as f64);
+        let Some(focus_index) = text_state_mut(element_state, texts[focus].element_data().component_id)
+            .map(|state| state.index_at_point(focus_point))
+        else {
+            return;
+        };
+
+        let forward = anchor <= focus;
+        for (index, text) in texts.iter().enumerate() {
+            let Some(state) = text_state_mut(element_state, text.element_data().component_id) else {
+                continue;
+            };
+            if index == anchor && index == focus {
+                state.select_range(anchor_index, focus_index);
+            } else if index == anchor {
+                state.select_range(anchor_index, if forward { usize::MAX } else { 0 });
+            } else if index == focus {
+                state.select_range(if forward { 0 } else { usize::MAX }, focus_index);
+            } else if index > anchor.min(focus) && index < anchor.max(focus) {
+                state.select_all();
+            } else {
+                state.clear_selection();
+            }
+        }
+    }
+}
+
+/// Selects all the text in the selectable texts under `root`, and clears the selections of all other texts.
+pub(crate) fn select_all_text(root: &dyn Element, element_state: &mut ElementStateStore) {
+    for item in element_state.storage.values_mut() {
+        if let Some(state) = item.data.downcast_mut::<TextState>() {
+            state.clear_selection();
+        }
+    }
+    for text in selectable_texts(root) {
+        if let Some(state) = text_state_mut(element_state, text.element_data().component_id) {
+            state.select_all();
+        }
+    }
+}
+
+/// The selected text of the texts under `root`.
+///
+/// The texts are separated by a line break when a text starts below the previous one.
+pub(crate) fn selected_text(root: &dyn Element, element_state: &ElementStateStore) -> Option<String> {
+    let mut selected = String::new();
+    let mut previous_bottom: Option<f32> = None;
+
+    for text in selectable_texts(root) {
+        let Some(state) = element_state
+            .storage
+            .get(&text.element_data().component_id)
+            .and_then(|item| item.data.downcast_ref::<TextState>())
+        else {
+            continue;
+        };
+        let Some(text_selection) = state.selected_text() else {
+            continue;
+        };
+
+        let rectangle = text.computed_box_transformed().content_rectangle();
+        if previous_bottom.is_some_and(|bottom| rectangle.y >= bottom) {
+            selected.push('\n');
+        }
+        selected.push_str(text_selection);
+        previous_bottom = Some(rectangle.y + rectangle.height);
+    }
+
+    (!selected.is_empty()).then_some(selected)
+}
+
+/// The selectable `Text` elements under `root` in tree order.
+fn selectable_texts(root: &dyn Element) -> Vec<&Text> {
+    fn collect<'a>(element: &'a dyn Element, texts: &mut Vec<&'a Text>) {
+        if let Some(text) = element.as_any().downcast_ref::<Text>().filter(|text| text.is_selectable()) {
+            texts.push(text);
+        }
+        for child in element.children() {
+            collect(child, texts);
+        }
+    }
+
+    let mut texts = Vec::new();
+    collect(root, &mut texts);
+    texts
+}
+
+fn text_state_mut(element_state: &mut ElementStateStore, id: ComponentId) -> Option<&mut TextState> {
+    element_state.storage.get_mut(&id).and_then(|item| item.data.downcast_mut::<TextState>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{select_all_text, selectable_texts, selected_text, text_state_mut, TextSelection};
+    use crate::app::layout;
+    use crate::components::ComponentSpecification;
+    use crate::craft_runtime::CraftRuntime;
+    use crate::elements::{Container, Element, ElementStyles, Text};
+    use crate::events::update_queue_entry::UpdateQueueEntry;
+    use crate::geometry::Point;
+    use crate::reactive::element_id::reset_unique_element_id;
+    use crate::reactive::tree::diff_trees;
+    use crate::resource_manager::ResourceManager;
+    use crate::style::FlexDirection;
+    use crate::text::text_context::TextContext;
+    use crate::window_context::WindowContext;
+    use crate::{GlobalState, ReactiveTree};
+    use std::any::Any;
+    use std::collections::VecDeque;
+    use std::sync::Arc;
+    use winit::dpi::LogicalSize;
+
+    /// Lays out `view` in a 400x300 window.
+    fn laid_out(view: ComponentSpecification, text_context: &mut TextContext) -> ReactiveTree {
+        reset_unique_element_id();
+        let mut reactive_tree = ReactiveTree::default();
+        let mut global_state = GlobalState::from(Box::new(()) as Box<dyn Any + Send>);
+        let mut window_context = WindowContext::new();
+        let mut update_queue: VecDeque<UpdateQueueEntry> = VecDeque::new();
+
+        let tree = diff_trees(
+            view,
+            Vec::new(),
+            Container::new().into(),
+            None,
+            &mut reactive_tree.user_state,
+            &mut global_state,
+            &mut reactive_tree.element_state,
+            false,
+            text_context,
+            1.0,
+            &mut window_context,
+            &mut update_queue,
+        );
+        let mut root = tree.element_tree.internal;
+
+        let runtime = CraftRuntime::new();
+        let (app_sender, _app_receiver) = tokio::sync::mpsc::channel(1);
+        let resource_manager = Arc::new(ResourceManager::new(app_sender, runtime.handle()));
+        layout(
+            &mut reactive_tree.element_state,
+            LogicalSize::new(400.0, 300.0),
+            text_context,
+            root.as_mut(),
+            Point::new(0.0, 0.0),
+            resource_manager,
+            1.0,
+            None,
+        );
+
+        reactive_tree.element_tree = Some(root);
+        reactive_tree
+    }
+
+    fn texts(texts: &[&str], flex_direction: FlexDirection) -> ComponentSpecification {
+        let mut container = Container::new().flex_direction(flex_direction).component();
+        for text in texts {
+            container = container.push(Text::new(text).component());
+        }
+        container
+    }
+
+    /// Starts dragging a selection at the byte index `index` of the `text`th text.
+    fn start_drag(reactive_tree: &mut ReactiveTree, text: usize, index: usize) -> TextSelection {
+        let root = reactive_tree.element_tree.as_deref().unwrap();
+        let id = selectable_texts(root)[text].element_data().component_id;
+        let state = text_state_mut(&mut reactive_tree.element_state, id).unwrap();
+        state.select_range(index, index);
+        state.pointer_down = true;
+        state.click_count = 1;
+
+        let mut text_selection = TextSelection::default();
+        text_selection.start(root, &mut reactive_tree.element_state);
+        text_selection
+    }
+
+    /// A point in the content box of the `text`th text, at `x` from its left and half way down.
+    fn point_in(reactive_tree: &ReactiveTree, text: usize, x: f64) -> Point {
+        let root = reactive_tree.element_tree.as_deref().unwrap();
+        let rectangle = selectable_texts(root)[text].computed_box_transformed().content_rectangle();
+        Point::new(rectangle.x as f64 + x, (rectangle.y + rectangle.height / 2.0) as f64)
+    }
+
+    fn select_all(reactive_tree: &mut ReactiveTree) {
+        let root = reactive_tree.element_tree.as_deref().unwrap();
+        select_all_text(root, &mut reactive_tree.element_state);
+    }
+
+    fn selected(reactive_tree: &ReactiveTree) -> Option<String> {
+        selected_text(reactive_tree.element_tree.as_deref().unwrap(), &reactive_tree.element_state)
+    }
+
+    #[test]
+    fn stacked_texts_are_joined_with_line_breaks() {
+        let mut text_context = TextContext::with_test_font();
+        let mut reactive_tree = laid_out(texts(&["one", "two"], FlexDirection::Column), &mut text_context);
+
+        select_all(&mut reactive_tree);
+        assert_eq!(selected(&reactive_tree).as_deref(), Some("one\ntwo"));
+    }
+
+    #[test]
+    fn inline_texts_are_joined_without_a_separator() {
+        let mut text_context = TextContext::with_test_font();
+        let mut reactive_tree = laid_out(texts(&["one", "two"], FlexDirection::Row), &mut text_context);
+
+        select_all(&mut reactive_tree);
+        assert_eq!(selected(&reactive_tree).as_deref(), Some("onetwo"));
+    }
+
+    #[test]
+    fn select_all_clears_the_texts_outside_of_the_container() {
+        let mut text_context = TextContext::with_test_font();
+        let view = Container::new()
+            .flex_direction(FlexDirection::Column)
+            .component()
+            .push(Text::new("outside").component())
+            .push(texts(&["inside"], FlexDirection::Column));
+        let mut reactive_tree = laid_out(view, &mut text_context);
+
+        select_all(&mut reactive_tree);
+        let root = reactive_tree.element_tree.as_deref().unwrap();
+        let inner_container = root.children()[0].children()[1];
+        select_all_text(inner_container, &mut reactive_tree.element_state);
+
+        assert_eq!(selected(&reactive_tree).as_deref(), Some("inside"));
+    }
+
+    #[test]
+    fn dragging_forward_selects_the_texts_in_between() {
+        let mut text_context = TextContext::with_test_font();
+        let mut reactive_tree = laid_out(texts(&["one", "two", "three"], FlexDirection::Column), &mut text_context);
+
+        let mut text_selection = start_drag(&mut reactive_tree, 0, 1);
+        // Past the end of the last text.
+        let pointer = point_in(&reactive_tree, 2, 200.0);
+        let root = reactive_tree.element_tree.as_deref().unwrap();
+        text_selection.extend(root, &mut reactive_tree.element_state, pointer);
+
+        assert_eq!(selected(&reactive_tree).as_deref(), Some("ne\ntwo\nthree"));
+    }
+
+    #[test]
+    fn dragging_backward_selects_the_texts_in_between() {
+        let mut text_context = TextContext::with_test_font();
+        let mut reactive_tree = laid_out(texts(&["one", "two", "three"], FlexDirection::Column), &mut text_context);
+
+        let mut text_selection = start_drag(&mut reactive_tree, 2, 2);
+        let pointer = point_in(&reactive_tree, 0, 0.0);
+        let root = reactive_tree.element_tree.as_deref().unwrap();
+        text_selection.extend(root, &mut reactive_tree.element_state, pointer);
+
+        assert_eq!(selected(&reactive_tree).as_deref(), Some("one\ntwo\nth"));
+    }
+
+    #[test]
+    fn releasing_the_pointer_ends_the_drag() {
+        let mut text_context = TextContext::with_test_font();
+        let mut reactive_tree = laid_out(texts(&["one", "two"], FlexDirection::Column), &mut text_context);
+
+        let mut text_selection = start_drag(&mut reactive_tree, 0, 0);
+        text_selection.end();
+        let pointer = point_in(&reactive_tree, 1, 200.0);
+        let root = reactive_tree.element_tree.as_deref().unwrap();
+        text_selection.extend(root, &mut reactive_tree.element_state, pointer);
+
+        assert_eq!(selected(&reactive_tree), None);
+    }
+}
@@ -0,0 +1,208 @@
+use crate::style::TextStyleProperty;
+use crate::text::RangedStyles;
+use std::ops::Range;
+
+/// Splits source code into styled tokens, one line at a time.
+///
+/// Used by the [`CodeEditor`](crate::elements::CodeEditor) to highlight its text. A line is only tokenized again
+/// when its text or the state it starts in changed, so big files stay fast while editing.
+pub trait Tokenizer: Send + Sync {
+    /// Returns the styles of the tokens in `line`, with byte ranges relative to the start of the line.
+    ///
+    /// `state` is whatever the tokenizer carries from one line to the next, like whether a block comment is open.
+    /// It holds the state at the end of the previous line, `0` for the first line, and should be updated to the
+    /// state at the end of `line`. The line includes its line break, if any.
+    fn tokenize_line(&self, line: &str, state: &mut u64) -> Vec<(Range<usize>, TextStyleProperty)>;
+}
+
+/// A line that was tokenized.
+struct TokenizedLine {
+    /// The length of the line in bytes, including its line break.
+    length: usize,
+    /// The state at the start of the line.
+    start_state: u64,
+    tokens: Vec<(Range<usize>, TextStyleProperty)>,
+    /// The state at the end of the line.
+    end_state: u64,
+}
+
+/// Remembers the tokens of each line of the last text, so that only the lines from the first change on are tokenized
+/// again.
+#[derive(Default)]
+pub(crate) struct LineStyleCache {
+    text: String,
+    lines: Vec<TokenizedLine>,
+}
+
+impl LineStyleCache {
+    /// Tokenizes `text`, starting at the first line that differs from the last text.
+    ///
+    /// The lines after the change are tokenized until one starts in the same state as before and its text didn't
+    /// change, from where on the old tokens are kept.
+    pub(crate) fn tokenize(&mut self, tokenizer: &dyn Tokenizer, text: &str) -> RangedStyles {
+        let old_text = std::mem::take(&mut self.text);
+        let old_lines = std::mem::take(&mut self.lines);
+
+        let prefix = common_prefix(&old_text, text);
+        let suffix = common_suffix(&old_text, text).min(old_text.len().min(text.len()) - prefix);
+        // Offsets in the text that start in the common suffix map to old offsets by the difference in length.
+        let suffix_start = text.len() - suffix;
+
+        let mut old_lines = old_lines.into_iter().peekable();
+        let mut old_offset = 0;
+        let mut offset = 0;
+        let mut state = 0;
+
+        // The lines before the first change keep their tokens, as long as their line break didn't change either.
+        while let Some(line) = old_lines.next_if(|line| {
+            let end = old_offset + line.length;
+            end <= prefix && old_text.as_bytes()[end - 1] == b'\n'
+        }) {
+            old_offset += line.length;
+            offset += line.length;
+            state = line.end_state;
+            self.lines.push(line);
+        }
+
+        while offset < text.len() {
+            if offset >= suffix_start {
+                // Skip the old lines before the one that starts at the same place in the old text.
+                let old_start = offset - suffix_start + (old_text.len() - suffix);
+                while let Some(line) = old_lines.next_if(|line| old_offset + line.length <= old_start) {
+                    old_offset += line.length;
+                }
+
+                if old_offset == old_start && old_lines.peek().is_some_and(|line| line.start_state == state) {
+                    // The rest of the text and the state it starts in are the same, so its tokens are too.
+                    self.lines.extend(old_lines);
+                    break;
+                }
+            }
+
+            let line_end = text[offset..].find('\n').map_or(text.len(), |index| offset + index + 1);
+            let mut end_state = state;
+            let tokens = tokenizer.tokenize_line(&text[offset..line_end], &mut end_state);
+            self.lines.push(TokenizedLine {
+                length: line_end - offset,
+                start_state: state,
+                tokens,
+                end_state,
+            });
+            offset = line_end;
+            state = end_state;
+        }
+
+        self.text = text.to_string();
+
+        let mut styles = Vec::new();
+        let mut offset = 0;
+        for line in &self.lines {
+            styles.extend(
+                line.tokens.iter().map(|(range, style)| (range.start + offset..range.end + offset, style.clone())),
+            );
+            offset += line.length;
+        }
+        RangedStyles::new(styles)
+    }
+}
+
+/// The number of bytes at the start of `a` and `b` that are the same, on a char boundary.
+fn common_prefix(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .zip(b.chars())
+        .find(|((_, a_char), b_char)| a_char != b_char)
+        .map_or(a.len().min(b.len()), |((index, _), _)| index)
+}
+
+/// The number of bytes at the end of `a` and `b` that are the same, on a char boundary.
+fn common_suffix(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .rev()
+        .zip(b.chars().rev())
+        .find(|((_, a_char), b_char)| a_char != b_char)
+        .map_or(a.len().min(b.len()), |((index, a_char), _)| a.len() - index - a_char.len_utf8())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LineStyleCache, Tokenizer};
+    use crate::style::TextStyleProperty;
+    use std::ops::Range;
+    use std::sync::Mutex;
+
+    /// Styles each line as a whole, and counts the `/*` that are not closed in its state.
+    #[derive(Default)]
+    struct CommentTokenizer {
+        tokenized: Mutex<Vec<String>>,
+    }
+
+    impl Tokenizer for CommentTokenizer {
+        fn tokenize_line(&self, line: &str, state: &mut u64) -> Vec<(Range<usize>, TextStyleProperty)> {
+            self.tokenized.lock().unwrap().push(line.to_string());
+            let size = if *state > 0 { 10.0 } else { 12.0 };
+            *state += line.matches("/*").count() as u64;
+            *state = state.saturating_sub(line.matches("*/").count() as u64);
+            vec![(0..line.len(), TextStyleProperty::FontSize(size))]
+        }
+    }
+
+    impl CommentTokenizer {
+        fn take_tokenized(&self) -> Vec<String> {
+            std::mem::take(&mut self.tokenized.lock().unwrap())
+        }
+    }
+
+    fn style_ranges(cache: &mut LineStyleCache, tokenizer: &CommentTokenizer, text: &str) -> Vec<Range<usize>> {
+        cache.tokenize(tokenizer, text).styles.into_iter().map(|(range, _)| range).collect()
+    }
+
+    #[test]
+    fn only_the_changed_line_is_tokenized_again() {
+        let tokenizer = CommentTokenizer::default();
+        let mut cache = LineStyleCache::default();
+        cache.tokenize(&tokenizer, "a\nb\nc\n");
+        assert_eq!(tokenizer.take_tokenized(), ["a\n", "b\n", "c\n"]);
+
+        let ranges = style_ranges(&mut cache, &tokenizer, "a\nbbb\nc\n");
+        assert_eq!(tokenizer.take_tokenized(), ["bbb\n"]);
+        assert_eq!(ranges, [0..2, 2..6, 6..8]);
+    }
+
+    #[test]
+    fn lines_after_a_changed_state_are_tokenized_again() {
+        let tokenizer = CommentTokenizer::default();
+        let mut cache = LineStyleCache::default();
+        cache.tokenize(&tokenizer, "a\nb\nc\n");
+        tokenizer.take_tokenized();
+
+        cache.tokenize(&tokenizer, "/*\nb\nc\n");
+        assert_eq!(tokenizer.take_tokenized(), ["/*\n", "b\n", "c\n"]);
+    }
+
+    #[test]
+    fn inserted_lines_keep_the_tokens_after_them() {
+        let tokenizer = CommentTokenizer::default();
+        let mut cache = LineStyleCache::default();
+        cache.tokenize(&tokenizer, "a\nb\nc\n");
+        tokenizer.take_tokenized();
+
+        let ranges = style_ranges(&mut cache, &tokenizer, "a\nx\ny\nb\nc\n");
+        assert_eq!(tokenizer.take_tokenized(), ["x\n", "y\n"]);
+        assert_eq!(ranges, [0..2, 2..4, 4..6, 6..8, 8..10]);
+
+        let ranges = style_ranges(&mut cache, &tokenizer, "a\nc\n");
+        assert!(tokenizer.take_tokenized().is_empty());
+        assert_eq!(ranges, [0..2, 2..4]);
+    }
+
+    #[test]
+    fn typing_at_the_end_of_the_last_line_tokenizes_it_again() {
+        let tokenizer = CommentTokenizer::default();
+        let mut cache = LineStyleCache::default();
+        cache.tokenize(&tokenizer, "a\nb");
+        tokenizer.take_tokenized();
+
+        cache.tokenize(&tokenizer, "a\nbc");
+        assert_eq!(tokenizer.take_tokenized(), ["bc"]);
+    }
+}
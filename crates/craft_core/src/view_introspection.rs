@@ -1,3 +1,4 @@
+use crate::components::ComponentId;
 use crate::elements::element::Element;
 use crate::elements::{Font, Image, TinyVg};
 use crate::reactive::fiber_tree;
@@ -71,3 +72,43 @@ pub fn scan_view_for_resources(
         }
     }
 }
+
+/// Finds the `Font` elements that load `resource_identifier`.
+///
+/// Returns the family alias of the first of them, if any, and the ids of the components that contain them.
+pub(crate) fn find_font_elements(
+    element: &dyn Element,
+    component: &ComponentTreeNode,
+    resource_identifier: &ResourceIdentifier,
+) -> (Option<String>, Vec<ComponentId>) {
+    let fiber: Rc<RefCell<FiberNode>> = fiber_tree::new(component, element);
+
+    let mut family = None;
+    let mut components = Vec::new();
+    let mut to_visit: Vec<Rc<RefCell<FiberNode>>> = vec![fiber];
+
+    while let Some(node) = to_visit.pop() {
+        let node = node.borrow();
+        to_visit.extend(node.children.iter().cloned());
+
+        let Some(font) = node.element.and_then(|element| element.as_any().downcast_ref::<Font>()) else {
+            continue;
+        };
+        if &font.resource_identifier != resource_identifier {
+            continue;
+        }
+        family = family.or_else(|| font.family.clone());
+
+        // The closest ancestor that is not an element is the component that contains the font.
+        let mut ancestor = node.parent.clone();
+        while let Some(current) = ancestor {
+            if current.borrow().element.is_none() {
+                components.push(current.borrow().component.id);
+                break;
+            }
+            ancestor = current.borrow().parent.clone();
+        }
+    }
+
+    (family, components)
+}
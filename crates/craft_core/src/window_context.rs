@@ -1,4 +1,10 @@
+use crate::clipboard::Clipboard;
+use crate::components::ComponentId;
+use crate::elements::Toast;
+use crate::i18n::{Catalog, Localization};
+use crate::resource_manager::ResourceIdentifier;
 use kurbo::Point;
+use std::sync::Arc;
 use winit::dpi::{LogicalPosition, LogicalSize, PhysicalSize};
 use winit::window::{Cursor, Window};
 
@@ -17,6 +23,14 @@ pub struct WindowContext {
     requested_mouse_position_x: Option<f32>,
     requested_mouse_position_y: Option<f32>,
     requested_cursor: Option<Cursor>,
+
+    /// Toasts pushed since the last view update.
+    pending_toasts: Vec<Toast>,
+
+    localization: Arc<Localization>,
+    clipboard: Clipboard,
+    /// Catalogs to load since the last view update, with their locales.
+    requested_catalogs: Vec<(String, ResourceIdentifier)>,
 }
 
 impl WindowContext {
@@ -78,6 +92,10 @@ impl WindowContext {
             requested_mouse_position_x: None,
             requested_mouse_position_y: None,
             requested_cursor: None,
+            pending_toasts: Vec::new(),
+            localization: Default::default(),
+            clipboard: Default::default(),
+            requested_catalogs: Vec::new(),
         }
     }
 
@@ -123,7 +141,63 @@ impl WindowContext {
     pub fn set_cursor(&mut self, cursor: Cursor) {
         self.requested_cursor = Some(cursor);
     }
+
+    /// Requests `cursor` if no other cursor was requested.
+    pub(crate) fn set_cursor_if_unset(&mut self, cursor: Cursor) {
+        self.requested_cursor.get_or_insert(cursor);
+    }
     
+    /// Shows a toast notification in the corner of the window.
+    pub fn push_toast(&mut self, toast: Toast) {
+        self.pending_toasts.push(toast);
+    }
+
+    pub(crate) fn take_toasts(&mut self) -> Vec<Toast> {
+        std::mem::take(&mut self.pending_toasts)
+    }
+
+    /// Marks the toasts pushed by a component's update as coming from that component,
+    /// so that their action messages are sent back to it.
+    pub(crate) fn claim_toasts(&mut self, origin: ComponentId) {
+        for toast in self.pending_toasts.iter_mut().filter(|toast| toast.origin.is_none()) {
+            toast.origin = Some(origin);
+        }
+    }
+
+    /// The system clipboard, or an in-memory clipboard if there is none.
+    pub fn clipboard(&self) -> &Clipboard {
+        &self.clipboard
+    }
+
+    /// The translated messages and formatting of the current locale.
+    pub fn localization(&self) -> &Localization {
+        &self.localization
+    }
+
+    /// Switches the locale, which renders the view again in the new locale.
+    pub fn set_locale(&mut self, locale: &str) {
+        Arc::make_mut(&mut self.localization).set_locale(locale);
+    }
+
+    /// Set the locale whose messages are used when a message is missing from the current locale.
+    pub fn set_fallback_locale(&mut self, locale: Option<&str>) {
+        Arc::make_mut(&mut self.localization).set_fallback_locale(locale);
+    }
+
+    /// Adds the messages of `catalog` to `locale`.
+    pub fn add_catalog(&mut self, locale: &str, catalog: Catalog) {
+        Arc::make_mut(&mut self.localization).add_catalog(locale, catalog);
+    }
+
+    /// Loads a catalog in the format of [`Catalog::parse`] and adds its messages to `locale` once it's loaded.
+    pub fn load_catalog(&mut self, locale: &str, resource: ResourceIdentifier) {
+        self.requested_catalogs.push((locale.to_string(), resource));
+    }
+
+    pub(crate) fn take_catalog_requests(&mut self) -> Vec<(String, ResourceIdentifier)> {
+        std::mem::take(&mut self.requested_catalogs)
+    }
+
     pub fn effective_scale_factor(&self) -> f64 {
         self.scale_factor * self.zoom_factor
     }